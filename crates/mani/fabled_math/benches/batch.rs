@@ -0,0 +1,100 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use fabled_math::batch_math::{normalize_vectors, rotate_vectors_quat, transform_points_mat4};
+use fabled_math::matrix4x4_math::{compose_trs_mat4, transform_point3_mat4};
+use fabled_math::quaternion_math::from_angle_axis_quat;
+use fabled_math::vector_math::normalize;
+use fabled_math::{Matrix4x4, Quaternion, Vector3};
+
+const COUNTS: [usize; 3] = [64, 1024, 16384];
+
+fn sample_points(count: usize) -> Vec<Vector3> {
+    (0..count)
+        .map(|index| {
+            let index: f32 = index as f32;
+            Vector3::set(index.sin(), index.cos(), index * 0.01)
+        })
+        .collect()
+}
+
+fn transform_points(c: &mut Criterion) {
+    let matrix: Matrix4x4 = compose_trs_mat4(
+        Vector3::set(1.0, 2.0, 3.0),
+        from_angle_axis_quat(Vector3::UP, 0.5),
+        Vector3::set(2.0, 2.0, 2.0),
+    );
+
+    let mut group = c.benchmark_group("transform_points_mat4");
+
+    for count in COUNTS {
+        let points: Vec<Vector3> = sample_points(count);
+        let mut output: Vec<Vector3> = vec![Vector3::ZERO; count];
+
+        group.bench_with_input(BenchmarkId::new("scalar", count), &points, |b, points| {
+            b.iter(|| {
+                for (point, transformed) in points.iter().zip(output.iter_mut()) {
+                    *transformed = transform_point3_mat4(black_box(matrix), *point);
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", count), &points, |b, points| {
+            b.iter(|| transform_points_mat4(black_box(matrix), points, &mut output))
+        });
+    }
+
+    group.finish();
+}
+
+fn rotate_vectors(c: &mut Criterion) {
+    let rotation: Quaternion = from_angle_axis_quat(Vector3::RIGHT, 1.1);
+
+    let mut group = c.benchmark_group("rotate_vectors_quat");
+
+    for count in COUNTS {
+        let vectors: Vec<Vector3> = sample_points(count);
+        let mut output: Vec<Vector3> = vec![Vector3::ZERO; count];
+
+        group.bench_with_input(BenchmarkId::new("scalar", count), &vectors, |b, vectors| {
+            b.iter(|| {
+                for (vector, rotated) in vectors.iter().zip(output.iter_mut()) {
+                    *rotated = *vector * black_box(rotation);
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", count), &vectors, |b, vectors| {
+            b.iter(|| rotate_vectors_quat(black_box(rotation), vectors, &mut output))
+        });
+    }
+
+    group.finish();
+}
+
+fn normalize_bulk(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize_vectors");
+
+    for count in COUNTS {
+        let vectors: Vec<Vector3> = sample_points(count);
+        let mut output: Vec<Vector3> = vec![Vector3::ZERO; count];
+
+        group.bench_with_input(BenchmarkId::new("scalar", count), &vectors, |b, vectors| {
+            b.iter(|| {
+                for (vector, normalized) in vectors.iter().zip(output.iter_mut()) {
+                    *normalized = Vector3 {
+                        value: normalize(vector.value),
+                    };
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", count), &vectors, |b, vectors| {
+            b.iter(|| normalize_vectors(vectors, &mut output))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(batch, transform_points, rotate_vectors, normalize_bulk);
+criterion_main!(batch);
@@ -0,0 +1,39 @@
+// Absolute tolerance comparisons shared by the unit tests. The relative
+// approximate_equal in boolean fails for values near zero, which most test
+// results are.
+use crate::{Aabb, Matrix3x3, Quaternion, Vector2, Vector3};
+
+pub(crate) const THRESHOLD: f32 = 0.0001;
+
+// Decompositions accumulate more error than a single product.
+pub(crate) const DECOMPOSITION_THRESHOLD: f32 = 0.0005;
+
+pub(crate) fn approx_lanes(lhs: &[f32], rhs: &[f32], threshold: f32) -> bool {
+    lhs.iter()
+        .zip(rhs.iter())
+        .all(|(lhs, rhs)| (lhs - rhs).abs() <= threshold)
+}
+
+pub(crate) fn approx_vec2(lhs: Vector2, rhs: Vector2) -> bool {
+    approx_lanes(&lhs.to_primitive(), &rhs.to_primitive(), THRESHOLD)
+}
+
+pub(crate) fn approx_vec3(lhs: Vector3, rhs: Vector3) -> bool {
+    approx_lanes(&lhs.to_primitive(), &rhs.to_primitive(), THRESHOLD)
+}
+
+pub(crate) fn approx_quat(lhs: Quaternion, rhs: Quaternion) -> bool {
+    approx_lanes(&lhs.to_primitive(), &rhs.to_primitive(), THRESHOLD)
+}
+
+pub(crate) fn approx_aabb(lhs: Aabb, rhs: Aabb) -> bool {
+    approx_vec3(lhs.min, rhs.min) && approx_vec3(lhs.max, rhs.max)
+}
+
+pub(crate) fn approx_mat3(lhs: Matrix3x3, rhs: Matrix3x3) -> bool {
+    approx_lanes(
+        &lhs.to_primitive(),
+        &rhs.to_primitive(),
+        DECOMPOSITION_THRESHOLD,
+    )
+}
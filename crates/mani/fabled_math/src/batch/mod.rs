@@ -0,0 +1,5 @@
+mod slice;
+mod vec3x8;
+
+pub use slice::*;
+pub use vec3x8::*;
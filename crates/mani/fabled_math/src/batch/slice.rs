@@ -0,0 +1,207 @@
+pub mod batch_math {
+    use crate::{Matrix3x3, Matrix4x4, Quaternion, Vector3, Vector3x8};
+
+    use crate::vector3x8_math::*;
+
+    // Slices are processed eight at a time through Vector3x8, the last chunk
+    // is padded with zero lanes that are never written back.
+
+    #[inline]
+    fn map_vec3x8<F: Fn(Vector3x8) -> Vector3x8>(
+        input: &[Vector3],
+        output: &mut [Vector3],
+        kernel: F,
+    ) {
+        debug_assert_eq!(input.len(), output.len());
+
+        for (input_chunk, output_chunk) in input
+            .chunks(Vector3x8::LANES)
+            .zip(output.chunks_mut(Vector3x8::LANES))
+        {
+            kernel(Vector3x8::load(input_chunk)).store(output_chunk);
+        }
+    }
+
+    #[inline]
+    pub fn transform_points_mat4(matrix: Matrix4x4, points: &[Vector3], output: &mut [Vector3]) {
+        map_vec3x8(points, output, |points| {
+            transform_point3x8_mat4(matrix, points)
+        });
+    }
+
+    #[inline]
+    pub fn transform_vectors_mat4(matrix: Matrix4x4, vectors: &[Vector3], output: &mut [Vector3]) {
+        map_vec3x8(vectors, output, |vectors| {
+            transform_vector3x8_mat4(matrix, vectors)
+        });
+    }
+
+    #[inline]
+    pub fn transform_normals_mat4(matrix: Matrix4x4, normals: &[Vector3], output: &mut [Vector3]) {
+        let normal_matrix: Matrix3x3 = normal_matrix_mat4(matrix);
+
+        map_vec3x8(normals, output, |normals| {
+            normalize_vec3x8(transform_vector3x8_mat3(normal_matrix, normals))
+        });
+    }
+
+    #[inline]
+    pub fn rotate_vectors_quat(
+        quaternion: Quaternion,
+        vectors: &[Vector3],
+        output: &mut [Vector3],
+    ) {
+        map_vec3x8(vectors, output, |vectors| {
+            rotate_vec3x8_quat(quaternion, vectors)
+        });
+    }
+
+    #[inline]
+    pub fn normalize_vectors(vectors: &[Vector3], output: &mut [Vector3]) {
+        map_vec3x8(vectors, output, normalize_vec3x8);
+    }
+
+    #[inline]
+    pub fn cross_vectors(lhs: &[Vector3], rhs: &[Vector3], output: &mut [Vector3]) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+        debug_assert_eq!(lhs.len(), output.len());
+
+        for ((lhs_chunk, rhs_chunk), output_chunk) in lhs
+            .chunks(Vector3x8::LANES)
+            .zip(rhs.chunks(Vector3x8::LANES))
+            .zip(output.chunks_mut(Vector3x8::LANES))
+        {
+            cross_vec3x8(Vector3x8::load(lhs_chunk), Vector3x8::load(rhs_chunk))
+                .store(output_chunk);
+        }
+    }
+
+    #[inline]
+    pub fn dot_vectors(lhs: &[Vector3], rhs: &[Vector3], output: &mut [f32]) {
+        debug_assert_eq!(lhs.len(), rhs.len());
+        debug_assert_eq!(lhs.len(), output.len());
+
+        for ((lhs_chunk, rhs_chunk), output_chunk) in lhs
+            .chunks(Vector3x8::LANES)
+            .zip(rhs.chunks(Vector3x8::LANES))
+            .zip(output.chunks_mut(Vector3x8::LANES))
+        {
+            let dot: [f32; 8] =
+                dot_vec3x8(Vector3x8::load(lhs_chunk), Vector3x8::load(rhs_chunk)).to_array();
+
+            output_chunk.copy_from_slice(&dot[..output_chunk.len()]);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod batch_test {
+    use crate::batch_math::*;
+    use crate::matrix4x4_math::{compose_trs_mat4, from_scale_mat4, transform_point3_mat4};
+    use crate::quaternion_math::from_angle_axis_quat;
+    use crate::vector_math::{cross, dot, normalize};
+    use crate::{Matrix4x4, Quaternion, Vector3};
+
+    fn sample_vectors(count: usize) -> Vec<Vector3> {
+        (0..count)
+            .map(|index| {
+                let index: f32 = index as f32;
+
+                Vector3::set(
+                    index * 0.5 - 3.0,
+                    (index * 1.7).sin() * 4.0,
+                    1.0 + index * 0.25,
+                )
+            })
+            .collect()
+    }
+
+    fn approximate(a: Vector3, b: Vector3) -> bool {
+        let a: [f32; 3] = a.to_primitive();
+        let b: [f32; 3] = b.to_primitive();
+
+        a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-4)
+    }
+
+    #[test]
+    fn transform_matches_scalar() {
+        // 19 is not a multiple of the lane count so the padded tail is covered.
+        let points: Vec<Vector3> = sample_vectors(19);
+        let matrix: Matrix4x4 = compose_trs_mat4(
+            Vector3::set(1.0, -2.0, 3.0),
+            from_angle_axis_quat(normalize_vec3(Vector3::set(1.0, 1.0, 0.0)), 0.7),
+            Vector3::set(2.0, 1.0, 0.5),
+        );
+
+        let mut output: Vec<Vector3> = vec![Vector3::ZERO; points.len()];
+        transform_points_mat4(matrix, &points, &mut output);
+
+        for (point, transformed) in points.iter().zip(output.iter()) {
+            assert!(approximate(
+                transform_point3_mat4(matrix, *point),
+                *transformed
+            ));
+        }
+    }
+
+    #[test]
+    fn normals_stay_perpendicular() {
+        let matrix: Matrix4x4 = from_scale_mat4(Vector3::set(4.0, 1.0, 1.0));
+
+        let tangent: Vector3 = normalize_vec3(Vector3::set(1.0, -1.0, 0.0));
+        let normal: Vector3 = normalize_vec3(Vector3::set(1.0, 1.0, 0.0));
+
+        let mut transformed_tangent: [Vector3; 1] = [Vector3::ZERO];
+        let mut transformed_normal: [Vector3; 1] = [Vector3::ZERO];
+
+        transform_vectors_mat4(matrix, &[tangent], &mut transformed_tangent);
+        transform_normals_mat4(matrix, &[normal], &mut transformed_normal);
+
+        assert!(dot(transformed_tangent[0].value, transformed_normal[0].value).abs() < 1e-5);
+        assert!((dot(transformed_normal[0].value, transformed_normal[0].value) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn bulk_vector_ops_match_scalar() {
+        let lhs: Vec<Vector3> = sample_vectors(11);
+        let rhs: Vec<Vector3> = lhs.iter().rev().copied().collect();
+
+        let mut dots: Vec<f32> = vec![0.0; lhs.len()];
+        let mut crosses: Vec<Vector3> = vec![Vector3::ZERO; lhs.len()];
+        let mut normalized: Vec<Vector3> = vec![Vector3::ZERO; lhs.len()];
+        let mut rotated: Vec<Vector3> = vec![Vector3::ZERO; lhs.len()];
+
+        let rotation: Quaternion = from_angle_axis_quat(Vector3::UP, 1.2);
+
+        dot_vectors(&lhs, &rhs, &mut dots);
+        cross_vectors(&lhs, &rhs, &mut crosses);
+        normalize_vectors(&lhs, &mut normalized);
+        rotate_vectors_quat(rotation, &lhs, &mut rotated);
+
+        for index in 0..lhs.len() {
+            let (a, b): (Vector3, Vector3) = (lhs[index], rhs[index]);
+
+            assert!((dot(a.value, b.value) - dots[index]).abs() < 1e-3);
+            assert!(approximate(
+                Vector3 {
+                    value: cross(a.value, b.value)
+                },
+                crosses[index]
+            ));
+            assert!(approximate(
+                Vector3 {
+                    value: normalize(a.value)
+                },
+                normalized[index]
+            ));
+            assert!(approximate(a * rotation, rotated[index]));
+        }
+    }
+
+    fn normalize_vec3(vector: Vector3) -> Vector3 {
+        Vector3 {
+            value: normalize(vector.value),
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Eight Vector3 stored as structure of arrays, one f32x8 per axis.
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Vector3x8 {
+    pub x: std::simd::f32x8,
+    pub y: std::simd::f32x8,
+    pub z: std::simd::f32x8,
+}
+
+impl Default for Vector3x8 {
+    fn default() -> Self {
+        Vector3x8::ZERO
+    }
+}
+
+impl Vector3x8 {
+    pub const LANES: usize = 8;
+
+    pub const ZERO: Vector3x8 = Vector3x8 {
+        x: std::simd::f32x8::from_array([0.0; 8]),
+        y: std::simd::f32x8::from_array([0.0; 8]),
+        z: std::simd::f32x8::from_array([0.0; 8]),
+    };
+
+    #[inline(always)]
+    pub const fn set(x: std::simd::f32x8, y: std::simd::f32x8, z: std::simd::f32x8) -> Vector3x8 {
+        Vector3x8 { x, y, z }
+    }
+
+    #[inline(always)]
+    pub const fn splat(vector: Vector3) -> Vector3x8 {
+        Vector3x8 {
+            x: std::simd::f32x8::from_array([vector.x(); 8]),
+            y: std::simd::f32x8::from_array([vector.y(); 8]),
+            z: std::simd::f32x8::from_array([vector.z(); 8]),
+        }
+    }
+
+    // Gathers up to eight vectors, lanes past the end of the slice are zero.
+    #[inline]
+    pub fn load(vectors: &[Vector3]) -> Vector3x8 {
+        let mut x: [f32; 8] = [0.0; 8];
+        let mut y: [f32; 8] = [0.0; 8];
+        let mut z: [f32; 8] = [0.0; 8];
+
+        for (index, vector) in vectors.iter().take(Vector3x8::LANES).enumerate() {
+            let array: [f32; 4] = vector.value.to_array();
+
+            x[index] = array[0];
+            y[index] = array[1];
+            z[index] = array[2];
+        }
+
+        Vector3x8 {
+            x: std::simd::f32x8::from_array(x),
+            y: std::simd::f32x8::from_array(y),
+            z: std::simd::f32x8::from_array(z),
+        }
+    }
+
+    // Scatters up to eight vectors back into the slice.
+    #[inline]
+    pub fn store(self, vectors: &mut [Vector3]) {
+        let x: [f32; 8] = self.x.to_array();
+        let y: [f32; 8] = self.y.to_array();
+        let z: [f32; 8] = self.z.to_array();
+
+        for (index, vector) in vectors.iter_mut().take(Vector3x8::LANES).enumerate() {
+            *vector = Vector3::set(x[index], y[index], z[index]);
+        }
+    }
+
+    #[inline]
+    pub fn extract(self, lane: usize) -> Vector3 {
+        Vector3::set(self.x[lane], self.y[lane], self.z[lane])
+    }
+
+    #[inline]
+    pub fn to_primitive(self) -> [[f32; 3]; 8] {
+        let mut primitive: [[f32; 3]; 8] = [[0.0; 3]; 8];
+
+        for (lane, element) in primitive.iter_mut().enumerate() {
+            *element = [self.x[lane], self.y[lane], self.z[lane]];
+        }
+
+        primitive
+    }
+
+    #[inline]
+    pub fn from_primitive(array: [[f32; 3]; 8]) -> Vector3x8 {
+        let mut x: [f32; 8] = [0.0; 8];
+        let mut y: [f32; 8] = [0.0; 8];
+        let mut z: [f32; 8] = [0.0; 8];
+
+        for (lane, element) in array.iter().enumerate() {
+            x[lane] = element[0];
+            y[lane] = element[1];
+            z[lane] = element[2];
+        }
+
+        Vector3x8 {
+            x: std::simd::f32x8::from_array(x),
+            y: std::simd::f32x8::from_array(y),
+            z: std::simd::f32x8::from_array(z),
+        }
+    }
+}
+
+impl Display for Vector3x8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Vector3x8 (x : {:?}, y : {:?}, z : {:?})",
+            self.x.to_array(),
+            self.y.to_array(),
+            self.z.to_array()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<std::simd::f32x8> for Vector3x8 {
+    type Output = Vector3x8;
+
+    #[inline]
+    fn mul(self, rhs: std::simd::f32x8) -> Self::Output {
+        Vector3x8 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl MulAssign<std::simd::f32x8> for Vector3x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: std::simd::f32x8) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<std::simd::f32x8> for Vector3x8 {
+    type Output = Vector3x8;
+
+    #[inline]
+    fn div(self, rhs: std::simd::f32x8) -> Self::Output {
+        Vector3x8 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
+    }
+}
+
+impl DivAssign<std::simd::f32x8> for Vector3x8 {
+    #[inline]
+    fn div_assign(&mut self, rhs: std::simd::f32x8) {
+        *self = *self / rhs;
+    }
+}
+
+// Vector-Wise
+impl Add for Vector3x8 {
+    type Output = Vector3x8;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector3x8 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl AddAssign for Vector3x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vector3x8 {
+    type Output = Vector3x8;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector3x8 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl SubAssign for Vector3x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Vector3x8 {
+    type Output = Vector3x8;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Vector3x8 {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+            z: self.z * rhs.z,
+        }
+    }
+}
+
+impl MulAssign for Vector3x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for Vector3x8 {
+    type Output = Vector3x8;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Vector3x8 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+
+pub mod vector3x8_math {
+    use crate::{Matrix3x3, Matrix4x4, Quaternion, Vector3x8};
+
+    use crate::matrix3x3_math::{inverse_mat3, transpose_mat3};
+    use crate::Affine3;
+
+    use std::simd::StdFloat;
+
+    #[inline]
+    pub fn dot_vec3x8(vector: Vector3x8, vector1: Vector3x8) -> std::simd::f32x8 {
+        vector.x * vector1.x + vector.y * vector1.y + vector.z * vector1.z
+    }
+
+    #[inline]
+    pub fn cross_vec3x8(vector: Vector3x8, vector1: Vector3x8) -> Vector3x8 {
+        Vector3x8 {
+            x: vector.y * vector1.z - vector.z * vector1.y,
+            y: vector.z * vector1.x - vector.x * vector1.z,
+            z: vector.x * vector1.y - vector.y * vector1.x,
+        }
+    }
+
+    #[inline]
+    pub fn length_squared_vec3x8(vector: Vector3x8) -> std::simd::f32x8 {
+        dot_vec3x8(vector, vector)
+    }
+
+    #[inline]
+    pub fn length_vec3x8(vector: Vector3x8) -> std::simd::f32x8 {
+        length_squared_vec3x8(vector).sqrt()
+    }
+
+    #[inline]
+    pub fn normalize_vec3x8(vector: Vector3x8) -> Vector3x8 {
+        let inverse_length: std::simd::f32x8 = std::simd::f32x8::splat(1.0) / length_vec3x8(vector);
+
+        vector * inverse_length
+    }
+
+    #[inline]
+    pub fn lerp_vec3x8(start: Vector3x8, end: Vector3x8, t: std::simd::f32x8) -> Vector3x8 {
+        start + (end - start) * t
+    }
+
+    #[inline]
+    pub fn transform_point3x8_mat4(matrix: Matrix4x4, points: Vector3x8) -> Vector3x8 {
+        let translation: Vector3x8 = Vector3x8::splat(matrix.column_w.trunc_vec3());
+
+        transform_vector3x8_mat4(matrix, points) + translation
+    }
+
+    #[inline]
+    pub fn transform_vector3x8_mat4(matrix: Matrix4x4, vectors: Vector3x8) -> Vector3x8 {
+        let x_axis: Vector3x8 = Vector3x8::splat(matrix.column_x.trunc_vec3()) * vectors.x;
+        let y_axis: Vector3x8 = Vector3x8::splat(matrix.column_y.trunc_vec3()) * vectors.y;
+        let z_axis: Vector3x8 = Vector3x8::splat(matrix.column_z.trunc_vec3()) * vectors.z;
+
+        (x_axis + y_axis) + z_axis
+    }
+
+    #[inline]
+    pub fn transform_vector3x8_mat3(matrix: Matrix3x3, vectors: Vector3x8) -> Vector3x8 {
+        let x_axis: Vector3x8 = Vector3x8::splat(matrix.column_x) * vectors.x;
+        let y_axis: Vector3x8 = Vector3x8::splat(matrix.column_y) * vectors.y;
+        let z_axis: Vector3x8 = Vector3x8::splat(matrix.column_z) * vectors.z;
+
+        (x_axis + y_axis) + z_axis
+    }
+
+    // Normals go through the inverse transpose so non-uniform scale keeps them
+    // perpendicular to the surface.
+    #[inline]
+    pub fn normal_matrix_mat4(matrix: Matrix4x4) -> Matrix3x3 {
+        let upper: Matrix3x3 = Affine3::from_mat4(matrix).matrix3;
+
+        transpose_mat3(inverse_mat3(upper))
+    }
+
+    #[inline]
+    pub fn transform_normal3x8_mat4(matrix: Matrix4x4, normals: Vector3x8) -> Vector3x8 {
+        normalize_vec3x8(transform_vector3x8_mat3(
+            normal_matrix_mat4(matrix),
+            normals,
+        ))
+    }
+
+    #[inline]
+    pub fn rotate_vec3x8_quat(quaternion: Quaternion, vectors: Vector3x8) -> Vector3x8 {
+        let imaginary: Vector3x8 = Vector3x8::splat(quaternion.to_pure());
+        let real: std::simd::f32x8 = std::simd::f32x8::splat(quaternion.to_real());
+
+        let t: Vector3x8 = cross_vec3x8(imaginary, vectors);
+        let t: Vector3x8 = t + t;
+
+        vectors + t * real + cross_vec3x8(imaginary, t)
+    }
+}
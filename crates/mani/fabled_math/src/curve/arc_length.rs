@@ -0,0 +1,121 @@
+use crate::Vector3;
+
+use crate::vector_math::distance;
+
+// Cumulative chord length sampled at uniform parameter steps. Used to map a
+// travelled distance back to a curve parameter for constant speed motion.
+#[derive(Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArcLengthTable {
+    pub lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    pub fn new<F: Fn(f32) -> Vector3>(curve: F, samples: usize) -> ArcLengthTable {
+        let samples: usize = samples.max(1);
+        let step: f32 = (samples as f32).recip();
+
+        let mut lengths: Vec<f32> = Vec::with_capacity(samples + 1);
+        lengths.push(0.0);
+
+        let mut previous: Vector3 = curve(0.0);
+        let mut accumulated: f32 = 0.0;
+
+        for index in 1..=samples {
+            let current: Vector3 = curve(index as f32 * step);
+
+            accumulated += distance(previous.value, current.value);
+            lengths.push(accumulated);
+
+            previous = current;
+        }
+
+        ArcLengthTable { lengths }
+    }
+
+    #[inline]
+    pub fn total_length(&self) -> f32 {
+        self.lengths.last().copied().unwrap_or(0.0)
+    }
+
+    // Curve parameter at the given distance along the curve, clamped to the
+    // curve length and linearly interpolated between samples.
+    pub fn parameter_at_distance(&self, distance: f32) -> f32 {
+        let total_length: f32 = self.total_length();
+
+        if std::intrinsics::unlikely(total_length <= 0.0) {
+            return 0.0;
+        }
+
+        let distance: f32 = distance.clamp(0.0, total_length);
+        let segments: usize = self.lengths.len() - 1;
+
+        let upper: usize = self
+            .lengths
+            .partition_point(|length| *length < distance)
+            .clamp(1, segments);
+        let lower: usize = upper - 1;
+
+        let lower_length: f32 = self.lengths[lower];
+        let segment_length: f32 = self.lengths[upper] - lower_length;
+
+        let fraction: f32 = if segment_length > 0.0 {
+            (distance - lower_length) / segment_length
+        } else {
+            0.0
+        };
+
+        (lower as f32 + fraction) / segments as f32
+    }
+
+    #[inline]
+    pub fn parameter_at_fraction(&self, fraction: f32) -> f32 {
+        self.parameter_at_distance(fraction * self.total_length())
+    }
+}
+
+
+#[cfg(test)]
+mod arc_length_test {
+    use crate::cubic_bezier_math::evaluate_bezier;
+    use crate::vector_math::distance;
+    use crate::{ArcLengthTable, CubicBezier, Vector3};
+
+    #[test]
+    fn straight_line_is_linear() {
+        let line = |t: f32| Vector3::set(t * 10.0, 0.0, 0.0);
+        let table: ArcLengthTable = ArcLengthTable::new(line, 16);
+
+        assert!((table.total_length() - 10.0).abs() < 1e-4);
+        assert!((table.parameter_at_distance(2.5) - 0.25).abs() < 1e-5);
+        assert_eq!(table.parameter_at_distance(-1.0), 0.0);
+        assert_eq!(table.parameter_at_distance(20.0), 1.0);
+    }
+
+    #[test]
+    fn constant_speed_on_bezier() {
+        // Control points bunched at the start so uniform t moves unevenly.
+        let curve: CubicBezier = CubicBezier::set(
+            Vector3::set(0.0, 0.0, 0.0),
+            Vector3::set(0.1, 0.0, 0.0),
+            Vector3::set(0.2, 0.0, 0.0),
+            Vector3::set(9.0, 3.0, 0.0),
+        );
+
+        let table: ArcLengthTable = ArcLengthTable::new(|t| evaluate_bezier(curve, t), 256);
+        let step_length: f32 = table.total_length() / 10.0;
+
+        let mut previous: Vector3 = evaluate_bezier(curve, 0.0);
+
+        for step in 1..=10 {
+            let t: f32 = table.parameter_at_distance(step as f32 * step_length);
+            let current: Vector3 = evaluate_bezier(curve, t);
+
+            assert!(
+                (distance(previous.value, current.value) - step_length).abs() < step_length * 0.02
+            );
+
+            previous = current;
+        }
+    }
+}
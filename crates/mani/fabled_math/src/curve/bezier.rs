@@ -0,0 +1,159 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier {
+    pub start: Vector3,
+    pub control_start: Vector3,
+    pub control_end: Vector3,
+    pub end: Vector3,
+}
+
+impl Default for CubicBezier {
+    fn default() -> Self {
+        CubicBezier {
+            start: Vector3::ZERO,
+            control_start: Vector3::ZERO,
+            control_end: Vector3::FORWARD,
+            end: Vector3::FORWARD,
+        }
+    }
+}
+
+impl CubicBezier {
+    #[inline(always)]
+    pub const fn set(
+        start: Vector3,
+        control_start: Vector3,
+        control_end: Vector3,
+        end: Vector3,
+    ) -> CubicBezier {
+        CubicBezier {
+            start,
+            control_start,
+            control_end,
+            end,
+        }
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [Vector3; 4] {
+        [self.start, self.control_start, self.control_end, self.end]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [Vector3; 4]) -> CubicBezier {
+        CubicBezier::set(array[0], array[1], array[2], array[3])
+    }
+}
+
+impl Display for CubicBezier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CubicBezier (start : {}, control_start : {}, control_end : {}, end : {})",
+            self.start, self.control_start, self.control_end, self.end
+        )
+    }
+}
+
+pub mod cubic_bezier_math {
+    use crate::{CubicBezier, Vector3};
+
+    #[inline]
+    pub fn evaluate_bezier(curve: CubicBezier, t: f32) -> Vector3 {
+        let one_minus_t: f32 = 1.0 - t;
+
+        let one_minus_t_sqr: f32 = one_minus_t * one_minus_t;
+        let t_sqr: f32 = t * t;
+
+        curve.start * (one_minus_t_sqr * one_minus_t)
+            + curve.control_start * (3.0 * one_minus_t_sqr * t)
+            + curve.control_end * (3.0 * one_minus_t * t_sqr)
+            + curve.end * (t_sqr * t)
+    }
+
+    #[inline]
+    pub fn derivative_bezier(curve: CubicBezier, t: f32) -> Vector3 {
+        let one_minus_t: f32 = 1.0 - t;
+
+        (curve.control_start - curve.start) * (3.0 * one_minus_t * one_minus_t)
+            + (curve.control_end - curve.control_start) * (6.0 * one_minus_t * t)
+            + (curve.end - curve.control_end) * (3.0 * t * t)
+    }
+
+    #[inline]
+    pub fn second_derivative_bezier(curve: CubicBezier, t: f32) -> Vector3 {
+        let first: Vector3 = curve.control_end - curve.control_start * 2.0 + curve.start;
+        let second: Vector3 = curve.end - curve.control_end * 2.0 + curve.control_start;
+
+        first * (6.0 * (1.0 - t)) + second * (6.0 * t)
+    }
+
+    // De Casteljau split, both halves together trace the original curve.
+    #[inline]
+    pub fn subdivide_bezier(curve: CubicBezier, t: f32) -> (CubicBezier, CubicBezier) {
+        let lerp = |a: Vector3, b: Vector3| a + (b - a) * t;
+
+        let start_control: Vector3 = lerp(curve.start, curve.control_start);
+        let control_control: Vector3 = lerp(curve.control_start, curve.control_end);
+        let control_end: Vector3 = lerp(curve.control_end, curve.end);
+
+        let left_inner: Vector3 = lerp(start_control, control_control);
+        let right_inner: Vector3 = lerp(control_control, control_end);
+
+        let split: Vector3 = lerp(left_inner, right_inner);
+
+        (
+            CubicBezier::set(curve.start, start_control, left_inner, split),
+            CubicBezier::set(split, right_inner, control_end, curve.end),
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod cubic_bezier_test {
+    use crate::cubic_bezier_math::*;
+    use crate::vector_math::distance;
+    use crate::{CubicBezier, Vector3};
+
+    const CURVE: CubicBezier = CubicBezier::set(
+        Vector3::set(0.0, 0.0, 0.0),
+        Vector3::set(1.0, 2.0, 0.0),
+        Vector3::set(3.0, 2.0, 1.0),
+        Vector3::set(4.0, 0.0, 0.0),
+    );
+
+    #[test]
+    fn end_points_and_tangents() {
+        assert!(distance(evaluate_bezier(CURVE, 0.0).value, CURVE.start.value) < 1e-6);
+        assert!(distance(evaluate_bezier(CURVE, 1.0).value, CURVE.end.value) < 1e-6);
+
+        let start_tangent: Vector3 = (CURVE.control_start - CURVE.start) * 3.0;
+        assert!(distance(derivative_bezier(CURVE, 0.0).value, start_tangent.value) < 1e-6);
+
+        let step: f32 = 1e-3;
+        let central: Vector3 = (derivative_bezier(CURVE, 0.4 + step)
+            - derivative_bezier(CURVE, 0.4 - step))
+            * (0.5 / step);
+        assert!(distance(central.value, second_derivative_bezier(CURVE, 0.4).value) < 1e-2);
+    }
+
+    #[test]
+    fn subdivision_traces_curve() {
+        let (left, right): (CubicBezier, CubicBezier) = subdivide_bezier(CURVE, 0.3);
+
+        for step in 0..=10 {
+            let u: f32 = step as f32 / 10.0;
+
+            let on_left: Vector3 = evaluate_bezier(left, u);
+            let on_right: Vector3 = evaluate_bezier(right, u);
+
+            assert!(distance(on_left.value, evaluate_bezier(CURVE, u * 0.3).value) < 1e-5);
+            assert!(distance(on_right.value, evaluate_bezier(CURVE, 0.3 + u * 0.7).value) < 1e-5);
+        }
+    }
+}
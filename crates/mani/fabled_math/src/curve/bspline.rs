@@ -0,0 +1,128 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+// A single segment of a uniform cubic b-spline, the curve approximates the
+// control points rather than passing through them.
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BSpline {
+    pub points: [Vector3; 4],
+}
+
+impl Default for BSpline {
+    fn default() -> Self {
+        BSpline {
+            points: [Vector3::ZERO; 4],
+        }
+    }
+}
+
+impl BSpline {
+    #[inline(always)]
+    pub const fn set(points: [Vector3; 4]) -> BSpline {
+        BSpline { points }
+    }
+
+    // Segment starting at control point index - 1, end points are repeated
+    // so every index within the path yields a segment. None for an empty path.
+    #[inline]
+    pub fn from_path(path: &[Vector3], index: usize) -> Option<BSpline> {
+        let last: usize = path.len().checked_sub(1)?;
+
+        let sample = |offset: isize| -> Vector3 {
+            let clamped: isize = (index as isize + offset).clamp(0, last as isize);
+
+            path[clamped as usize]
+        };
+
+        Some(BSpline::set([sample(-1), sample(0), sample(1), sample(2)]))
+    }
+}
+
+impl Display for BSpline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BSpline (p0 : {}, p1 : {}, p2 : {}, p3 : {})",
+            self.points[0], self.points[1], self.points[2], self.points[3]
+        )
+    }
+}
+
+pub mod bspline_math {
+    use crate::{BSpline, CubicBezier, Vector3};
+
+    const ONE_SIXTH: f32 = 1.0 / 6.0;
+
+    #[inline]
+    pub fn evaluate_bspline(curve: BSpline, t: f32) -> Vector3 {
+        let [p0, p1, p2, p3]: [Vector3; 4] = curve.points;
+
+        let t_sqr: f32 = t * t;
+        let t_cube: f32 = t_sqr * t;
+        let one_minus_t: f32 = 1.0 - t;
+
+        let basis0: f32 = one_minus_t * one_minus_t * one_minus_t;
+        let basis1: f32 = 3.0 * t_cube - 6.0 * t_sqr + 4.0;
+        let basis2: f32 = -3.0 * t_cube + 3.0 * t_sqr + 3.0 * t + 1.0;
+        let basis3: f32 = t_cube;
+
+        (p0 * basis0 + p1 * basis1 + p2 * basis2 + p3 * basis3) * ONE_SIXTH
+    }
+
+    #[inline]
+    pub fn derivative_bspline(curve: BSpline, t: f32) -> Vector3 {
+        let [p0, p1, p2, p3]: [Vector3; 4] = curve.points;
+
+        let t_sqr: f32 = t * t;
+        let one_minus_t: f32 = 1.0 - t;
+
+        let basis0: f32 = -3.0 * one_minus_t * one_minus_t;
+        let basis1: f32 = 9.0 * t_sqr - 12.0 * t;
+        let basis2: f32 = -9.0 * t_sqr + 6.0 * t + 3.0;
+        let basis3: f32 = 3.0 * t_sqr;
+
+        (p0 * basis0 + p1 * basis1 + p2 * basis2 + p3 * basis3) * ONE_SIXTH
+    }
+
+    #[inline]
+    pub fn second_derivative_bspline(curve: BSpline, t: f32) -> Vector3 {
+        let [p0, p1, p2, p3]: [Vector3; 4] = curve.points;
+
+        p0 * (1.0 - t) + p1 * (3.0 * t - 2.0) + p2 * (1.0 - 3.0 * t) + p3 * t
+    }
+
+    #[inline]
+    pub fn to_bezier_bspline(curve: BSpline) -> CubicBezier {
+        let [p0, p1, p2, p3]: [Vector3; 4] = curve.points;
+
+        const ONE_THIRD: f32 = 1.0 / 3.0;
+        const TWO_THIRD: f32 = 2.0 / 3.0;
+
+        CubicBezier::set(
+            (p0 + p1 * 4.0 + p2) * ONE_SIXTH,
+            p1 * TWO_THIRD + p2 * ONE_THIRD,
+            p1 * ONE_THIRD + p2 * TWO_THIRD,
+            (p1 + p2 * 4.0 + p3) * ONE_SIXTH,
+        )
+    }
+
+    // Uniform refinement at the segment midpoint, both halves are again
+    // uniform b-spline segments.
+    #[inline]
+    pub fn subdivide_bspline(curve: BSpline) -> (BSpline, BSpline) {
+        let [p0, p1, p2, p3]: [Vector3; 4] = curve.points;
+
+        let edge0: Vector3 = (p0 + p1) * 0.5;
+        let vertex1: Vector3 = (p0 + p1 * 6.0 + p2) * 0.125;
+        let edge1: Vector3 = (p1 + p2) * 0.5;
+        let vertex2: Vector3 = (p1 + p2 * 6.0 + p3) * 0.125;
+        let edge2: Vector3 = (p2 + p3) * 0.5;
+
+        (
+            BSpline::set([edge0, vertex1, edge1, vertex2]),
+            BSpline::set([vertex1, edge1, vertex2, edge2]),
+        )
+    }
+}
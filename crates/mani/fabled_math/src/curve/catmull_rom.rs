@@ -0,0 +1,278 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+// Knot spacing exponent, centripetal avoids cusps and self intersection
+// within a segment while chordal follows the control polygon more tightly.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CatmullRomType {
+    Uniform,
+    #[default]
+    Centripetal,
+    Chordal,
+}
+
+impl CatmullRomType {
+    #[inline]
+    pub const fn alpha(self) -> f32 {
+        match self {
+            CatmullRomType::Uniform => 0.0,
+            CatmullRomType::Centripetal => 0.5,
+            CatmullRomType::Chordal => 1.0,
+        }
+    }
+}
+
+// A single segment interpolating points[1] to points[2].
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatmullRom {
+    pub points: [Vector3; 4],
+    pub catmull_rom_type: CatmullRomType,
+}
+
+impl Default for CatmullRom {
+    fn default() -> Self {
+        CatmullRom {
+            points: [Vector3::ZERO; 4],
+            catmull_rom_type: CatmullRomType::default(),
+        }
+    }
+}
+
+impl CatmullRom {
+    #[inline(always)]
+    pub const fn set(points: [Vector3; 4], catmull_rom_type: CatmullRomType) -> CatmullRom {
+        CatmullRom {
+            points,
+            catmull_rom_type,
+        }
+    }
+
+    // Segment from path[index] to path[index + 1], the neighbours are
+    // extrapolated at both ends so the curve reaches the first and last point.
+    // None for an empty path, an index past the end gives the degenerate
+    // segment at the last point.
+    #[inline]
+    pub fn from_path(
+        path: &[Vector3],
+        index: usize,
+        catmull_rom_type: CatmullRomType,
+    ) -> Option<CatmullRom> {
+        let last: usize = path.len().checked_sub(1)?;
+        let index: usize = index.min(last);
+
+        let start: Vector3 = path[index];
+        let end: Vector3 = path[(index + 1).min(last)];
+
+        let previous: Vector3 = if index == 0 {
+            start * 2.0 - end
+        } else {
+            path[index - 1]
+        };
+
+        let next: Vector3 = if index + 2 > last {
+            end * 2.0 - start
+        } else {
+            path[index + 2]
+        };
+
+        Some(CatmullRom::set([previous, start, end, next], catmull_rom_type))
+    }
+}
+
+impl Display for CatmullRom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CatmullRom (p0 : {}, p1 : {}, p2 : {}, p3 : {}, type : {:?})",
+            self.points[0], self.points[1], self.points[2], self.points[3], self.catmull_rom_type
+        )
+    }
+}
+
+pub mod catmull_rom_math {
+    use crate::{CatmullRom, CubicBezier, Hermite, Vector3};
+
+    use crate::cubic_bezier_math::subdivide_bezier;
+    use crate::hermite_math::{
+        derivative_hermite, evaluate_hermite, second_derivative_hermite, to_bezier_hermite,
+    };
+    use crate::vector_math::distance_squared;
+
+    #[inline]
+    fn knot_interval(start: Vector3, end: Vector3, alpha: f32) -> f32 {
+        let interval: f32 = distance_squared(start.value, end.value).powf(alpha * 0.5);
+
+        // Coincident control points would collapse the knot spacing.
+        if std::intrinsics::unlikely(interval < f32::EPSILON) {
+            return 1.0;
+        }
+
+        interval
+    }
+
+    // Non uniform tangents following Barry and Goldman, rescaled to the
+    // [0, 1] parameter range of the middle segment.
+    #[inline]
+    pub fn to_hermite_catmull_rom(curve: CatmullRom) -> Hermite {
+        let [p0, p1, p2, p3]: [Vector3; 4] = curve.points;
+        let alpha: f32 = curve.catmull_rom_type.alpha();
+
+        let dt0: f32 = knot_interval(p0, p1, alpha);
+        let dt1: f32 = knot_interval(p1, p2, alpha);
+        let dt2: f32 = knot_interval(p2, p3, alpha);
+
+        let start_tangent: Vector3 =
+            ((p1 - p0) / dt0 - (p2 - p0) / (dt0 + dt1) + (p2 - p1) / dt1) * dt1;
+        let end_tangent: Vector3 =
+            ((p2 - p1) / dt1 - (p3 - p1) / (dt1 + dt2) + (p3 - p2) / dt2) * dt1;
+
+        Hermite::set(p1, start_tangent, p2, end_tangent)
+    }
+
+    #[inline]
+    pub fn evaluate_catmull_rom(curve: CatmullRom, t: f32) -> Vector3 {
+        evaluate_hermite(to_hermite_catmull_rom(curve), t)
+    }
+
+    #[inline]
+    pub fn derivative_catmull_rom(curve: CatmullRom, t: f32) -> Vector3 {
+        derivative_hermite(to_hermite_catmull_rom(curve), t)
+    }
+
+    #[inline]
+    pub fn second_derivative_catmull_rom(curve: CatmullRom, t: f32) -> Vector3 {
+        second_derivative_hermite(to_hermite_catmull_rom(curve), t)
+    }
+
+    #[inline]
+    pub fn to_bezier_catmull_rom(curve: CatmullRom) -> CubicBezier {
+        to_bezier_hermite(to_hermite_catmull_rom(curve))
+    }
+
+    #[inline]
+    pub fn subdivide_catmull_rom(curve: CatmullRom, t: f32) -> (CubicBezier, CubicBezier) {
+        subdivide_bezier(to_bezier_catmull_rom(curve), t)
+    }
+}
+
+
+#[cfg(test)]
+mod catmull_rom_test {
+    use crate::bspline_math::*;
+    use crate::catmull_rom_math::*;
+    use crate::cubic_bezier_math::evaluate_bezier;
+    use crate::hermite_math::*;
+    use crate::vector_math::distance;
+    use crate::{BSpline, CatmullRom, CatmullRomType, Hermite, Vector3};
+
+    const PATH: [Vector3; 5] = [
+        Vector3::set(0.0, 0.0, 0.0),
+        Vector3::set(1.0, 0.0, 0.0),
+        Vector3::set(1.2, 0.0, 3.0),
+        Vector3::set(4.0, 1.0, 3.0),
+        Vector3::set(5.0, 1.0, 0.0),
+    ];
+
+    #[test]
+    fn interpolates_path() {
+        for catmull_rom_type in [
+            CatmullRomType::Uniform,
+            CatmullRomType::Centripetal,
+            CatmullRomType::Chordal,
+        ] {
+            for index in 0..PATH.len() - 1 {
+                let curve: CatmullRom =
+                    CatmullRom::from_path(&PATH, index, catmull_rom_type).unwrap();
+
+                let start: Vector3 = evaluate_catmull_rom(curve, 0.0);
+                let end: Vector3 = evaluate_catmull_rom(curve, 1.0);
+
+                assert!(distance(start.value, PATH[index].value) < 1e-5);
+                assert!(distance(end.value, PATH[index + 1].value) < 1e-5);
+
+                let bezier_point: Vector3 = evaluate_bezier(to_bezier_catmull_rom(curve), 0.35);
+                let point: Vector3 = evaluate_catmull_rom(curve, 0.35);
+                assert!(distance(bezier_point.value, point.value) < 1e-4);
+            }
+        }
+
+        assert!(CatmullRom::from_path(&[], 0, CatmullRomType::Uniform).is_none());
+        assert!(BSpline::from_path(&[], 0).is_none());
+
+        // Past the end clamps to the last point instead of indexing out of bounds.
+        for index in [PATH.len() - 1, PATH.len() + 3] {
+            let curve: CatmullRom =
+                CatmullRom::from_path(&PATH, index, CatmullRomType::Centripetal).unwrap();
+            let last: Vector3 = PATH[PATH.len() - 1];
+
+            assert!(distance(evaluate_catmull_rom(curve, 0.0).value, last.value) < 1e-5);
+            assert!(distance(evaluate_catmull_rom(curve, 1.0).value, last.value) < 1e-5);
+        }
+    }
+
+    #[test]
+    fn uniform_matches_classic_tangent() {
+        let curve: CatmullRom = CatmullRom::set(
+            [PATH[0], PATH[1], PATH[2], PATH[3]],
+            CatmullRomType::Uniform,
+        );
+        let hermite: Hermite = to_hermite_catmull_rom(curve);
+
+        let classic: Vector3 = (PATH[2] - PATH[0]) * 0.5;
+        assert!(distance(hermite.start_tangent.value, classic.value) < 1e-5);
+    }
+
+    #[test]
+    fn hermite_subdivision_and_derivative() {
+        let curve: Hermite = Hermite::set(PATH[0], PATH[1], PATH[3], PATH[2]);
+        let (left, right): (Hermite, Hermite) = subdivide_hermite(curve, 0.6);
+
+        assert!(
+            distance(
+                evaluate_hermite(left, 0.5).value,
+                evaluate_hermite(curve, 0.3).value
+            ) < 1e-5
+        );
+        assert!(
+            distance(
+                evaluate_hermite(right, 0.5).value,
+                evaluate_hermite(curve, 0.8).value
+            ) < 1e-5
+        );
+
+        let step: f32 = 1e-3;
+        let central: Vector3 = (evaluate_hermite(curve, 0.5 + step)
+            - evaluate_hermite(curve, 0.5 - step))
+            * (0.5 / step);
+        assert!(distance(central.value, derivative_hermite(curve, 0.5).value) < 1e-2);
+    }
+
+    #[test]
+    fn bspline_bezier_and_refinement() {
+        let curve: BSpline = BSpline::from_path(&PATH, 2).unwrap();
+        let (left, right): (BSpline, BSpline) = subdivide_bspline(curve);
+
+        for step in 0..=8 {
+            let u: f32 = step as f32 / 8.0;
+
+            let point: Vector3 = evaluate_bspline(curve, u);
+            let bezier_point: Vector3 = evaluate_bezier(to_bezier_bspline(curve), u);
+            assert!(distance(point.value, bezier_point.value) < 1e-5);
+
+            let refined: Vector3 = evaluate_bspline(left, u);
+            assert!(distance(refined.value, evaluate_bspline(curve, u * 0.5).value) < 1e-5);
+
+            let refined: Vector3 = evaluate_bspline(right, u);
+            assert!(distance(refined.value, evaluate_bspline(curve, 0.5 + u * 0.5).value) < 1e-5);
+        }
+
+        let step: f32 = 1e-3;
+        let central: Vector3 = (derivative_bspline(curve, 0.5 + step)
+            - derivative_bspline(curve, 0.5 - step))
+            * (0.5 / step);
+        assert!(distance(central.value, second_derivative_bspline(curve, 0.5).value) < 1e-2);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hermite {
+    pub start: Vector3,
+    pub start_tangent: Vector3,
+    pub end: Vector3,
+    pub end_tangent: Vector3,
+}
+
+impl Default for Hermite {
+    fn default() -> Self {
+        Hermite {
+            start: Vector3::ZERO,
+            start_tangent: Vector3::FORWARD,
+            end: Vector3::FORWARD,
+            end_tangent: Vector3::FORWARD,
+        }
+    }
+}
+
+impl Hermite {
+    #[inline(always)]
+    pub const fn set(
+        start: Vector3,
+        start_tangent: Vector3,
+        end: Vector3,
+        end_tangent: Vector3,
+    ) -> Hermite {
+        Hermite {
+            start,
+            start_tangent,
+            end,
+            end_tangent,
+        }
+    }
+}
+
+impl Display for Hermite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Hermite (start : {}, start_tangent : {}, end : {}, end_tangent : {})",
+            self.start, self.start_tangent, self.end, self.end_tangent
+        )
+    }
+}
+
+pub mod hermite_math {
+    use crate::{CubicBezier, Hermite, Vector3};
+
+    #[inline]
+    pub fn evaluate_hermite(curve: Hermite, t: f32) -> Vector3 {
+        let t_sqr: f32 = t * t;
+        let t_cube: f32 = t_sqr * t;
+
+        let start_basis: f32 = 2.0 * t_cube - 3.0 * t_sqr + 1.0;
+        let start_tangent_basis: f32 = t_cube - 2.0 * t_sqr + t;
+        let end_basis: f32 = -2.0 * t_cube + 3.0 * t_sqr;
+        let end_tangent_basis: f32 = t_cube - t_sqr;
+
+        curve.start * start_basis
+            + curve.start_tangent * start_tangent_basis
+            + curve.end * end_basis
+            + curve.end_tangent * end_tangent_basis
+    }
+
+    #[inline]
+    pub fn derivative_hermite(curve: Hermite, t: f32) -> Vector3 {
+        let t_sqr: f32 = t * t;
+
+        let start_basis: f32 = 6.0 * t_sqr - 6.0 * t;
+        let start_tangent_basis: f32 = 3.0 * t_sqr - 4.0 * t + 1.0;
+        let end_tangent_basis: f32 = 3.0 * t_sqr - 2.0 * t;
+
+        (curve.start - curve.end) * start_basis
+            + curve.start_tangent * start_tangent_basis
+            + curve.end_tangent * end_tangent_basis
+    }
+
+    #[inline]
+    pub fn second_derivative_hermite(curve: Hermite, t: f32) -> Vector3 {
+        let start_basis: f32 = 12.0 * t - 6.0;
+        let start_tangent_basis: f32 = 6.0 * t - 4.0;
+        let end_tangent_basis: f32 = 6.0 * t - 2.0;
+
+        (curve.start - curve.end) * start_basis
+            + curve.start_tangent * start_tangent_basis
+            + curve.end_tangent * end_tangent_basis
+    }
+
+    #[inline]
+    pub fn to_bezier_hermite(curve: Hermite) -> CubicBezier {
+        const ONE_THIRD: f32 = 1.0 / 3.0;
+
+        CubicBezier::set(
+            curve.start,
+            curve.start + curve.start_tangent * ONE_THIRD,
+            curve.end - curve.end_tangent * ONE_THIRD,
+            curve.end,
+        )
+    }
+
+    #[inline]
+    pub fn from_bezier_hermite(curve: CubicBezier) -> Hermite {
+        Hermite::set(
+            curve.start,
+            (curve.control_start - curve.start) * 3.0,
+            curve.end,
+            (curve.end - curve.control_end) * 3.0,
+        )
+    }
+
+    // Tangents are rescaled so each half keeps the [0, 1] parameter range.
+    #[inline]
+    pub fn subdivide_hermite(curve: Hermite, t: f32) -> (Hermite, Hermite) {
+        let split: Vector3 = evaluate_hermite(curve, t);
+        let split_tangent: Vector3 = derivative_hermite(curve, t);
+
+        let right_scale: f32 = 1.0 - t;
+
+        (
+            Hermite::set(
+                curve.start,
+                curve.start_tangent * t,
+                split,
+                split_tangent * t,
+            ),
+            Hermite::set(
+                split,
+                split_tangent * right_scale,
+                curve.end,
+                curve.end_tangent * right_scale,
+            ),
+        )
+    }
+}
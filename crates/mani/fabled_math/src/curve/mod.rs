@@ -0,0 +1,13 @@
+// cubic curves over Vector3, every segment can be converted to a bezier for
+// subdivision and sampled by an arc length table for constant speed travel.
+mod arc_length;
+mod bezier;
+mod bspline;
+mod catmull_rom;
+mod hermite;
+
+pub use arc_length::*;
+pub use bezier::*;
+pub use bspline::*;
+pub use catmull_rom::*;
+pub use hermite::*;
@@ -1,11 +1,893 @@
-// LinearIn, LinearOut,
-// EaseInSin, EaseOutSin, EaseInOutSin, EaseInQuad, EaseOutQuad, EaseInOutQuad,
-// EaseInCubic, EaseOutCubic, EaseInOutCubic, EaseInQuart, EaseOutQuart,
-// EaseInOutQuart EaseInQuint, EaseOutQuint, EaseInOutQuint, EaseInExpo,
-// EaseOutExpo, EaseInOutExpo, EaseInCirc, EaseOutCirc, EaseInOutCirc,
-// EaseInBack, EaseOutBack, EaseInOutBack, EaseInElastic, EaseOutElastic,
-// EaseInOutElastic, EaseInBounce, EaseOutBounce, EaseInOutBounce
+use crate::Vector4;
 
+use crate::ease_math::*;
 
-// linear interpolation, spherical interpolation, normalized linear
-// interpolation
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EaseFunction {
+    #[default]
+    LinearIn,
+    LinearOut,
+    EaseInSin,
+    EaseOutSin,
+    EaseInOutSin,
+    EaseInQuad,
+    EaseOutQuad,
+    EaseInOutQuad,
+    EaseInCubic,
+    EaseOutCubic,
+    EaseInOutCubic,
+    EaseInQuart,
+    EaseOutQuart,
+    EaseInOutQuart,
+    EaseInQuint,
+    EaseOutQuint,
+    EaseInOutQuint,
+    EaseInExpo,
+    EaseOutExpo,
+    EaseInOutExpo,
+    EaseInCirc,
+    EaseOutCirc,
+    EaseInOutCirc,
+    EaseInBack,
+    EaseOutBack,
+    EaseInOutBack,
+    EaseInElastic,
+    EaseOutElastic,
+    EaseInOutElastic,
+    EaseInBounce,
+    EaseOutBounce,
+    EaseInOutBounce,
+}
+
+impl EaseFunction {
+    pub const ALL: [EaseFunction; 32] = [
+        EaseFunction::LinearIn,
+        EaseFunction::LinearOut,
+        EaseFunction::EaseInSin,
+        EaseFunction::EaseOutSin,
+        EaseFunction::EaseInOutSin,
+        EaseFunction::EaseInQuad,
+        EaseFunction::EaseOutQuad,
+        EaseFunction::EaseInOutQuad,
+        EaseFunction::EaseInCubic,
+        EaseFunction::EaseOutCubic,
+        EaseFunction::EaseInOutCubic,
+        EaseFunction::EaseInQuart,
+        EaseFunction::EaseOutQuart,
+        EaseFunction::EaseInOutQuart,
+        EaseFunction::EaseInQuint,
+        EaseFunction::EaseOutQuint,
+        EaseFunction::EaseInOutQuint,
+        EaseFunction::EaseInExpo,
+        EaseFunction::EaseOutExpo,
+        EaseFunction::EaseInOutExpo,
+        EaseFunction::EaseInCirc,
+        EaseFunction::EaseOutCirc,
+        EaseFunction::EaseInOutCirc,
+        EaseFunction::EaseInBack,
+        EaseFunction::EaseOutBack,
+        EaseFunction::EaseInOutBack,
+        EaseFunction::EaseInElastic,
+        EaseFunction::EaseOutElastic,
+        EaseFunction::EaseInOutElastic,
+        EaseFunction::EaseInBounce,
+        EaseFunction::EaseOutBounce,
+        EaseFunction::EaseInOutBounce,
+    ];
+
+    pub fn evaluate(self, t: f32) -> f32 {
+        match self {
+            EaseFunction::LinearIn => linear_in(t),
+            EaseFunction::LinearOut => linear_out(t),
+            EaseFunction::EaseInSin => ease_in_sin(t),
+            EaseFunction::EaseOutSin => ease_out_sin(t),
+            EaseFunction::EaseInOutSin => ease_in_out_sin(t),
+            EaseFunction::EaseInQuad => ease_in_quad(t),
+            EaseFunction::EaseOutQuad => ease_out_quad(t),
+            EaseFunction::EaseInOutQuad => ease_in_out_quad(t),
+            EaseFunction::EaseInCubic => ease_in_cubic(t),
+            EaseFunction::EaseOutCubic => ease_out_cubic(t),
+            EaseFunction::EaseInOutCubic => ease_in_out_cubic(t),
+            EaseFunction::EaseInQuart => ease_in_quart(t),
+            EaseFunction::EaseOutQuart => ease_out_quart(t),
+            EaseFunction::EaseInOutQuart => ease_in_out_quart(t),
+            EaseFunction::EaseInQuint => ease_in_quint(t),
+            EaseFunction::EaseOutQuint => ease_out_quint(t),
+            EaseFunction::EaseInOutQuint => ease_in_out_quint(t),
+            EaseFunction::EaseInExpo => ease_in_expo(t),
+            EaseFunction::EaseOutExpo => ease_out_expo(t),
+            EaseFunction::EaseInOutExpo => ease_in_out_expo(t),
+            EaseFunction::EaseInCirc => ease_in_circ(t),
+            EaseFunction::EaseOutCirc => ease_out_circ(t),
+            EaseFunction::EaseInOutCirc => ease_in_out_circ(t),
+            EaseFunction::EaseInBack => ease_in_back(t),
+            EaseFunction::EaseOutBack => ease_out_back(t),
+            EaseFunction::EaseInOutBack => ease_in_out_back(t),
+            EaseFunction::EaseInElastic => ease_in_elastic(t),
+            EaseFunction::EaseOutElastic => ease_out_elastic(t),
+            EaseFunction::EaseInOutElastic => ease_in_out_elastic(t),
+            EaseFunction::EaseInBounce => ease_in_bounce(t),
+            EaseFunction::EaseOutBounce => ease_out_bounce(t),
+            EaseFunction::EaseInOutBounce => ease_in_out_bounce(t),
+        }
+    }
+
+    pub fn evaluate_vec4(self, t: Vector4) -> Vector4 {
+        match self {
+            EaseFunction::LinearIn => linear_in_vec4(t),
+            EaseFunction::LinearOut => linear_out_vec4(t),
+            EaseFunction::EaseInSin => ease_in_sin_vec4(t),
+            EaseFunction::EaseOutSin => ease_out_sin_vec4(t),
+            EaseFunction::EaseInOutSin => ease_in_out_sin_vec4(t),
+            EaseFunction::EaseInQuad => ease_in_quad_vec4(t),
+            EaseFunction::EaseOutQuad => ease_out_quad_vec4(t),
+            EaseFunction::EaseInOutQuad => ease_in_out_quad_vec4(t),
+            EaseFunction::EaseInCubic => ease_in_cubic_vec4(t),
+            EaseFunction::EaseOutCubic => ease_out_cubic_vec4(t),
+            EaseFunction::EaseInOutCubic => ease_in_out_cubic_vec4(t),
+            EaseFunction::EaseInQuart => ease_in_quart_vec4(t),
+            EaseFunction::EaseOutQuart => ease_out_quart_vec4(t),
+            EaseFunction::EaseInOutQuart => ease_in_out_quart_vec4(t),
+            EaseFunction::EaseInQuint => ease_in_quint_vec4(t),
+            EaseFunction::EaseOutQuint => ease_out_quint_vec4(t),
+            EaseFunction::EaseInOutQuint => ease_in_out_quint_vec4(t),
+            EaseFunction::EaseInExpo => ease_in_expo_vec4(t),
+            EaseFunction::EaseOutExpo => ease_out_expo_vec4(t),
+            EaseFunction::EaseInOutExpo => ease_in_out_expo_vec4(t),
+            EaseFunction::EaseInCirc => ease_in_circ_vec4(t),
+            EaseFunction::EaseOutCirc => ease_out_circ_vec4(t),
+            EaseFunction::EaseInOutCirc => ease_in_out_circ_vec4(t),
+            EaseFunction::EaseInBack => ease_in_back_vec4(t),
+            EaseFunction::EaseOutBack => ease_out_back_vec4(t),
+            EaseFunction::EaseInOutBack => ease_in_out_back_vec4(t),
+            EaseFunction::EaseInElastic => ease_in_elastic_vec4(t),
+            EaseFunction::EaseOutElastic => ease_out_elastic_vec4(t),
+            EaseFunction::EaseInOutElastic => ease_in_out_elastic_vec4(t),
+            EaseFunction::EaseInBounce => ease_in_bounce_vec4(t),
+            EaseFunction::EaseOutBounce => ease_out_bounce_vec4(t),
+            EaseFunction::EaseInOutBounce => ease_in_out_bounce_vec4(t),
+        }
+    }
+}
+
+impl Display for EaseFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EaseFunction({:?})", self)
+    }
+}
+
+// Curves from Robert Penner's easing equations.
+// https://easings.net
+pub mod ease_math {
+    use crate::Vector4;
+
+    use crate::vector_math::{cos, exp2, ge, le, lt, select, sin, sqrt};
+
+    const PI_HALF: f32 = std::f32::consts::FRAC_PI_2;
+
+    const BACK_C1: f32 = 1.70158;
+    const BACK_C2: f32 = BACK_C1 * 1.525;
+    const BACK_C3: f32 = BACK_C1 + 1.0;
+
+    const ELASTIC_C4: f32 = std::f32::consts::TAU / 3.0;
+    const ELASTIC_C5: f32 = std::f32::consts::TAU / 4.5;
+
+    const BOUNCE_N1: f32 = 7.5625;
+    const BOUNCE_D1: f32 = 2.75;
+
+    const ZERO_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([0.0; 4]);
+    const HALF_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([0.5; 4]);
+    const ONE_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([1.0; 4]);
+    const TWO_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([2.0; 4]);
+
+    // Linear
+
+    #[inline(always)]
+    pub fn linear_in(t: f32) -> f32 {
+        t
+    }
+
+    #[inline(always)]
+    pub fn linear_out(t: f32) -> f32 {
+        t
+    }
+
+    #[inline(always)]
+    pub fn linear_in_vec4(t: Vector4) -> Vector4 {
+        t
+    }
+
+    #[inline(always)]
+    pub fn linear_out_vec4(t: Vector4) -> Vector4 {
+        t
+    }
+
+    // Sin
+
+    #[inline]
+    pub fn ease_in_sin(t: f32) -> f32 {
+        1.0 - (t * PI_HALF).cos()
+    }
+
+    #[inline]
+    pub fn ease_out_sin(t: f32) -> f32 {
+        (t * PI_HALF).sin()
+    }
+
+    #[inline]
+    pub fn ease_in_out_sin(t: f32) -> f32 {
+        -((std::f32::consts::PI * t).cos() - 1.0) * 0.5
+    }
+
+    #[inline]
+    pub fn ease_in_sin_vec4(t: Vector4) -> Vector4 {
+        const PI_HALF_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([PI_HALF; 4]);
+
+        Vector4 {
+            value: ONE_VEC - cos(t.value * PI_HALF_VEC),
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_sin_vec4(t: Vector4) -> Vector4 {
+        const PI_HALF_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([PI_HALF; 4]);
+
+        Vector4 {
+            value: sin(t.value * PI_HALF_VEC),
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_sin_vec4(t: Vector4) -> Vector4 {
+        const PI_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([std::f32::consts::PI; 4]);
+
+        Vector4 {
+            value: (ONE_VEC - cos(t.value * PI_VEC)) * HALF_VEC,
+        }
+    }
+
+    // Quad
+
+    #[inline]
+    pub fn ease_in_quad(t: f32) -> f32 {
+        t * t
+    }
+
+    #[inline]
+    pub fn ease_out_quad(t: f32) -> f32 {
+        let inv_t = 1.0 - t;
+
+        1.0 - inv_t * inv_t
+    }
+
+    #[inline]
+    pub fn ease_in_out_quad(t: f32) -> f32 {
+        if t < 0.5 {
+            2.0 * t * t
+        } else {
+            let inv_t = -2.0 * t + 2.0;
+
+            1.0 - inv_t * inv_t * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_quad_vec4(t: Vector4) -> Vector4 {
+        Vector4 {
+            value: t.value * t.value,
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_quad_vec4(t: Vector4) -> Vector4 {
+        let inv_t = ONE_VEC - t.value;
+
+        Vector4 {
+            value: ONE_VEC - inv_t * inv_t,
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_quad_vec4(t: Vector4) -> Vector4 {
+        let inv_t = TWO_VEC - t.value * TWO_VEC;
+
+        let lhs = TWO_VEC * t.value * t.value;
+        let rhs = ONE_VEC - inv_t * inv_t * HALF_VEC;
+
+        Vector4 {
+            value: select(lhs, rhs, lt(t.value, HALF_VEC)),
+        }
+    }
+
+    // Cubic
+
+    #[inline]
+    pub fn ease_in_cubic(t: f32) -> f32 {
+        t * t * t
+    }
+
+    #[inline]
+    pub fn ease_out_cubic(t: f32) -> f32 {
+        let inv_t = 1.0 - t;
+
+        1.0 - inv_t * inv_t * inv_t
+    }
+
+    #[inline]
+    pub fn ease_in_out_cubic(t: f32) -> f32 {
+        if t < 0.5 {
+            4.0 * t * t * t
+        } else {
+            let inv_t = -2.0 * t + 2.0;
+
+            1.0 - inv_t * inv_t * inv_t * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_cubic_vec4(t: Vector4) -> Vector4 {
+        Vector4 {
+            value: t.value * t.value * t.value,
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_cubic_vec4(t: Vector4) -> Vector4 {
+        let inv_t = ONE_VEC - t.value;
+
+        Vector4 {
+            value: ONE_VEC - inv_t * inv_t * inv_t,
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_cubic_vec4(t: Vector4) -> Vector4 {
+        const FOUR_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([4.0; 4]);
+
+        let inv_t = TWO_VEC - t.value * TWO_VEC;
+
+        let lhs = FOUR_VEC * t.value * t.value * t.value;
+        let rhs = ONE_VEC - inv_t * inv_t * inv_t * HALF_VEC;
+
+        Vector4 {
+            value: select(lhs, rhs, lt(t.value, HALF_VEC)),
+        }
+    }
+
+    // Quart
+
+    #[inline]
+    pub fn ease_in_quart(t: f32) -> f32 {
+        let t_sqr = t * t;
+
+        t_sqr * t_sqr
+    }
+
+    #[inline]
+    pub fn ease_out_quart(t: f32) -> f32 {
+        let inv_t = 1.0 - t;
+        let inv_t_sqr = inv_t * inv_t;
+
+        1.0 - inv_t_sqr * inv_t_sqr
+    }
+
+    #[inline]
+    pub fn ease_in_out_quart(t: f32) -> f32 {
+        if t < 0.5 {
+            let t_sqr = t * t;
+
+            8.0 * t_sqr * t_sqr
+        } else {
+            let inv_t = -2.0 * t + 2.0;
+            let inv_t_sqr = inv_t * inv_t;
+
+            1.0 - inv_t_sqr * inv_t_sqr * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_quart_vec4(t: Vector4) -> Vector4 {
+        let t_sqr = t.value * t.value;
+
+        Vector4 {
+            value: t_sqr * t_sqr,
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_quart_vec4(t: Vector4) -> Vector4 {
+        let inv_t = ONE_VEC - t.value;
+        let inv_t_sqr = inv_t * inv_t;
+
+        Vector4 {
+            value: ONE_VEC - inv_t_sqr * inv_t_sqr,
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_quart_vec4(t: Vector4) -> Vector4 {
+        const EIGHT_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([8.0; 4]);
+
+        let t_sqr = t.value * t.value;
+
+        let inv_t = TWO_VEC - t.value * TWO_VEC;
+        let inv_t_sqr = inv_t * inv_t;
+
+        let lhs = EIGHT_VEC * t_sqr * t_sqr;
+        let rhs = ONE_VEC - inv_t_sqr * inv_t_sqr * HALF_VEC;
+
+        Vector4 {
+            value: select(lhs, rhs, lt(t.value, HALF_VEC)),
+        }
+    }
+
+    // Quint
+
+    #[inline]
+    pub fn ease_in_quint(t: f32) -> f32 {
+        let t_sqr = t * t;
+
+        t_sqr * t_sqr * t
+    }
+
+    #[inline]
+    pub fn ease_out_quint(t: f32) -> f32 {
+        let inv_t = 1.0 - t;
+        let inv_t_sqr = inv_t * inv_t;
+
+        1.0 - inv_t_sqr * inv_t_sqr * inv_t
+    }
+
+    #[inline]
+    pub fn ease_in_out_quint(t: f32) -> f32 {
+        if t < 0.5 {
+            let t_sqr = t * t;
+
+            16.0 * t_sqr * t_sqr * t
+        } else {
+            let inv_t = -2.0 * t + 2.0;
+            let inv_t_sqr = inv_t * inv_t;
+
+            1.0 - inv_t_sqr * inv_t_sqr * inv_t * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_quint_vec4(t: Vector4) -> Vector4 {
+        let t_sqr = t.value * t.value;
+
+        Vector4 {
+            value: t_sqr * t_sqr * t.value,
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_quint_vec4(t: Vector4) -> Vector4 {
+        let inv_t = ONE_VEC - t.value;
+        let inv_t_sqr = inv_t * inv_t;
+
+        Vector4 {
+            value: ONE_VEC - inv_t_sqr * inv_t_sqr * inv_t,
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_quint_vec4(t: Vector4) -> Vector4 {
+        const SIXTEEN_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([16.0; 4]);
+
+        let t_sqr = t.value * t.value;
+
+        let inv_t = TWO_VEC - t.value * TWO_VEC;
+        let inv_t_sqr = inv_t * inv_t;
+
+        let lhs = SIXTEEN_VEC * t_sqr * t_sqr * t.value;
+        let rhs = ONE_VEC - inv_t_sqr * inv_t_sqr * inv_t * HALF_VEC;
+
+        Vector4 {
+            value: select(lhs, rhs, lt(t.value, HALF_VEC)),
+        }
+    }
+
+    // Expo
+
+    #[inline]
+    pub fn ease_in_expo(t: f32) -> f32 {
+        if t <= 0.0 {
+            0.0
+        } else {
+            (10.0 * t - 10.0).exp2()
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_expo(t: f32) -> f32 {
+        if t >= 1.0 {
+            1.0
+        } else {
+            1.0 - (-10.0 * t).exp2()
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_expo(t: f32) -> f32 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else if t < 0.5 {
+            (20.0 * t - 10.0).exp2() * 0.5
+        } else {
+            (2.0 - (-20.0 * t + 10.0).exp2()) * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_expo_vec4(t: Vector4) -> Vector4 {
+        const TEN_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([10.0; 4]);
+
+        let curve = exp2(TEN_VEC * t.value - TEN_VEC);
+
+        Vector4 {
+            value: select(ZERO_VEC, curve, le(t.value, ZERO_VEC)),
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_expo_vec4(t: Vector4) -> Vector4 {
+        const NEG_TEN_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([-10.0; 4]);
+
+        let curve = ONE_VEC - exp2(NEG_TEN_VEC * t.value);
+
+        Vector4 {
+            value: select(ONE_VEC, curve, ge(t.value, ONE_VEC)),
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_expo_vec4(t: Vector4) -> Vector4 {
+        const TEN_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([10.0; 4]);
+        const TWENTY_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([20.0; 4]);
+
+        let lhs = exp2(TWENTY_VEC * t.value - TEN_VEC) * HALF_VEC;
+        let rhs = (TWO_VEC - exp2(TEN_VEC - TWENTY_VEC * t.value)) * HALF_VEC;
+
+        let curve = select(lhs, rhs, lt(t.value, HALF_VEC));
+        let clamped_end = select(ONE_VEC, curve, ge(t.value, ONE_VEC));
+
+        Vector4 {
+            value: select(ZERO_VEC, clamped_end, le(t.value, ZERO_VEC)),
+        }
+    }
+
+    // Circ
+
+    #[inline]
+    pub fn ease_in_circ(t: f32) -> f32 {
+        1.0 - (1.0 - t * t).sqrt()
+    }
+
+    #[inline]
+    pub fn ease_out_circ(t: f32) -> f32 {
+        let t_min_one = t - 1.0;
+
+        (1.0 - t_min_one * t_min_one).sqrt()
+    }
+
+    #[inline]
+    pub fn ease_in_out_circ(t: f32) -> f32 {
+        if t < 0.5 {
+            let t_mul_two = 2.0 * t;
+
+            (1.0 - (1.0 - t_mul_two * t_mul_two).sqrt()) * 0.5
+        } else {
+            let inv_t = -2.0 * t + 2.0;
+
+            ((1.0 - inv_t * inv_t).sqrt() + 1.0) * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_circ_vec4(t: Vector4) -> Vector4 {
+        Vector4 {
+            value: ONE_VEC - sqrt(ONE_VEC - t.value * t.value),
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_circ_vec4(t: Vector4) -> Vector4 {
+        let t_min_one = t.value - ONE_VEC;
+
+        Vector4 {
+            value: sqrt(ONE_VEC - t_min_one * t_min_one),
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_circ_vec4(t: Vector4) -> Vector4 {
+        let t_mul_two = t.value * TWO_VEC;
+        let inv_t = TWO_VEC - t_mul_two;
+
+        let lhs = (ONE_VEC - sqrt(ONE_VEC - t_mul_two * t_mul_two)) * HALF_VEC;
+        let rhs = (sqrt(ONE_VEC - inv_t * inv_t) + ONE_VEC) * HALF_VEC;
+
+        Vector4 {
+            value: select(lhs, rhs, lt(t.value, HALF_VEC)),
+        }
+    }
+
+    // Back
+
+    #[inline]
+    pub fn ease_in_back(t: f32) -> f32 {
+        let t_sqr = t * t;
+
+        BACK_C3 * t_sqr * t - BACK_C1 * t_sqr
+    }
+
+    #[inline]
+    pub fn ease_out_back(t: f32) -> f32 {
+        let t_min_one = t - 1.0;
+        let t_min_one_sqr = t_min_one * t_min_one;
+
+        1.0 + BACK_C3 * t_min_one_sqr * t_min_one + BACK_C1 * t_min_one_sqr
+    }
+
+    #[inline]
+    pub fn ease_in_out_back(t: f32) -> f32 {
+        let t_mul_two = 2.0 * t;
+
+        if t < 0.5 {
+            (t_mul_two * t_mul_two * ((BACK_C2 + 1.0) * t_mul_two - BACK_C2)) * 0.5
+        } else {
+            let t_mul_two_min_two = t_mul_two - 2.0;
+
+            (t_mul_two_min_two * t_mul_two_min_two
+                * ((BACK_C2 + 1.0) * t_mul_two_min_two + BACK_C2)
+                + 2.0)
+                * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_back_vec4(t: Vector4) -> Vector4 {
+        const C1_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([BACK_C1; 4]);
+        const C3_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([BACK_C3; 4]);
+
+        let t_sqr = t.value * t.value;
+
+        Vector4 {
+            value: C3_VEC * t_sqr * t.value - C1_VEC * t_sqr,
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_back_vec4(t: Vector4) -> Vector4 {
+        const C1_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([BACK_C1; 4]);
+        const C3_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([BACK_C3; 4]);
+
+        let t_min_one = t.value - ONE_VEC;
+        let t_min_one_sqr = t_min_one * t_min_one;
+
+        Vector4 {
+            value: ONE_VEC + C3_VEC * t_min_one_sqr * t_min_one + C1_VEC * t_min_one_sqr,
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_back_vec4(t: Vector4) -> Vector4 {
+        const C2_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([BACK_C2; 4]);
+        const C2_ONE_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([BACK_C2 + 1.0; 4]);
+
+        let t_mul_two = t.value * TWO_VEC;
+        let t_mul_two_min_two = t_mul_two - TWO_VEC;
+
+        let lhs = (t_mul_two * t_mul_two * (C2_ONE_VEC * t_mul_two - C2_VEC)) * HALF_VEC;
+        let rhs = (t_mul_two_min_two * t_mul_two_min_two
+            * (C2_ONE_VEC * t_mul_two_min_two + C2_VEC)
+            + TWO_VEC)
+            * HALF_VEC;
+
+        Vector4 {
+            value: select(lhs, rhs, lt(t.value, HALF_VEC)),
+        }
+    }
+
+    // Elastic
+
+    #[inline]
+    pub fn ease_in_elastic(t: f32) -> f32 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            -(10.0 * t - 10.0).exp2() * ((t * 10.0 - 10.75) * ELASTIC_C4).sin()
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_elastic(t: f32) -> f32 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            (-10.0 * t).exp2() * ((t * 10.0 - 0.75) * ELASTIC_C4).sin() + 1.0
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_elastic(t: f32) -> f32 {
+        if t <= 0.0 {
+            0.0
+        } else if t >= 1.0 {
+            1.0
+        } else {
+            let wave = ((20.0 * t - 11.125) * ELASTIC_C5).sin();
+
+            if t < 0.5 {
+                -((20.0 * t - 10.0).exp2() * wave) * 0.5
+            } else {
+                ((-20.0 * t + 10.0).exp2() * wave) * 0.5 + 1.0
+            }
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_elastic_vec4(t: Vector4) -> Vector4 {
+        const TEN_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([10.0; 4]);
+        const PHASE_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([10.75; 4]);
+        const C4_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([ELASTIC_C4; 4]);
+
+        let curve =
+            -exp2(TEN_VEC * t.value - TEN_VEC) * sin((t.value * TEN_VEC - PHASE_VEC) * C4_VEC);
+
+        let clamped_end = select(ONE_VEC, curve, ge(t.value, ONE_VEC));
+
+        Vector4 {
+            value: select(ZERO_VEC, clamped_end, le(t.value, ZERO_VEC)),
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_elastic_vec4(t: Vector4) -> Vector4 {
+        const TEN_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([10.0; 4]);
+        const PHASE_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([0.75; 4]);
+        const C4_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([ELASTIC_C4; 4]);
+
+        let curve =
+            exp2(-TEN_VEC * t.value) * sin((t.value * TEN_VEC - PHASE_VEC) * C4_VEC) + ONE_VEC;
+
+        let clamped_end = select(ONE_VEC, curve, ge(t.value, ONE_VEC));
+
+        Vector4 {
+            value: select(ZERO_VEC, clamped_end, le(t.value, ZERO_VEC)),
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_elastic_vec4(t: Vector4) -> Vector4 {
+        const TEN_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([10.0; 4]);
+        const TWENTY_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([20.0; 4]);
+        const PHASE_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([11.125; 4]);
+        const C5_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([ELASTIC_C5; 4]);
+
+        let t_mul_twenty = TWENTY_VEC * t.value;
+
+        let wave = sin((t_mul_twenty - PHASE_VEC) * C5_VEC);
+
+        let lhs = -(exp2(t_mul_twenty - TEN_VEC) * wave) * HALF_VEC;
+        let rhs = (exp2(TEN_VEC - t_mul_twenty) * wave) * HALF_VEC + ONE_VEC;
+
+        let curve = select(lhs, rhs, lt(t.value, HALF_VEC));
+        let clamped_end = select(ONE_VEC, curve, ge(t.value, ONE_VEC));
+
+        Vector4 {
+            value: select(ZERO_VEC, clamped_end, le(t.value, ZERO_VEC)),
+        }
+    }
+
+    // Bounce
+
+    #[inline]
+    pub fn ease_in_bounce(t: f32) -> f32 {
+        1.0 - ease_out_bounce(1.0 - t)
+    }
+
+    #[inline]
+    pub fn ease_out_bounce(t: f32) -> f32 {
+        if t < 1.0 / BOUNCE_D1 {
+            BOUNCE_N1 * t * t
+        } else if t < 2.0 / BOUNCE_D1 {
+            let offset_t = t - 1.5 / BOUNCE_D1;
+
+            BOUNCE_N1 * offset_t * offset_t + 0.75
+        } else if t < 2.5 / BOUNCE_D1 {
+            let offset_t = t - 2.25 / BOUNCE_D1;
+
+            BOUNCE_N1 * offset_t * offset_t + 0.9375
+        } else {
+            let offset_t = t - 2.625 / BOUNCE_D1;
+
+            BOUNCE_N1 * offset_t * offset_t + 0.984375
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_bounce(t: f32) -> f32 {
+        if t < 0.5 {
+            (1.0 - ease_out_bounce(1.0 - 2.0 * t)) * 0.5
+        } else {
+            (1.0 + ease_out_bounce(2.0 * t - 1.0)) * 0.5
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_bounce_vec4(t: Vector4) -> Vector4 {
+        let bounce = ease_out_bounce_vec4(Vector4 {
+            value: ONE_VEC - t.value,
+        });
+
+        Vector4 {
+            value: ONE_VEC - bounce.value,
+        }
+    }
+
+    #[inline]
+    pub fn ease_out_bounce_vec4(t: Vector4) -> Vector4 {
+        const N1_VEC: std::simd::f32x4 = std::simd::f32x4::from_array([BOUNCE_N1; 4]);
+
+        const FIRST_EDGE: std::simd::f32x4 = std::simd::f32x4::from_array([1.0 / BOUNCE_D1; 4]);
+        const SECOND_EDGE: std::simd::f32x4 = std::simd::f32x4::from_array([2.0 / BOUNCE_D1; 4]);
+        const THIRD_EDGE: std::simd::f32x4 = std::simd::f32x4::from_array([2.5 / BOUNCE_D1; 4]);
+
+        const SECOND_OFFSET: std::simd::f32x4 =
+            std::simd::f32x4::from_array([1.5 / BOUNCE_D1; 4]);
+        const THIRD_OFFSET: std::simd::f32x4 =
+            std::simd::f32x4::from_array([2.25 / BOUNCE_D1; 4]);
+        const FOURTH_OFFSET: std::simd::f32x4 =
+            std::simd::f32x4::from_array([2.625 / BOUNCE_D1; 4]);
+
+        const SECOND_BIAS: std::simd::f32x4 = std::simd::f32x4::from_array([0.75; 4]);
+        const THIRD_BIAS: std::simd::f32x4 = std::simd::f32x4::from_array([0.9375; 4]);
+        const FOURTH_BIAS: std::simd::f32x4 = std::simd::f32x4::from_array([0.984375; 4]);
+
+        let second_t = t.value - SECOND_OFFSET;
+        let third_t = t.value - THIRD_OFFSET;
+        let fourth_t = t.value - FOURTH_OFFSET;
+
+        let first = N1_VEC * t.value * t.value;
+        let second = N1_VEC * second_t * second_t + SECOND_BIAS;
+        let third = N1_VEC * third_t * third_t + THIRD_BIAS;
+        let fourth = N1_VEC * fourth_t * fourth_t + FOURTH_BIAS;
+
+        let third_or_fourth = select(third, fourth, lt(t.value, THIRD_EDGE));
+        let second_or_rest = select(second, third_or_fourth, lt(t.value, SECOND_EDGE));
+
+        Vector4 {
+            value: select(first, second_or_rest, lt(t.value, FIRST_EDGE)),
+        }
+    }
+
+    #[inline]
+    pub fn ease_in_out_bounce_vec4(t: Vector4) -> Vector4 {
+        let t_mul_two = t.value * TWO_VEC;
+
+        let lhs_bounce = ease_out_bounce_vec4(Vector4 {
+            value: ONE_VEC - t_mul_two,
+        });
+        let rhs_bounce = ease_out_bounce_vec4(Vector4 {
+            value: t_mul_two - ONE_VEC,
+        });
+
+        let lhs = (ONE_VEC - lhs_bounce.value) * HALF_VEC;
+        let rhs = (ONE_VEC + rhs_bounce.value) * HALF_VEC;
+
+        Vector4 {
+            value: select(lhs, rhs, lt(t.value, HALF_VEC)),
+        }
+    }
+}
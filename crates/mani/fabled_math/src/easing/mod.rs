@@ -1,2 +1,7 @@
+// linear interpolation, spherical interpolation, normalized linear
+// interpolation
 mod ease;
 mod remap;
+
+pub use ease::*;
+pub use remap::*;
@@ -0,0 +1,78 @@
+pub mod remap_math {
+    use crate::{EaseFunction, Vector4};
+
+    use crate::vector_math::{lerp, saturate, unlerp};
+
+    #[inline]
+    pub fn remap(value: f32, from_min: f32, from_max: f32, to_min: f32, to_max: f32) -> f32 {
+        let t = (value - from_min) / (from_max - from_min);
+
+        to_min + (to_max - to_min) * t
+    }
+
+    #[inline]
+    pub fn remap_clamped(
+        value: f32,
+        from_min: f32,
+        from_max: f32,
+        to_min: f32,
+        to_max: f32,
+    ) -> f32 {
+        let t = ((value - from_min) / (from_max - from_min)).clamp(0.0, 1.0);
+
+        to_min + (to_max - to_min) * t
+    }
+
+    #[inline]
+    pub fn remap_vec4(
+        value: Vector4,
+        from_min: Vector4,
+        from_max: Vector4,
+        to_min: Vector4,
+        to_max: Vector4,
+    ) -> Vector4 {
+        let t = unlerp(value.value, from_min.value, from_max.value);
+
+        Vector4 {
+            value: lerp(to_min.value, to_max.value, t),
+        }
+    }
+
+    #[inline]
+    pub fn remap_clamped_vec4(
+        value: Vector4,
+        from_min: Vector4,
+        from_max: Vector4,
+        to_min: Vector4,
+        to_max: Vector4,
+    ) -> Vector4 {
+        let t = saturate(unlerp(value.value, from_min.value, from_max.value));
+
+        Vector4 {
+            value: lerp(to_min.value, to_max.value, t),
+        }
+    }
+
+    #[inline]
+    pub fn ease(start: f32, end: f32, t: f32, ease_function: EaseFunction) -> f32 {
+        let eased_t = ease_function.evaluate(t.clamp(0.0, 1.0));
+
+        start + (end - start) * eased_t
+    }
+
+    #[inline]
+    pub fn ease_vec4(
+        start: Vector4,
+        end: Vector4,
+        t: Vector4,
+        ease_function: EaseFunction,
+    ) -> Vector4 {
+        let eased_t = ease_function.evaluate_vec4(Vector4 {
+            value: saturate(t.value),
+        });
+
+        Vector4 {
+            value: lerp(start.value, end.value, eased_t.value),
+        }
+    }
+}
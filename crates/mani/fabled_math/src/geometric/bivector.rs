@@ -0,0 +1,208 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Stored as (xy, xz, yz, 0) where each lane is the projected area of the
+// oriented plane onto the matching basis plane.
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Bivector {
+    pub value: std::simd::f32x4,
+}
+
+impl Default for Bivector {
+    fn default() -> Self {
+        Bivector::ZERO
+    }
+}
+
+impl Bivector {
+    pub const ZERO: Bivector = Bivector {
+        value: std::simd::f32x4::from_array([0.0; 4]),
+    };
+
+    pub const XY: Bivector = Bivector {
+        value: std::simd::f32x4::from_array([1.0, 0.0, 0.0, 0.0]),
+    };
+
+    pub const XZ: Bivector = Bivector {
+        value: std::simd::f32x4::from_array([0.0, 1.0, 0.0, 0.0]),
+    };
+
+    pub const YZ: Bivector = Bivector {
+        value: std::simd::f32x4::from_array([0.0, 0.0, 1.0, 0.0]),
+    };
+
+    #[inline(always)]
+    pub const fn set(xy: f32, xz: f32, yz: f32) -> Bivector {
+        Bivector {
+            value: std::simd::f32x4::from_array([xy, xz, yz, 0.0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: f32) -> Bivector {
+        Bivector {
+            value: std::simd::f32x4::from_array([val, val, val, 0.0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn xy(self) -> f32 {
+        let array_bivector: [f32; 4] = self.value.to_array();
+
+        array_bivector[0]
+    }
+
+    #[inline(always)]
+    pub const fn xz(self) -> f32 {
+        let array_bivector: [f32; 4] = self.value.to_array();
+
+        array_bivector[1]
+    }
+
+    #[inline(always)]
+    pub const fn yz(self) -> f32 {
+        let array_bivector: [f32; 4] = self.value.to_array();
+
+        array_bivector[2]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f32; 3] {
+        [self.xy(), self.xz(), self.yz()]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f32; 3]) -> Bivector {
+        Bivector::set(array[0], array[1], array[2])
+    }
+}
+
+impl Display for Bivector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Bivector (xy : {}, xz : {}, yz : {})",
+            self.xy(),
+            self.xz(),
+            self.yz()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<f32> for Bivector {
+    type Output = Bivector;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        let splat_f32x4: std::simd::f32x4 = std::simd::f32x4::splat(rhs);
+
+        Bivector {
+            value: self.value * splat_f32x4,
+        }
+    }
+}
+
+impl MulAssign<f32> for Bivector {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        let splat_f32x4: std::simd::f32x4 = std::simd::f32x4::splat(rhs);
+
+        self.value *= splat_f32x4;
+    }
+}
+
+// Bivector-Wise
+impl Add for Bivector {
+    type Output = Bivector;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Bivector {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign for Bivector {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub for Bivector {
+    type Output = Bivector;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Bivector {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign for Bivector {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl Neg for Bivector {
+    type Output = Bivector;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Bivector { value: -self.value }
+    }
+}
+
+pub mod bivector_math {
+    use crate::{Bivector, Vector3};
+
+    use crate::vector_math::{dot, length, length_squared, normalize};
+
+    #[inline]
+    pub fn wedge_vec3(lhs: Vector3, rhs: Vector3) -> Bivector {
+        Bivector::set(
+            lhs.x() * rhs.y() - lhs.y() * rhs.x(),
+            lhs.x() * rhs.z() - lhs.z() * rhs.x(),
+            lhs.y() * rhs.z() - lhs.z() * rhs.y(),
+        )
+    }
+
+    #[inline]
+    pub fn dot_bivector(lhs: Bivector, rhs: Bivector) -> f32 {
+        dot(lhs.value, rhs.value)
+    }
+
+    #[inline]
+    pub fn magnitude_bivector(bivector: Bivector) -> f32 {
+        length(bivector.value)
+    }
+
+    #[inline]
+    pub fn magnitude_sqr_bivector(bivector: Bivector) -> f32 {
+        length_squared(bivector.value)
+    }
+
+    #[inline]
+    pub fn normalize_bivector(bivector: Bivector) -> Bivector {
+        Bivector {
+            value: normalize(bivector.value),
+        }
+    }
+
+    // The normal of the plane, the bivector's hodge dual.
+    #[inline]
+    pub fn to_normal_vec3(bivector: Bivector) -> Vector3 {
+        Vector3::set(bivector.yz(), -bivector.xz(), bivector.xy())
+    }
+
+    #[inline]
+    pub fn from_normal_bivector(normal: Vector3) -> Bivector {
+        Bivector::set(normal.z(), -normal.y(), normal.x())
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{DVector3, Quaternion};
+
+use crate::dvector_math::{component_sum, cross};
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct DQuaternion {
+    pub value: std::simd::f64x4,
+}
+
+impl Default for DQuaternion {
+    fn default() -> Self {
+        DQuaternion::IDENTITY
+    }
+}
+
+impl DQuaternion {
+    pub const IDENTITY: DQuaternion = DQuaternion {
+        value: std::simd::f64x4::from_array([0.0, 0.0, 0.0, 1.0]),
+    };
+
+    pub const ZERO: DQuaternion = DQuaternion {
+        value: std::simd::f64x4::from_array([0.0; 4]),
+    };
+
+    #[inline(always)]
+    pub const fn set(i: f64, j: f64, k: f64, w: f64) -> DQuaternion {
+        DQuaternion {
+            value: std::simd::f64x4::from_array([i, j, k, w]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn i(self) -> f64 {
+        let array_dquaternion: [f64; 4] = self.value.to_array();
+
+        array_dquaternion[0]
+    }
+
+    #[inline(always)]
+    pub const fn j(self) -> f64 {
+        let array_dquaternion: [f64; 4] = self.value.to_array();
+
+        array_dquaternion[1]
+    }
+
+    #[inline(always)]
+    pub const fn k(self) -> f64 {
+        let array_dquaternion: [f64; 4] = self.value.to_array();
+
+        array_dquaternion[2]
+    }
+
+    #[inline(always)]
+    pub const fn w(self) -> f64 {
+        let array_dquaternion: [f64; 4] = self.value.to_array();
+
+        array_dquaternion[3]
+    }
+
+    #[inline]
+    pub const fn to_pure(self) -> DVector3 {
+        DVector3::set(self.i(), self.j(), self.k())
+    }
+
+    #[inline]
+    pub const fn to_real(self) -> f64 {
+        self.w()
+    }
+
+    #[inline]
+    pub const fn from_additive_form(real: f64, pure: DVector3) -> DQuaternion {
+        DQuaternion::set(pure.x(), pure.y(), pure.z(), real)
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f64; 4] {
+        self.value.to_array()
+    }
+
+    #[inline]
+    pub const fn from_primitive(val: [f64; 4]) -> DQuaternion {
+        DQuaternion {
+            value: std::simd::f64x4::from_array(val),
+        }
+    }
+
+    #[inline]
+    pub fn as_quat(self) -> Quaternion {
+        Quaternion {
+            value: self.value.cast::<f32>(),
+        }
+    }
+}
+
+impl From<Quaternion> for DQuaternion {
+    #[inline]
+    fn from(value: Quaternion) -> Self {
+        DQuaternion {
+            value: value.value.cast::<f64>(),
+        }
+    }
+}
+
+impl Display for DQuaternion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DQuaternion (i : {}, j : {}, k : {}, w : {})",
+            self.i(),
+            self.j(),
+            self.k(),
+            self.w()
+        )
+    }
+}
+
+impl Mul<DQuaternion> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn mul(self, rhs: DQuaternion) -> Self::Output {
+        let t: std::simd::f64x4 = cross(rhs.value, self.value);
+
+        let lhs: DVector3 = DVector3 { value: t + t };
+
+        let intermediate: DVector3 = DVector3 {
+            value: cross(rhs.value, lhs.value),
+        };
+
+        lhs * rhs.to_real() + (intermediate + self)
+    }
+}
+
+impl MulAssign<DQuaternion> for DVector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DQuaternion) {
+        self.value = (*self * rhs).value;
+    }
+}
+
+// Component-Wise
+impl Mul<f64> for DQuaternion {
+    type Output = DQuaternion;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DQuaternion {
+            value: self.value * splat_f64x4,
+        }
+    }
+}
+
+impl MulAssign<f64> for DQuaternion {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value *= splat_f64x4;
+    }
+}
+
+// DQuaternion-Wise
+impl Add for DQuaternion {
+    type Output = DQuaternion;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        DQuaternion {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign for DQuaternion {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub for DQuaternion {
+    type Output = DQuaternion;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        DQuaternion {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign for DQuaternion {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl Mul for DQuaternion {
+    type Output = DQuaternion;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let lhs_pure_vector: DVector3 = self.to_pure();
+        let rhs_pure_vector: DVector3 = rhs.to_pure();
+
+        let lhs_real: f64 = self.to_real();
+        let rhs_real: f64 = rhs.to_real();
+
+        DQuaternion::from_additive_form(
+            (lhs_real * rhs_real) - component_sum(lhs_pure_vector.value * rhs_pure_vector.value),
+            (rhs_pure_vector * lhs_real)
+                + (lhs_pure_vector * rhs_real)
+                + DVector3 {
+                    value: cross(lhs_pure_vector.value, rhs_pure_vector.value),
+                },
+        )
+    }
+}
+
+impl MulAssign for DQuaternion {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for DQuaternion {
+    type Output = DQuaternion;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DQuaternion { value: -self.value }
+    }
+}
+
+pub mod dquaternion_math {
+    use crate::{DQuaternion, DVector3};
+
+    use crate::dvector_math::{dot, length_squared, lerp, normalize};
+
+    #[inline]
+    pub fn from_angle_axis_dquat(normalized_axis: DVector3, angle_radian: f64) -> DQuaternion {
+        let (sin_half_angle, cos_half_angle) = (angle_radian * 0.5).sin_cos();
+
+        DQuaternion::from_additive_form(cos_half_angle, normalized_axis * sin_half_angle)
+    }
+
+    #[inline]
+    pub fn conjugate_dquat(quaternion: DQuaternion) -> DQuaternion {
+        DQuaternion::set(
+            -quaternion.i(),
+            -quaternion.j(),
+            -quaternion.k(),
+            quaternion.w(),
+        )
+    }
+
+    #[inline]
+    pub fn inverse_dquat(quaternion: DQuaternion) -> DQuaternion {
+        conjugate_dquat(quaternion) * length_squared(quaternion.value).recip()
+    }
+
+    #[inline]
+    pub fn normalize_dquat(quaternion: DQuaternion) -> DQuaternion {
+        DQuaternion {
+            value: normalize(quaternion.value),
+        }
+    }
+
+    #[inline]
+    pub fn normalized_lerp_dquat(
+        start_quaternion: DQuaternion,
+        target_quaternion: DQuaternion,
+        delta: f64,
+    ) -> DQuaternion {
+        let mut target_quaternion: DQuaternion = target_quaternion;
+
+        if dot(start_quaternion.value, target_quaternion.value) < 0.0 {
+            target_quaternion = -target_quaternion;
+        }
+
+        DQuaternion {
+            value: normalize(lerp(
+                start_quaternion.value,
+                target_quaternion.value,
+                std::simd::f64x4::splat(delta),
+            )),
+        }
+    }
+}
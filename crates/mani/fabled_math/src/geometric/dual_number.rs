@@ -4,6 +4,7 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 
 #[derive(Copy, Clone, PartialEq, Default)]
+#[repr(C)]
 pub struct DualNumber {
     // real, img
     pub value: Vector2,
@@ -4,6 +4,7 @@ use std::fmt::Display;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 #[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
 pub struct DualQuaternion {
     pub real: Quaternion,
     pub dual: Quaternion,
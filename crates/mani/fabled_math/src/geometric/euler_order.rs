@@ -2,6 +2,7 @@ use crate::Vector4;
 
 #[non_exhaustive]
 #[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
 pub struct EulerOrder(pub(crate) Vector4);
 
 impl Default for EulerOrder {
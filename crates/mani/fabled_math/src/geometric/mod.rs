@@ -1,10 +1,16 @@
+mod bivector;
+mod dquaternion;
 mod quaternion;
 mod dual_quaternion;
 mod dual_number;
 mod euler_order;
+mod rotor;
 
+pub use bivector::*;
+pub use dquaternion::*;
 pub use quaternion::*;
 pub use quaternion::quaternion_math::*;
 pub use dual_number::*;
 pub use dual_quaternion::*;
 pub use euler_order::*;
+pub use rotor::*;
@@ -9,6 +9,7 @@ use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign, Neg, Div, DivAssi
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
 pub struct Quaternion {
     pub value: std::simd::f32x4,
 }
@@ -302,7 +303,8 @@ pub mod quaternion_math {
     use crate::Bool4;
 
     use crate::vector_math::{
-        component_sum, cos, dot, length, length_squared, lerp, normalize, rcp, select, sin, mul_add,
+        component_sum, cos, cross, dot, length, length_squared, lerp, normalize, orthogonal, rcp,
+        select, sin, mul_add,
     };
     use crate::{EulerOrder, Matrix3x3, Matrix4x4, Quaternion, Vector3, Vector4};
 
@@ -543,7 +545,27 @@ pub mod quaternion_math {
         target_quaternion: Quaternion,
         delta: f32,
     ) -> Quaternion {
-        let normalized_dot: f32 = dot(start_quaternion.value, target_quaternion.value);
+        let mut target_quaternion: Quaternion = target_quaternion;
+        let mut normalized_dot: f32 = dot(start_quaternion.value, target_quaternion.value);
+
+        // q and -q are the same rotation, flipping the target takes the shortest
+        // arc and keeps the lerp fallback away from the zero quaternion.
+        if normalized_dot < 0.0 {
+            target_quaternion = target_quaternion * -1.0;
+            normalized_dot = -normalized_dot;
+        }
+
+        if std::intrinsics::unlikely(normalized_dot > 0.9995) {
+            let linear_interpolated_quaternion: std::simd::f32x4 = lerp(
+                start_quaternion.value,
+                target_quaternion.value,
+                Vector4::broadcast(delta).value,
+            );
+
+            return Quaternion {
+                value: normalize(linear_interpolated_quaternion),
+            };
+        }
 
         let angle: f32 = normalized_dot.acos();
 
@@ -569,8 +591,14 @@ pub mod quaternion_math {
         let real_norm: f32 = (quaternion_real * quaternion_length.recip()).clamp(-1.0, 1.0);
         let arc_cos_real_norm: f32 = real_norm.acos();
 
-        let pure_quaternion: Vector3 =
-            quaternion_pure * length(quaternion_pure.value).recip() * arc_cos_real_norm;
+        let quaternion_pure_length: f32 = length(quaternion_pure.value);
+
+        let mut pure_quaternion: Vector3 =
+            quaternion_pure * quaternion_pure_length.recip() * arc_cos_real_norm;
+
+        if std::intrinsics::unlikely(quaternion_pure_length < f32::EPSILON) {
+            pure_quaternion = Vector3::ZERO;
+        }
         let real_quaternion: f32 = 0.5f32 * quaternion_length.log2();
 
         Quaternion::from_additive_form(real_quaternion, pure_quaternion)
@@ -584,14 +612,210 @@ pub mod quaternion_math {
 
         let (sin_pure_len, cos_pure_len) = quaternion_pure_length.sin_cos();
 
-        let pure_exp_quaternion: Vector3 =
+        let mut pure_exp_quaternion: Vector3 =
             quaternion_pure * quaternion_pure_length.recip() * sin_pure_len;
 
+        if std::intrinsics::unlikely(quaternion_pure_length < f32::EPSILON) {
+            pure_exp_quaternion = Vector3::ZERO;
+        }
+
         let real_exp_quaternion: f32 = cos_pure_len;
 
         Quaternion::from_additive_form(real_exp_quaternion, pure_exp_quaternion)
             * quaternion_real.exp()
     }
+
+    #[inline]
+    pub fn angle_between_quat(lhs_quaternion: Quaternion, rhs_quaternion: Quaternion) -> f32 {
+        let abs_dot: f32 = dot(lhs_quaternion.value, rhs_quaternion.value)
+            .abs()
+            .min(1.0);
+
+        2.0 * abs_dot.acos()
+    }
+
+    pub fn from_to_rotation_quat(from_vector: Vector3, to_vector: Vector3) -> Quaternion {
+        let from_normalized: std::simd::f32x4 = normalize(from_vector.value);
+        let to_normalized: std::simd::f32x4 = normalize(to_vector.value);
+
+        let cos_angle: f32 = dot(from_normalized, to_normalized);
+
+        // Opposite vectors, any axis perpendicular to the from vector is valid.
+        if std::intrinsics::unlikely(cos_angle < -0.999999) {
+            let axis: Vector3 = Vector3 {
+                value: normalize(orthogonal(from_normalized)),
+            };
+
+            return from_angle_axis_quat(axis, std::f32::consts::PI);
+        }
+
+        let quaternion_pure: Vector3 = Vector3 {
+            value: cross(from_normalized, to_normalized),
+        };
+
+        Quaternion {
+            value: normalize(Quaternion::from_additive_form(1.0 + cos_angle, quaternion_pure).value),
+        }
+    }
+
+    pub fn look_rotation_quat(forward: Vector3, up: Vector3) -> Quaternion {
+        let forward_normalized: Vector3 = Vector3 {
+            value: normalize(forward.value),
+        };
+
+        let right: std::simd::f32x4 = cross(up.value, forward_normalized.value);
+
+        // Up is parallel to forward, so there is no unique roll.
+        if std::intrinsics::unlikely(length_squared(right) < f32::EPSILON) {
+            return from_to_rotation_quat(Vector3::FORWARD, forward_normalized);
+        }
+
+        let right_normalized: Vector3 = Vector3 {
+            value: normalize(right),
+        };
+
+        let up_orthogonal: Vector3 = Vector3 {
+            value: cross(forward_normalized.value, right_normalized.value),
+        };
+
+        from_rotation_matrix_quat(Matrix3x3::set(
+            right_normalized,
+            up_orthogonal,
+            forward_normalized,
+        ))
+    }
+
+    pub fn rotate_towards_quat(
+        from_quaternion: Quaternion,
+        to_quaternion: Quaternion,
+        max_radians_delta: f32,
+    ) -> Quaternion {
+        let angle: f32 = angle_between_quat(from_quaternion, to_quaternion);
+
+        if std::intrinsics::unlikely(angle <= max_radians_delta) {
+            return to_quaternion;
+        }
+
+        let to_quaternion: Quaternion = Quaternion {
+            value: select(
+                to_quaternion.value,
+                -to_quaternion.value,
+                Bool4::broadcast(dot(from_quaternion.value, to_quaternion.value).ge(&0.0)).value,
+            ),
+        };
+
+        slerp_quat(from_quaternion, to_quaternion, max_radians_delta / angle)
+    }
+
+    // Returns (swing, twist) where quaternion = swing * twist and twist is the
+    // rotation about the normalized twist axis.
+    pub fn to_swing_twist_quat(
+        quaternion: Quaternion,
+        normalized_twist_axis: Vector3,
+    ) -> (Quaternion, Quaternion) {
+        let projected_pure: Vector3 =
+            normalized_twist_axis * dot(quaternion.to_pure().value, normalized_twist_axis.value);
+
+        let unnormalized_twist: Quaternion =
+            Quaternion::from_additive_form(quaternion.to_real(), projected_pure);
+
+        let mut twist: Quaternion = Quaternion::IDENTITY;
+
+        if std::intrinsics::likely(length_squared(unnormalized_twist.value) > f32::EPSILON) {
+            twist = Quaternion {
+                value: normalize(unnormalized_twist.value),
+            };
+        }
+
+        let swing: Quaternion = quaternion * conjugate_quat(twist);
+
+        (swing, twist)
+    }
+
+    // The inner control point for the quaternion at current, used as the
+    // start or end tangent by squad_quat.
+    pub fn squad_tangent_quat(
+        previous_quaternion: Quaternion,
+        current_quaternion: Quaternion,
+        next_quaternion: Quaternion,
+    ) -> Quaternion {
+        let current_inverse: Quaternion = conjugate_quat(current_quaternion);
+
+        // Neighbours stored in the other hemisphere would put their log past a
+        // half turn and throw the tangent far off the curve.
+        let same_hemisphere = |quaternion: Quaternion| -> Quaternion {
+            if dot(current_quaternion.value, quaternion.value) < 0.0 {
+                quaternion * -1.0
+            } else {
+                quaternion
+            }
+        };
+
+        let log_next: Quaternion = log_quat(current_inverse * same_hemisphere(next_quaternion));
+        let log_previous: Quaternion =
+            log_quat(current_inverse * same_hemisphere(previous_quaternion));
+
+        let tangent: Vector3 = (log_next.to_pure() + log_previous.to_pure()) * -0.25;
+
+        current_quaternion * exp_quat(Quaternion::from_additive_form(0.0, tangent))
+    }
+
+    // slerp_quat without the shortest arc flip, the caller picks the
+    // hemisphere. A flip part way through a squad segment makes it jump.
+    #[inline]
+    fn slerp_unflipped_quat(
+        start_quaternion: Quaternion,
+        target_quaternion: Quaternion,
+        delta: f32,
+    ) -> Quaternion {
+        let normalized_dot: f32 = dot(start_quaternion.value, target_quaternion.value);
+
+        if std::intrinsics::unlikely(normalized_dot > 0.9995) {
+            let linear_interpolated_quaternion: std::simd::f32x4 = lerp(
+                start_quaternion.value,
+                target_quaternion.value,
+                Vector4::broadcast(delta).value,
+            );
+
+            return Quaternion {
+                value: normalize(linear_interpolated_quaternion),
+            };
+        }
+
+        let angle: f32 = normalized_dot.clamp(-1.0, 1.0).acos();
+
+        let rcp_sin_angle: f32 = angle.sin().recip();
+
+        let weight_start: f32 = ((1.0 - delta) * angle).sin() * rcp_sin_angle;
+        let weight_target: f32 = (delta * angle).sin() * rcp_sin_angle;
+
+        (start_quaternion * weight_start) + (target_quaternion * weight_target)
+    }
+
+    // https://www.geometrictools.com/Documentation/Quaternions.pdf
+    // The target key and its tangent are moved to the hemisphere of the start
+    // key once, every slerp after that keeps its sign so the curve stays
+    // continuous.
+    pub fn squad_quat(
+        start_quaternion: Quaternion,
+        target_quaternion: Quaternion,
+        start_tangent: Quaternion,
+        target_tangent: Quaternion,
+        delta: f32,
+    ) -> Quaternion {
+        let (target_quaternion, target_tangent): (Quaternion, Quaternion) =
+            if dot(start_quaternion.value, target_quaternion.value) < 0.0 {
+                (target_quaternion * -1.0, target_tangent * -1.0)
+            } else {
+                (target_quaternion, target_tangent)
+            };
+
+        let outer: Quaternion = slerp_unflipped_quat(start_quaternion, target_quaternion, delta);
+        let inner: Quaternion = slerp_unflipped_quat(start_tangent, target_tangent, delta);
+
+        slerp_unflipped_quat(outer, inner, 2.0 * delta * (1.0 - delta))
+    }
+
 }
 
 
@@ -2372,4 +2596,184 @@ impl QuaternionSwizzles for Quaternion {
     fn wwww(self) -> Self {
         Quaternion::broadcast(self.w())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod quaternion_test {
+    use crate::approx::{approx_quat, approx_vec3, THRESHOLD};
+    use crate::quaternion_math::*;
+    use crate::vector_math::{dot, length};
+    use crate::{Quaternion, Vector3};
+
+    #[test]
+    fn slerp_opposite_hemisphere() {
+        let quaternion: Quaternion = from_angle_axis_quat(Vector3::UP, 0.7);
+
+        let halfway: Quaternion = slerp_quat(quaternion, quaternion * -1.0, 0.5);
+
+        assert!(halfway.value.to_array().iter().all(|v| v.is_finite()));
+        assert!(approx_quat(halfway, quaternion));
+
+        // Slightly apart across the hemispheres still lands between the two.
+        let target: Quaternion = from_angle_axis_quat(Vector3::UP, 0.702) * -1.0;
+
+        assert!(approx_quat(
+            slerp_quat(quaternion, target, 0.5),
+            from_angle_axis_quat(Vector3::UP, 0.701)
+        ));
+    }
+
+    #[test]
+    fn angle_between() {
+        let angle: f32 = angle_between_quat(Quaternion::IDENTITY, rotate_y_quat(1.0));
+        assert!((angle - 1.0).abs() <= THRESHOLD);
+
+        let double_cover: f32 = angle_between_quat(rotate_x_quat(0.3), -rotate_x_quat(0.3));
+        assert!(double_cover.abs() <= 0.001);
+    }
+
+    #[test]
+    fn from_to_rotation() {
+        let quaternion: Quaternion = from_to_rotation_quat(Vector3::RIGHT, Vector3::UP);
+        let reference: Quaternion = Quaternion::set(0.0, 0.0, 0.70710677, 0.70710677);
+
+        assert!(approx_quat(quaternion, reference));
+
+        let opposite: Quaternion = from_to_rotation_quat(Vector3::RIGHT, Vector3::LEFT);
+        assert!(approx_vec3(Vector3::RIGHT * opposite, Vector3::LEFT));
+
+        let from: Vector3 = Vector3::set(0.3, -2.0, 1.0);
+        let to: Vector3 = Vector3::set(-1.0, 0.5, 4.0);
+        let rotated: Vector3 = from * from_to_rotation_quat(from, to);
+
+        assert!(approx_vec3(
+            rotated * (length(to.value) / length(from.value)),
+            to
+        ));
+    }
+
+    #[test]
+    fn look_rotation() {
+        assert!(approx_quat(
+            look_rotation_quat(Vector3::FORWARD, Vector3::UP),
+            Quaternion::IDENTITY
+        ));
+
+        let reference: Quaternion = Quaternion::set(0.0, 0.70710677, 0.0, 0.70710677);
+        assert!(approx_quat(
+            look_rotation_quat(Vector3::RIGHT, Vector3::UP),
+            reference
+        ));
+
+        let forward: Vector3 = Vector3::set(0.48, 0.6, 0.64);
+        let quaternion: Quaternion = look_rotation_quat(forward, Vector3::UP);
+
+        assert!(approx_vec3(forward_vec3(quaternion), forward));
+        assert!(up_vec3(quaternion).y() > 0.0);
+        assert!(right_vec3(quaternion).y().abs() <= THRESHOLD);
+    }
+
+    #[test]
+    fn rotate_towards() {
+        let target: Quaternion = rotate_y_quat(1.0);
+
+        assert!(approx_quat(
+            rotate_towards_quat(Quaternion::IDENTITY, target, 0.25),
+            rotate_y_quat(0.25)
+        ));
+        assert!(approx_quat(
+            rotate_towards_quat(Quaternion::IDENTITY, target, 2.0),
+            target
+        ));
+    }
+
+    #[test]
+    fn swing_twist() {
+        let swing_reference: Quaternion = rotate_x_quat(0.5);
+        let twist_reference: Quaternion = rotate_y_quat(0.7);
+
+        let (swing, twist) = to_swing_twist_quat(swing_reference * twist_reference, Vector3::UP);
+
+        assert!(approx_quat(swing, swing_reference));
+        assert!(approx_quat(twist, twist_reference));
+
+        let (swing, twist) = to_swing_twist_quat(rotate_x_quat(1.0), Vector3::UP);
+
+        assert!(approx_quat(swing, rotate_x_quat(1.0)));
+        assert!(approx_quat(twist, Quaternion::IDENTITY));
+    }
+
+    #[test]
+    fn squad() {
+        let keys: [Quaternion; 4] = [
+            rotate_y_quat(0.0),
+            rotate_y_quat(0.5),
+            rotate_y_quat(1.0),
+            rotate_y_quat(1.5),
+        ];
+
+        let start_tangent: Quaternion = squad_tangent_quat(keys[0], keys[1], keys[2]);
+        let target_tangent: Quaternion = squad_tangent_quat(keys[1], keys[2], keys[3]);
+
+        assert!(approx_quat(
+            squad_quat(keys[1], keys[2], start_tangent, target_tangent, 0.0),
+            keys[1]
+        ));
+        assert!(approx_quat(
+            squad_quat(keys[1], keys[2], start_tangent, target_tangent, 1.0),
+            keys[2]
+        ));
+        assert!(approx_quat(
+            squad_quat(keys[1], keys[2], start_tangent, target_tangent, 0.5),
+            rotate_y_quat(0.75)
+        ));
+    }
+
+    #[test]
+    fn squad_is_continuous() {
+        // Every third key is stored in the opposite hemisphere.
+        let keys: Vec<Quaternion> = (0..7)
+            .map(|index| {
+                let key: Quaternion =
+                    rotate_x_quat(index as f32 * 0.3) * rotate_y_quat(index as f32 * 0.3);
+
+                if index % 3 == 0 {
+                    key * -1.0
+                } else {
+                    key
+                }
+            })
+            .collect();
+
+        // Angle of the rotation taking one quaternion to the other.
+        let rotation_angle = |lhs: Quaternion, rhs: Quaternion| -> f32 {
+            2.0 * dot(lhs.value, rhs.value).abs().min(1.0).acos()
+        };
+
+        let mut previous: Quaternion = keys[1];
+
+        for segment in 1..keys.len() - 2 {
+            let start_tangent: Quaternion =
+                squad_tangent_quat(keys[segment - 1], keys[segment], keys[segment + 1]);
+            let target_tangent: Quaternion =
+                squad_tangent_quat(keys[segment], keys[segment + 1], keys[segment + 2]);
+
+            for step in 0..=200 {
+                let delta: f32 = step as f32 / 200.0;
+
+                let sample: Quaternion = squad_quat(
+                    keys[segment],
+                    keys[segment + 1],
+                    start_tangent,
+                    target_tangent,
+                    delta,
+                );
+
+                assert!(rotation_angle(sample, previous) < 0.1);
+
+                previous = sample;
+            }
+
+            assert!(rotation_angle(previous, keys[segment + 1]) < 0.001);
+        }
+    }
+}
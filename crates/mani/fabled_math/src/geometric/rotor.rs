@@ -0,0 +1,451 @@
+use crate::{Bivector, Vector3};
+
+use crate::rotor_math::rotate_vec3_rotor;
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Scalar and bivector part of the even sub-algebra of G(3).
+// Stored as (xy, xz, yz, scalar) to mirror the quaternion layout.
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Rotor {
+    pub value: std::simd::f32x4,
+}
+
+impl Default for Rotor {
+    fn default() -> Self {
+        Rotor::IDENTITY
+    }
+}
+
+impl Rotor {
+    pub const IDENTITY: Rotor = Rotor {
+        value: std::simd::f32x4::from_array([0.0, 0.0, 0.0, 1.0]),
+    };
+
+    pub const ZERO: Rotor = Rotor {
+        value: std::simd::f32x4::from_array([0.0; 4]),
+    };
+
+    #[inline(always)]
+    pub const fn set(xy: f32, xz: f32, yz: f32, scalar: f32) -> Rotor {
+        Rotor {
+            value: std::simd::f32x4::from_array([xy, xz, yz, scalar]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn xy(self) -> f32 {
+        let array_rotor: [f32; 4] = self.value.to_array();
+
+        array_rotor[0]
+    }
+
+    #[inline(always)]
+    pub const fn xz(self) -> f32 {
+        let array_rotor: [f32; 4] = self.value.to_array();
+
+        array_rotor[1]
+    }
+
+    #[inline(always)]
+    pub const fn yz(self) -> f32 {
+        let array_rotor: [f32; 4] = self.value.to_array();
+
+        array_rotor[2]
+    }
+
+    #[inline(always)]
+    pub const fn scalar(self) -> f32 {
+        let array_rotor: [f32; 4] = self.value.to_array();
+
+        array_rotor[3]
+    }
+
+    #[inline(always)]
+    pub const fn to_bivector(self) -> Bivector {
+        Bivector::set(self.xy(), self.xz(), self.yz())
+    }
+
+    #[inline(always)]
+    pub const fn from_additive_form(scalar: f32, bivector: Bivector) -> Rotor {
+        Rotor::set(bivector.xy(), bivector.xz(), bivector.yz(), scalar)
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f32; 4] {
+        self.value.to_array()
+    }
+
+    #[inline]
+    pub const fn from_primitive(val: [f32; 4]) -> Rotor {
+        Rotor {
+            value: std::simd::f32x4::from_array(val),
+        }
+    }
+}
+
+impl Display for Rotor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Rotor (xy : {}, xz : {}, yz : {}, scalar : {})",
+            self.xy(),
+            self.xz(),
+            self.yz(),
+            self.scalar()
+        )
+    }
+}
+
+impl Mul<Rotor> for Vector3 {
+    type Output = Vector3;
+
+    #[inline]
+    fn mul(self, rhs: Rotor) -> Self::Output {
+        rotate_vec3_rotor(rhs, self)
+    }
+}
+
+impl MulAssign<Rotor> for Vector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Rotor) {
+        self.value = rotate_vec3_rotor(rhs, *self).value;
+    }
+}
+
+// Component-Wise
+impl Mul<f32> for Rotor {
+    type Output = Rotor;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        let splat_f32x4: std::simd::f32x4 = std::simd::f32x4::splat(rhs);
+
+        Rotor {
+            value: self.value * splat_f32x4,
+        }
+    }
+}
+
+impl MulAssign<f32> for Rotor {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        let splat_f32x4: std::simd::f32x4 = std::simd::f32x4::splat(rhs);
+
+        self.value *= splat_f32x4;
+    }
+}
+
+// Rotor-Wise
+impl Add for Rotor {
+    type Output = Rotor;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Rotor {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign for Rotor {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub for Rotor {
+    type Output = Rotor;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Rotor {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign for Rotor {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+// Geometric product, the result applies rhs first then self.
+impl Mul for Rotor {
+    type Output = Rotor;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (lhs_xy, lhs_xz, lhs_yz, lhs_scalar) = (self.xy(), self.xz(), self.yz(), self.scalar());
+        let (rhs_xy, rhs_xz, rhs_yz, rhs_scalar) = (rhs.xy(), rhs.xz(), rhs.yz(), rhs.scalar());
+
+        Rotor::set(
+            lhs_xy * rhs_scalar + lhs_scalar * rhs_xy + lhs_yz * rhs_xz - lhs_xz * rhs_yz,
+            lhs_xz * rhs_scalar + lhs_scalar * rhs_xz - lhs_yz * rhs_xy + lhs_xy * rhs_yz,
+            lhs_yz * rhs_scalar + lhs_scalar * rhs_yz + lhs_xz * rhs_xy - lhs_xy * rhs_xz,
+            lhs_scalar * rhs_scalar - lhs_xy * rhs_xy - lhs_xz * rhs_xz - lhs_yz * rhs_yz,
+        )
+    }
+}
+
+impl MulAssign for Rotor {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.value = (*self * rhs).value;
+    }
+}
+
+impl Neg for Rotor {
+    type Output = Rotor;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Rotor { value: -self.value }
+    }
+}
+
+// https://marctenbosch.com/quaternions/
+pub mod rotor_math {
+    use crate::{Bivector, Matrix3x3, Quaternion, Rotor, Vector3, Vector4};
+
+    use crate::bivector_math::wedge_vec3;
+    use crate::quaternion_math::from_rotation_matrix_quat;
+    use crate::vector_math::{dot, length, lerp, normalize, orthogonal};
+
+    #[inline]
+    pub fn from_to_rotor(from_normalized: Vector3, to_normalized: Vector3) -> Rotor {
+        let scalar: f32 = 1.0 + dot(to_normalized.value, from_normalized.value);
+
+        // Opposite vectors have no unique plane, so pick any plane holding
+        // the from vector and rotate half a turn within it.
+        if std::intrinsics::unlikely(scalar < f32::EPSILON) {
+            let orthogonal_vector = Vector3 {
+                value: normalize(orthogonal(from_normalized.value)),
+            };
+
+            return Rotor::from_additive_form(
+                0.0,
+                wedge_vec3(orthogonal_vector, from_normalized),
+            );
+        }
+
+        let bivector: Bivector = wedge_vec3(to_normalized, from_normalized);
+
+        normalize_rotor(Rotor::from_additive_form(scalar, bivector))
+    }
+
+    #[inline]
+    pub fn from_plane_angle_rotor(normalized_plane: Bivector, angle_radian: f32) -> Rotor {
+        let (sin_half_angle, cos_half_angle) = (angle_radian * 0.5).sin_cos();
+
+        Rotor::from_additive_form(cos_half_angle, normalized_plane * -sin_half_angle)
+    }
+
+    #[inline]
+    pub fn reverse_rotor(rotor: Rotor) -> Rotor {
+        Rotor::set(-rotor.xy(), -rotor.xz(), -rotor.yz(), rotor.scalar())
+    }
+
+    #[inline]
+    pub fn normalize_rotor(rotor: Rotor) -> Rotor {
+        Rotor {
+            value: normalize(rotor.value),
+        }
+    }
+
+    #[inline]
+    pub fn length_rotor(rotor: Rotor) -> f32 {
+        length(rotor.value)
+    }
+
+    #[inline]
+    pub fn dot_rotor(lhs: Rotor, rhs: Rotor) -> f32 {
+        dot(lhs.value, rhs.value)
+    }
+
+    // Sandwich product rotor * vector * reverse(rotor).
+    pub fn rotate_vec3_rotor(rotor: Rotor, vector: Vector3) -> Vector3 {
+        let (xy, xz, yz, scalar) = (rotor.xy(), rotor.xz(), rotor.yz(), rotor.scalar());
+        let (x, y, z) = (vector.x(), vector.y(), vector.z());
+
+        let q_x: f32 = scalar * x + y * xy + z * xz;
+        let q_y: f32 = scalar * y - x * xy + z * yz;
+        let q_z: f32 = scalar * z - x * xz - y * yz;
+
+        let q_trivector: f32 = x * yz - y * xz + z * xy;
+
+        Vector3::set(
+            scalar * q_x + q_y * xy + q_z * xz + q_trivector * yz,
+            scalar * q_y - q_x * xy - q_trivector * xz + q_z * yz,
+            scalar * q_z + q_trivector * xy - q_x * xz - q_y * yz,
+        )
+    }
+
+    pub fn slerp_rotor(start_rotor: Rotor, target_rotor: Rotor, delta: f32) -> Rotor {
+        let mut cos_angle: f32 = dot(start_rotor.value, target_rotor.value);
+
+        let mut target_rotor: Rotor = target_rotor;
+
+        // Take the shortest arc, a rotor and its negation rotate identically.
+        if cos_angle < 0.0 {
+            cos_angle = -cos_angle;
+            target_rotor = -target_rotor;
+        }
+
+        if std::intrinsics::unlikely(cos_angle > 1.0 - f32::EPSILON) {
+            return normalize_rotor(Rotor {
+                value: lerp(
+                    start_rotor.value,
+                    target_rotor.value,
+                    Vector4::broadcast(delta).value,
+                ),
+            });
+        }
+
+        let angle: f32 = cos_angle.acos();
+        let rcp_sin_angle: f32 = angle.sin().recip();
+
+        let weight_start: f32 = ((1.0 - delta) * angle).sin() * rcp_sin_angle;
+        let weight_target: f32 = (delta * angle).sin() * rcp_sin_angle;
+
+        (start_rotor * weight_start) + (target_rotor * weight_target)
+    }
+
+    #[inline]
+    pub fn from_quat_rotor(quaternion: Quaternion) -> Rotor {
+        Rotor::set(
+            -quaternion.k(),
+            quaternion.j(),
+            -quaternion.i(),
+            quaternion.w(),
+        )
+    }
+
+    #[inline]
+    pub fn to_quat_rotor(rotor: Rotor) -> Quaternion {
+        Quaternion::set(-rotor.yz(), rotor.xz(), -rotor.xy(), rotor.scalar())
+    }
+
+    #[inline]
+    pub fn to_mat3_rotor(rotor: Rotor) -> Matrix3x3 {
+        Matrix3x3::set(
+            rotate_vec3_rotor(rotor, Vector3::RIGHT),
+            rotate_vec3_rotor(rotor, Vector3::UP),
+            rotate_vec3_rotor(rotor, Vector3::FORWARD),
+        )
+    }
+
+    #[inline]
+    pub fn from_mat3_rotor(rotation_matrix: Matrix3x3) -> Rotor {
+        from_quat_rotor(from_rotation_matrix_quat(rotation_matrix))
+    }
+}
+
+#[cfg(test)]
+mod rotor_test {
+    use crate::approx::{approx_vec3, THRESHOLD};
+    use crate::bivector_math::{from_normal_bivector, wedge_vec3};
+    use crate::matrix3x3_math::from_quat_mat3;
+    use crate::quaternion_math::{from_angle_axis_quat, normalized_lerp_quat};
+    use crate::rotor_math::*;
+    use crate::vector_math::normalize;
+    use crate::{Bivector, Quaternion, Vector3};
+
+    #[test]
+    fn wedge_product() {
+        let bivector = wedge_vec3(Vector3::RIGHT, Vector3::UP);
+
+        assert!(bivector == Bivector::XY);
+
+        let anti_commutative = wedge_vec3(Vector3::UP, Vector3::RIGHT);
+
+        assert!(anti_commutative == -Bivector::XY);
+    }
+
+    #[test]
+    fn quaternion_round_trip() {
+        let axis = Vector3 {
+            value: normalize(Vector3::set(0.3, -1.2, 0.7).value),
+        };
+
+        let quaternion = from_angle_axis_quat(axis, 1.3);
+        let rotor = from_quat_rotor(quaternion);
+
+        assert!(to_quat_rotor(rotor) == quaternion);
+
+        let plane_rotor = from_plane_angle_rotor(from_normal_bivector(axis), 1.3);
+
+        for (lhs, rhs) in plane_rotor.to_primitive().iter().zip(rotor.to_primitive().iter()) {
+            assert!((lhs - rhs).abs() <= THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn rotate_matches_quaternion() {
+        let axis = Vector3 {
+            value: normalize(Vector3::set(1.0, 2.0, 3.0).value),
+        };
+
+        let quaternion = from_angle_axis_quat(axis, 2.1);
+        let rotor = from_quat_rotor(quaternion);
+
+        let point = Vector3::set(4.0, -2.0, 0.5);
+
+        assert!(approx_vec3(point * rotor, point * quaternion));
+
+        let rotor_matrix = to_mat3_rotor(rotor);
+        let quaternion_matrix = from_quat_mat3(quaternion);
+
+        assert!(approx_vec3(rotor_matrix.column_x, quaternion_matrix.column_x));
+        assert!(approx_vec3(rotor_matrix.column_y, quaternion_matrix.column_y));
+        assert!(approx_vec3(rotor_matrix.column_z, quaternion_matrix.column_z));
+    }
+
+    #[test]
+    fn composition_matches_quaternion() {
+        let lhs_quaternion = from_angle_axis_quat(Vector3::UP, 0.8);
+        let rhs_quaternion = from_angle_axis_quat(Vector3::RIGHT, -1.1);
+
+        let composed_rotor = from_quat_rotor(lhs_quaternion) * from_quat_rotor(rhs_quaternion);
+        let composed_quaternion: Quaternion = lhs_quaternion * rhs_quaternion;
+
+        let point = Vector3::set(-1.0, 0.25, 3.0);
+
+        assert!(approx_vec3(point * composed_rotor, point * composed_quaternion));
+    }
+
+    #[test]
+    fn from_to() {
+        let from = Vector3 {
+            value: normalize(Vector3::set(1.0, 1.0, 0.0).value),
+        };
+        let to = Vector3 {
+            value: normalize(Vector3::set(0.0, -1.0, 1.0).value),
+        };
+
+        assert!(approx_vec3(from * from_to_rotor(from, to), to));
+        assert!(approx_vec3(from * from_to_rotor(from, -from), -from));
+    }
+
+    #[test]
+    fn slerp_matches_quaternion() {
+        let start = from_angle_axis_quat(Vector3::FORWARD, 0.2);
+        let target = from_angle_axis_quat(Vector3::FORWARD, 1.4);
+
+        let rotor = slerp_rotor(from_quat_rotor(start), from_quat_rotor(target), 0.5);
+        let quaternion = normalized_lerp_quat(start, target, 0.5);
+
+        let point = Vector3::set(1.0, 2.0, 3.0);
+
+        assert!(approx_vec3(point * rotor, point * quaternion));
+        assert!(approx_vec3(
+            point * rotor,
+            point * from_angle_axis_quat(Vector3::FORWARD, 0.8)
+        ));
+    }
+}
@@ -0,0 +1,404 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Aabb {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl Default for Aabb {
+    fn default() -> Self {
+        Aabb {
+            min: Vector3::broadcast(-0.5),
+            max: Vector3::broadcast(0.5),
+        }
+    }
+}
+
+impl Aabb {
+    // Identity for union, min above max on every axis.
+    pub const EMPTY: Aabb = Aabb {
+        min: Vector3::broadcast(f32::INFINITY),
+        max: Vector3::broadcast(f32::NEG_INFINITY),
+    };
+
+    #[inline(always)]
+    pub const fn new(min: Vector3, max: Vector3) -> Aabb {
+        Aabb { min, max }
+    }
+}
+
+impl Display for Aabb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Aabb (min : {}, max : {})", self.min, self.max)
+    }
+}
+
+pub mod aabb_math {
+    use crate::{Aabb, Affine3, Capsule, Interval, Matrix4x4, Ray, Sphere, Vector3};
+
+    use crate::vector_math::{clamp, distance_squared, ge, le, max, min, rcp, sqrt};
+
+    #[inline]
+    pub fn from_center_extent_aabb(center: Vector3, half_extent: Vector3) -> Aabb {
+        Aabb {
+            min: center - half_extent,
+            max: center + half_extent,
+        }
+    }
+
+    #[inline]
+    pub fn center_aabb(aabb: Aabb) -> Vector3 {
+        (aabb.min + aabb.max) * 0.5
+    }
+
+    #[inline]
+    pub fn half_extent_aabb(aabb: Aabb) -> Vector3 {
+        (aabb.max - aabb.min) * 0.5
+    }
+
+    #[inline]
+    pub fn contains_point_aabb(aabb: Aabb, point: Vector3) -> bool {
+        (ge(point.value, aabb.min.value) & le(point.value, aabb.max.value)).all()
+    }
+
+    #[inline]
+    pub fn closest_point_aabb(aabb: Aabb, point: Vector3) -> Vector3 {
+        Vector3 {
+            value: clamp(point.value, aabb.min.value, aabb.max.value),
+        }
+    }
+
+    #[inline]
+    pub fn distance_sqr_aabb(aabb: Aabb, point: Vector3) -> f32 {
+        distance_squared(closest_point_aabb(aabb, point).value, point.value)
+    }
+
+    #[inline]
+    pub fn distance_aabb(aabb: Aabb, point: Vector3) -> f32 {
+        distance_sqr_aabb(aabb, point).sqrt()
+    }
+
+    #[inline]
+    pub fn intersect_aabb_aabb(aabb: Aabb, aabb1: Aabb) -> bool {
+        (le(aabb.min.value, aabb1.max.value) & ge(aabb.max.value, aabb1.min.value)).all()
+    }
+
+    #[inline]
+    pub fn intersect_sphere_aabb(sphere: Sphere, aabb: Aabb) -> bool {
+        distance_sqr_aabb(aabb, sphere.center()) <= sphere.radius() * sphere.radius()
+    }
+
+    // Axis 0, 1 and 2 are x, y and z, any other axis panics.
+    #[inline]
+    pub fn interval_aabb(aabb: Aabb, axis: usize) -> Interval {
+        assert!(axis < 3, "aabb axis must be 0, 1 or 2, got {}", axis);
+
+        Interval::new(aabb.min.value[axis], aabb.max.value[axis])
+    }
+
+    #[inline]
+    pub fn is_empty_aabb(aabb: Aabb) -> bool {
+        !le(aabb.min.value, aabb.max.value).all()
+    }
+
+    #[inline]
+    pub fn union_aabb(aabb: Aabb, aabb1: Aabb) -> Aabb {
+        Aabb {
+            min: Vector3 {
+                value: min(aabb.min.value, aabb1.min.value),
+            },
+            max: Vector3 {
+                value: max(aabb.max.value, aabb1.max.value),
+            },
+        }
+    }
+
+    #[inline]
+    pub fn intersection_aabb(aabb: Aabb, aabb1: Aabb) -> Option<Aabb> {
+        let intersection: Aabb = Aabb {
+            min: Vector3 {
+                value: max(aabb.min.value, aabb1.min.value),
+            },
+            max: Vector3 {
+                value: min(aabb.max.value, aabb1.max.value),
+            },
+        };
+
+        (!is_empty_aabb(intersection)).then_some(intersection)
+    }
+
+    #[inline]
+    pub fn expand_aabb(aabb: Aabb, point: Vector3) -> Aabb {
+        Aabb {
+            min: Vector3 {
+                value: min(aabb.min.value, point.value),
+            },
+            max: Vector3 {
+                value: max(aabb.max.value, point.value),
+            },
+        }
+    }
+
+    #[inline]
+    pub fn pad_aabb(aabb: Aabb, margin: f32) -> Aabb {
+        Aabb {
+            min: aabb.min - margin,
+            max: aabb.max + margin,
+        }
+    }
+
+    #[inline]
+    pub fn from_points_aabb(points: &[Vector3]) -> Aabb {
+        points
+            .iter()
+            .fold(Aabb::EMPTY, |aabb, point| expand_aabb(aabb, *point))
+    }
+
+    // Arvo, "Transforming Axis-Aligned Bounding Boxes", Graphics Gems 1990.
+    // Each column scaled by the box min and max along its axis contributes the
+    // smaller product to the new min and the larger to the new max, giving the
+    // tightest box around the transformed box. An empty box stays empty, its
+    // infinite bounds would otherwise turn into NaN against a zero column.
+    #[inline]
+    pub fn transform_aabb_affine3(aabb: Aabb, affine3: Affine3) -> Aabb {
+        if is_empty_aabb(aabb) {
+            return Aabb::EMPTY;
+        }
+
+        let columns: [Vector3; 3] = [
+            affine3.matrix3.column_x,
+            affine3.matrix3.column_y,
+            affine3.matrix3.column_z,
+        ];
+
+        let mut minimum: std::simd::f32x4 = affine3.translation.value;
+        let mut maximum: std::simd::f32x4 = affine3.translation.value;
+
+        for (axis, column) in columns.iter().enumerate() {
+            let a: std::simd::f32x4 = column.value * std::simd::f32x4::splat(aabb.min.value[axis]);
+            let b: std::simd::f32x4 = column.value * std::simd::f32x4::splat(aabb.max.value[axis]);
+
+            minimum += min(a, b);
+            maximum += max(a, b);
+        }
+
+        Aabb {
+            min: Vector3 { value: minimum },
+            max: Vector3 { value: maximum },
+        }
+    }
+
+    // Affine matrices only, a projective matrix needs its corners divided by w.
+    #[inline]
+    pub fn transform_aabb_mat4(aabb: Aabb, matrix: Matrix4x4) -> Aabb {
+        transform_aabb_affine3(aabb, Affine3::from_mat4(matrix))
+    }
+
+    // A sphere transformed by a non-uniform scale is an ellipsoid, its half
+    // extent on each axis is the radius times the length of the matrix row.
+    #[inline]
+    pub fn transform_sphere_aabb(sphere: Sphere, affine3: Affine3) -> Aabb {
+        let basis: [Vector3; 3] = [
+            affine3.matrix3.column_x,
+            affine3.matrix3.column_y,
+            affine3.matrix3.column_z,
+        ];
+
+        let row_length_sqr: Vector3 = basis
+            .iter()
+            .fold(Vector3::ZERO, |sum, column| sum + *column * *column);
+
+        let half_extent: Vector3 = Vector3 {
+            value: sqrt(row_length_sqr.value),
+        } * sphere.radius();
+
+        let center: Vector3 = affine3.matrix3 * sphere.center() + affine3.translation;
+
+        from_center_extent_aabb(center, half_extent)
+    }
+
+    #[inline]
+    pub fn sphere_aabb(sphere: Sphere) -> Aabb {
+        from_center_extent_aabb(sphere.center(), Vector3::broadcast(sphere.radius()))
+    }
+
+    #[inline]
+    pub fn capsule_aabb(capsule: Capsule) -> Aabb {
+        union_aabb(
+            sphere_aabb(Sphere::set(capsule.start, capsule.radius)),
+            sphere_aabb(Sphere::set(capsule.end, capsule.radius)),
+        )
+    }
+
+    // The capsule is the convex hull of its end spheres, so bounding the two
+    // transformed spheres bounds the whole capsule.
+    #[inline]
+    pub fn transform_capsule_aabb(capsule: Capsule, affine3: Affine3) -> Aabb {
+        union_aabb(
+            transform_sphere_aabb(Sphere::set(capsule.start, capsule.radius), affine3),
+            transform_sphere_aabb(Sphere::set(capsule.end, capsule.radius), affine3),
+        )
+    }
+
+    // https://tavianator.com/2011/ray_box.html
+    // Returns the distance along the ray to the first hit, zero if the ray starts inside.
+    pub fn intersect_ray_aabb(ray: Ray, aabb: Aabb) -> Option<f32> {
+        let rcp_direction: std::simd::f32x4 = rcp(ray.direction.value);
+
+        let slab_min: std::simd::f32x4 = (aabb.min.value - ray.origin.value) * rcp_direction;
+        let slab_max: std::simd::f32x4 = (aabb.max.value - ray.origin.value) * rcp_direction;
+
+        let near: [f32; 4] = min(slab_min, slab_max).to_array();
+        let far: [f32; 4] = max(slab_min, slab_max).to_array();
+
+        let entry: f32 = near[0].max(near[1]).max(near[2]).max(0.0);
+        let exit: f32 = far[0].min(far[1]).min(far[2]);
+
+        (entry <= exit).then_some(entry)
+    }
+}
+
+#[cfg(test)]
+mod aabb_test {
+    use crate::approx::{approx_aabb, THRESHOLD};
+    use crate::aabb_math::*;
+    use crate::affine3_math::from_angle_axis_affine3;
+    use crate::{Aabb, Affine3, Capsule, Ray, Sphere, Vector3};
+
+    const AABB: Aabb = Aabb::new(Vector3::set(-1.0, -1.0, -1.0), Vector3::set(1.0, 1.0, 1.0));
+
+    #[test]
+    fn ray_intersection() {
+        let hit: Option<f32> = intersect_ray_aabb(
+            Ray::new(Vector3::set(0.0, 0.5, -5.0), Vector3::FORWARD),
+            AABB,
+        );
+        assert!((hit.unwrap() - 4.0).abs() <= THRESHOLD);
+
+        let inside: Option<f32> = intersect_ray_aabb(Ray::new(Vector3::ZERO, Vector3::RIGHT), AABB);
+        assert!(inside.unwrap().abs() <= THRESHOLD);
+
+        let miss: Option<f32> = intersect_ray_aabb(
+            Ray::new(Vector3::set(0.0, 2.0, -5.0), Vector3::FORWARD),
+            AABB,
+        );
+        assert!(miss.is_none());
+
+        let behind: Option<f32> = intersect_ray_aabb(
+            Ray::new(Vector3::set(0.0, 0.0, 5.0), Vector3::FORWARD),
+            AABB,
+        );
+        assert!(behind.is_none());
+    }
+
+    #[test]
+    fn overlap() {
+        let shifted: Aabb = Aabb::new(Vector3::broadcast(0.5), Vector3::broadcast(3.0));
+        let separate: Aabb = Aabb::new(Vector3::set(0.0, 0.0, 1.5), Vector3::broadcast(3.0));
+
+        assert!(intersect_aabb_aabb(AABB, shifted));
+        assert!(!intersect_aabb_aabb(AABB, separate));
+
+        assert!(intersect_sphere_aabb(
+            Sphere::set(Vector3::set(0.0, 0.0, 1.5), 0.6),
+            AABB
+        ));
+        assert!(!intersect_sphere_aabb(
+            Sphere::set(Vector3::set(1.5, 1.5, 1.5), 0.6),
+            AABB
+        ));
+
+        assert!(contains_point_aabb(AABB, Vector3::set(0.0, 0.0, 1.0)));
+        assert!(!contains_point_aabb(AABB, Vector3::set(0.0, 0.0, 1.1)));
+        assert!((distance_aabb(AABB, Vector3::set(0.0, 4.0, 0.0)) - 3.0).abs() <= THRESHOLD);
+    }
+
+    #[test]
+    fn set_operations() {
+        let shifted: Aabb = Aabb::new(Vector3::broadcast(0.5), Vector3::broadcast(3.0));
+
+        assert!(approx_aabb(
+            union_aabb(AABB, shifted),
+            Aabb::new(Vector3::broadcast(-1.0), Vector3::broadcast(3.0))
+        ));
+        assert!(approx_aabb(
+            intersection_aabb(AABB, shifted).unwrap(),
+            Aabb::new(Vector3::broadcast(0.5), Vector3::broadcast(1.0))
+        ));
+        assert!(intersection_aabb(
+            AABB,
+            Aabb::new(Vector3::broadcast(2.0), Vector3::broadcast(3.0))
+        )
+        .is_none());
+        assert!(is_empty_aabb(Aabb::EMPTY));
+        assert!(approx_aabb(
+            from_points_aabb(&[Vector3::set(1.0, -2.0, 0.0), Vector3::set(-1.0, 3.0, 0.5)]),
+            Aabb::new(Vector3::set(-1.0, -2.0, 0.0), Vector3::set(1.0, 3.0, 0.5))
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn interval_rejects_w_axis() {
+        interval_aabb(AABB, 3);
+    }
+
+    #[test]
+    fn transformed_bounds() {
+        let rotation: Affine3 =
+            from_angle_axis_affine3(Vector3::FORWARD, std::f32::consts::FRAC_PI_4);
+        let affine3: Affine3 = Affine3 {
+            translation: Vector3::set(5.0, 0.0, -2.0),
+            matrix3: rotation.matrix3 * 2.0,
+        };
+
+        let transformed: Aabb = transform_aabb_affine3(AABB, affine3);
+        let reach: f32 = 2.0 * std::f32::consts::SQRT_2;
+
+        assert!(approx_aabb(
+            transformed,
+            Aabb::new(
+                Vector3::set(5.0 - reach, -reach, -4.0),
+                Vector3::set(5.0 + reach, reach, 0.0)
+            )
+        ));
+        assert!(approx_aabb(
+            transform_aabb_mat4(AABB, affine3.to_mat4()),
+            transformed
+        ));
+
+        let empty: Aabb = transform_aabb_affine3(Aabb::EMPTY, affine3);
+
+        assert!(is_empty_aabb(empty));
+        assert_eq!(empty.min.x(), f32::INFINITY);
+        assert_eq!(empty.max.x(), f32::NEG_INFINITY);
+
+        // Rotating a sphere leaves its bounds unchanged.
+        let sphere: Sphere = Sphere::set(Vector3::set(1.0, 0.0, 0.0), 0.5);
+        assert!(approx_aabb(
+            transform_sphere_aabb(sphere, rotation),
+            sphere_aabb(Sphere::set(
+                rotation.matrix3 * sphere.center(),
+                sphere.radius()
+            ))
+        ));
+
+        let capsule: Capsule = Capsule::new(Vector3::DOWN, Vector3::UP, 0.5);
+        let quarter_turn: Affine3 =
+            from_angle_axis_affine3(Vector3::FORWARD, std::f32::consts::FRAC_PI_2);
+
+        assert!(approx_aabb(
+            transform_capsule_aabb(capsule, quarter_turn),
+            Aabb::new(Vector3::set(-1.5, -0.5, -0.5), Vector3::set(1.5, 0.5, 0.5))
+        ));
+        assert!(approx_aabb(
+            capsule_aabb(capsule),
+            Aabb::new(Vector3::set(-0.5, -1.5, -0.5), Vector3::set(0.5, 1.5, 0.5))
+        ));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+// The swept sphere of radius along the segment start to end.
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capsule {
+    pub start: Vector3,
+    pub end: Vector3,
+    pub radius: f32,
+}
+
+impl Default for Capsule {
+    fn default() -> Self {
+        Capsule {
+            start: Vector3::DOWN * 0.5,
+            end: Vector3::UP * 0.5,
+            radius: 0.5,
+        }
+    }
+}
+
+impl Capsule {
+    #[inline(always)]
+    pub const fn new(start: Vector3, end: Vector3, radius: f32) -> Capsule {
+        Capsule { start, end, radius }
+    }
+}
+
+impl Display for Capsule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Capsule (start : {}, end : {}, radius : {})",
+            self.start, self.end, self.radius
+        )
+    }
+}
+
+pub mod capsule_math {
+    use crate::{Capsule, Sphere, Vector3};
+
+    use crate::vector_math::{
+        distance, distance_squared, dot, length_squared, normalize, orthogonal,
+    };
+
+    #[inline]
+    pub fn closest_point_segment(start: Vector3, end: Vector3, point: Vector3) -> Vector3 {
+        let segment: Vector3 = end - start;
+
+        let segment_length_sqr: f32 = length_squared(segment.value);
+
+        if std::intrinsics::unlikely(segment_length_sqr < f32::EPSILON) {
+            return start;
+        }
+
+        let delta: f32 =
+            (dot((point - start).value, segment.value) / segment_length_sqr).clamp(0.0, 1.0);
+
+        start + segment * delta
+    }
+
+    // Real-Time Collision Detection 5.1.9
+    // Returns the closest points (on segment, on segment1).
+    pub fn closest_points_segment_segment(
+        start: Vector3,
+        end: Vector3,
+        start1: Vector3,
+        end1: Vector3,
+    ) -> (Vector3, Vector3) {
+        let direction: Vector3 = end - start;
+        let direction1: Vector3 = end1 - start1;
+        let start_difference: Vector3 = start - start1;
+
+        let length_sqr: f32 = length_squared(direction.value);
+        let length_sqr1: f32 = length_squared(direction1.value);
+
+        let f: f32 = dot(direction1.value, start_difference.value);
+
+        if std::intrinsics::unlikely((length_sqr < f32::EPSILON) & (length_sqr1 < f32::EPSILON)) {
+            return (start, start1);
+        }
+
+        if std::intrinsics::unlikely(length_sqr < f32::EPSILON) {
+            return (start, closest_point_segment(start1, end1, start));
+        }
+
+        let c: f32 = dot(direction.value, start_difference.value);
+
+        if std::intrinsics::unlikely(length_sqr1 < f32::EPSILON) {
+            return (closest_point_segment(start, end, start1), start1);
+        }
+
+        let b: f32 = dot(direction.value, direction1.value);
+        let denominator: f32 = length_sqr * length_sqr1 - b * b;
+
+        let mut s: f32 = 0.0;
+
+        if denominator > f32::EPSILON {
+            s = ((b * f - c * length_sqr1) / denominator).clamp(0.0, 1.0);
+        }
+
+        let mut t: f32 = (b * s + f) / length_sqr1;
+
+        if t < 0.0 {
+            t = 0.0;
+            s = (-c / length_sqr).clamp(0.0, 1.0);
+        } else if t > 1.0 {
+            t = 1.0;
+            s = ((b - c) / length_sqr).clamp(0.0, 1.0);
+        }
+
+        (start + direction * s, start1 + direction1 * t)
+    }
+
+    #[inline]
+    pub fn contains_point_capsule(capsule: Capsule, point: Vector3) -> bool {
+        let axis_point: Vector3 = closest_point_segment(capsule.start, capsule.end, point);
+
+        distance_squared(axis_point.value, point.value) <= capsule.radius * capsule.radius
+    }
+
+    // A point on the axis is pushed out along a direction perpendicular to
+    // the axis, or up when the capsule is a sphere.
+    #[inline]
+    pub fn closest_point_capsule(capsule: Capsule, point: Vector3) -> Vector3 {
+        const EPSILON: f32 = f32::EPSILON * f32::EPSILON;
+
+        let axis_point: Vector3 = closest_point_segment(capsule.start, capsule.end, point);
+        let offset: Vector3 = point - axis_point;
+
+        let direction: std::simd::f32x4 = if length_squared(offset.value) > EPSILON {
+            offset.value
+        } else {
+            orthogonal((capsule.end - capsule.start).value)
+        };
+
+        let axis_to_point: Vector3 = if length_squared(direction) > EPSILON {
+            Vector3 {
+                value: normalize(direction),
+            }
+        } else {
+            Vector3::UP
+        };
+
+        axis_point + axis_to_point * capsule.radius
+    }
+
+    // Negative when the point is inside the capsule.
+    #[inline]
+    pub fn distance_capsule(capsule: Capsule, point: Vector3) -> f32 {
+        let axis_point: Vector3 = closest_point_segment(capsule.start, capsule.end, point);
+
+        distance(axis_point.value, point.value) - capsule.radius
+    }
+
+    #[inline]
+    pub fn intersect_sphere_capsule(sphere: Sphere, capsule: Capsule) -> bool {
+        let axis_point: Vector3 =
+            closest_point_segment(capsule.start, capsule.end, sphere.center());
+
+        let radius_sum: f32 = sphere.radius() + capsule.radius;
+
+        distance_squared(axis_point.value, sphere.center().value) <= radius_sum * radius_sum
+    }
+
+    #[inline]
+    pub fn intersect_capsule_capsule(capsule: Capsule, capsule1: Capsule) -> bool {
+        let (axis_point, axis_point1) = closest_points_segment_segment(
+            capsule.start,
+            capsule.end,
+            capsule1.start,
+            capsule1.end,
+        );
+
+        let radius_sum: f32 = capsule.radius + capsule1.radius;
+
+        distance_squared(axis_point.value, axis_point1.value) <= radius_sum * radius_sum
+    }
+}
+
+#[cfg(test)]
+mod capsule_test {
+    use crate::capsule_math::*;
+    use crate::vector_math::{distance, dot};
+    use crate::{Capsule, Vector3};
+
+    #[test]
+    fn closest_point_from_axis() {
+        let capsule: Capsule = Capsule::new(
+            Vector3::set(0.0, 0.0, -1.0),
+            Vector3::set(2.0, 0.0, 1.0),
+            0.5,
+        );
+        let axis_point: Vector3 = Vector3::set(1.0, 0.0, 0.0);
+
+        let closest: Vector3 = closest_point_capsule(capsule, axis_point);
+
+        assert!((distance(closest.value, axis_point.value) - 0.5).abs() <= 0.0001);
+        let axis: Vector3 = capsule.end - capsule.start;
+
+        assert!(dot((closest - axis_point).value, axis.value).abs() <= 0.0001);
+
+        let sphere: Capsule = Capsule::new(Vector3::ONE, Vector3::ONE, 0.5);
+        let closest: Vector3 = closest_point_capsule(sphere, Vector3::ONE);
+
+        assert!((distance(closest.value, Vector3::ONE.value) - 0.5).abs() <= 0.0001);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::Plane;
+
+use std::fmt::{Display, Formatter};
+
+// Planes face inward, ordered left, right, bottom, top, near, far.
+#[derive(Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Frustum {
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    pub const LEFT: usize = 0;
+    pub const RIGHT: usize = 1;
+    pub const BOTTOM: usize = 2;
+    pub const TOP: usize = 3;
+    pub const NEAR: usize = 4;
+    pub const FAR: usize = 5;
+
+    #[inline(always)]
+    pub const fn new(planes: [Plane; 6]) -> Frustum {
+        Frustum { planes }
+    }
+}
+
+impl Display for Frustum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Frustum (left : {}, right : {}, bottom : {}, top : {}, near : {}, far : {})",
+            self.planes[Frustum::LEFT],
+            self.planes[Frustum::RIGHT],
+            self.planes[Frustum::BOTTOM],
+            self.planes[Frustum::TOP],
+            self.planes[Frustum::NEAR],
+            self.planes[Frustum::FAR]
+        )
+    }
+}
+
+pub mod frustum_math {
+    use crate::{Aabb, Frustum, Matrix4x4, Plane, Sphere, Vector3, Vector4};
+
+    use crate::aabb_math::from_points_aabb;
+    use crate::matrix4x4_math::transpose_mat4;
+    use crate::plane_math::{normalize_plane, signed_distance_plane};
+    use crate::vector_math::{abs, distance, dot};
+
+    // https://www.gamedevs.org/uploads/fast-extraction-viewing-frustum-planes-from-world-view-projection-matrix.pdf
+    // Expects a clip space depth range of 0 to 1.
+    pub fn from_view_projection_frustum(view_projection: Matrix4x4) -> Frustum {
+        let rows: Matrix4x4 = transpose_mat4(view_projection);
+
+        let planes: [std::simd::f32x4; 6] = [
+            rows.column_w.value + rows.column_x.value,
+            rows.column_w.value - rows.column_x.value,
+            rows.column_w.value + rows.column_y.value,
+            rows.column_w.value - rows.column_y.value,
+            rows.column_z.value,
+            rows.column_w.value - rows.column_z.value,
+        ];
+
+        Frustum {
+            planes: planes.map(|plane| normalize_plane(Plane { value: plane })),
+        }
+    }
+
+    #[inline]
+    pub fn contains_point_frustum(frustum: Frustum, point: Vector3) -> bool {
+        frustum
+            .planes
+            .iter()
+            .all(|plane| signed_distance_plane(*plane, point) >= 0.0)
+    }
+
+    #[inline]
+    pub fn intersect_sphere_frustum(sphere: Sphere, frustum: Frustum) -> bool {
+        let center: Vector3 = sphere.center();
+        let radius: f32 = sphere.radius();
+
+        frustum
+            .planes
+            .iter()
+            .all(|plane| signed_distance_plane(*plane, center) >= -radius)
+    }
+
+    // World space corners of the part of the frustum between two fractions of
+    // the near to far distance, near corners first, each quad ordered top left,
+    // top right, bottom right, bottom left. Expects a clip space depth range of
+    // 0 to 1 like from_view_projection_frustum.
+    pub fn slice_corners_frustum(
+        inverse_view_projection: Matrix4x4,
+        near_fraction: f32,
+        far_fraction: f32,
+    ) -> [Vector3; 8] {
+        const NDC_CORNERS: [(f32, f32); 4] = [(-1.0, 1.0), (1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)];
+
+        let unproject = |x: f32, y: f32, z: f32| -> Vector3 {
+            let point: Vector4 = inverse_view_projection * Vector4::set(x, y, z, 1.0);
+
+            point.trunc_vec3() * point.w().recip()
+        };
+
+        let mut corners: [Vector3; 8] = [Vector3::ZERO; 8];
+
+        for (index, (x, y)) in NDC_CORNERS.iter().enumerate() {
+            let near: Vector3 = unproject(*x, *y, 0.0);
+            let edge: Vector3 = unproject(*x, *y, 1.0) - near;
+
+            corners[index] = near + edge * near_fraction;
+            corners[index + 4] = near + edge * far_fraction;
+        }
+
+        corners
+    }
+
+    #[inline]
+    pub fn slice_aabb_frustum(
+        inverse_view_projection: Matrix4x4,
+        near_fraction: f32,
+        far_fraction: f32,
+    ) -> Aabb {
+        from_points_aabb(&slice_corners_frustum(
+            inverse_view_projection,
+            near_fraction,
+            far_fraction,
+        ))
+    }
+
+    // Bounding sphere centered on the corner average. The radius does not
+    // change as the camera rotates, which keeps cascade texel snapping stable.
+    #[inline]
+    pub fn slice_sphere_frustum(
+        inverse_view_projection: Matrix4x4,
+        near_fraction: f32,
+        far_fraction: f32,
+    ) -> Sphere {
+        let corners: [Vector3; 8] =
+            slice_corners_frustum(inverse_view_projection, near_fraction, far_fraction);
+
+        let center: Vector3 = corners
+            .iter()
+            .fold(Vector3::ZERO, |sum, corner| sum + *corner)
+            * 0.125;
+
+        let radius: f32 = corners
+            .iter()
+            .map(|corner| distance(corner.value, center.value))
+            .fold(0.0, f32::max);
+
+        Sphere::set(center, radius)
+    }
+
+    // Conservative, an Aabb outside near a frustum corner can still report true.
+    #[inline]
+    pub fn intersect_aabb_frustum(aabb: Aabb, frustum: Frustum) -> bool {
+        let center: Vector3 = (aabb.min + aabb.max) * 0.5;
+        let half_extent: Vector3 = (aabb.max - aabb.min) * 0.5;
+
+        frustum.planes.iter().all(|plane| {
+            let projected_radius: f32 = dot(half_extent.value, abs(plane.normal().value));
+
+            signed_distance_plane(*plane, center) >= -projected_radius
+        })
+    }
+}
+
+#[cfg(test)]
+mod frustum_test {
+    use crate::frustum_math::*;
+    use crate::{Aabb, Frustum, Matrix4x4, Sphere, Vector3};
+
+    // The identity view projection frustum is the clip volume x, y in [-1, 1] and z in [0, 1].
+    #[test]
+    fn culling() {
+        let frustum: Frustum = from_view_projection_frustum(Matrix4x4::IDENTITY);
+
+        assert!(contains_point_frustum(
+            frustum,
+            Vector3::set(0.5, -0.5, 0.5)
+        ));
+        assert!(!contains_point_frustum(
+            frustum,
+            Vector3::set(0.5, -0.5, -0.1)
+        ));
+
+        assert!(intersect_sphere_frustum(
+            Sphere::set(Vector3::set(1.2, 0.0, 0.5), 0.3),
+            frustum
+        ));
+        assert!(!intersect_sphere_frustum(
+            Sphere::set(Vector3::set(1.4, 0.0, 0.5), 0.3),
+            frustum
+        ));
+        assert!(!intersect_sphere_frustum(
+            Sphere::set(Vector3::set(0.0, 0.0, 1.5), 0.3),
+            frustum
+        ));
+
+        let inside: Aabb = Aabb::new(Vector3::set(-0.2, -0.2, 0.2), Vector3::set(0.2, 0.2, 0.4));
+        let straddle: Aabb = Aabb::new(Vector3::set(0.8, -0.2, 0.2), Vector3::set(1.5, 0.2, 0.4));
+        let outside: Aabb = Aabb::new(Vector3::set(-0.2, 1.1, 0.2), Vector3::set(0.2, 2.0, 0.4));
+
+        assert!(intersect_aabb_frustum(inside, frustum));
+        assert!(intersect_aabb_frustum(straddle, frustum));
+        assert!(!intersect_aabb_frustum(outside, frustum));
+    }
+
+    #[test]
+    fn slice_bounds() {
+        let corners: [Vector3; 8] = slice_corners_frustum(Matrix4x4::IDENTITY, 0.25, 0.5);
+
+        assert_eq!(corners[0].to_primitive(), [-1.0, 1.0, 0.25]);
+        assert_eq!(corners[6].to_primitive(), [1.0, -1.0, 0.5]);
+
+        let aabb: Aabb = slice_aabb_frustum(Matrix4x4::IDENTITY, 0.25, 0.5);
+        assert_eq!(aabb.min.to_primitive(), [-1.0, -1.0, 0.25]);
+        assert_eq!(aabb.max.to_primitive(), [1.0, 1.0, 0.5]);
+
+        let sphere: Sphere = slice_sphere_frustum(Matrix4x4::IDENTITY, 0.25, 0.5);
+        assert_eq!(sphere.center().to_primitive(), [0.0, 0.0, 0.375]);
+        assert!((sphere.radius() - (2.0f32 + 0.125 * 0.125).sqrt()).abs() <= 0.0001);
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Closed interval [min, max]. An interval with min greater than max is empty.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Interval {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for Interval {
+    fn default() -> Self {
+        Interval::EMPTY
+    }
+}
+
+impl Interval {
+    pub const EMPTY: Interval = Interval {
+        min: f32::INFINITY,
+        max: f32::NEG_INFINITY,
+    };
+
+    pub const UNIT: Interval = Interval { min: 0.0, max: 1.0 };
+
+    #[inline(always)]
+    pub const fn new(min: f32, max: f32) -> Interval {
+        Interval { min, max }
+    }
+
+    #[inline(always)]
+    pub const fn point(value: f32) -> Interval {
+        Interval {
+            min: value,
+            max: value,
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Interval (min : {}, max : {})", self.min, self.max)
+    }
+}
+
+impl Add for Interval {
+    type Output = Interval;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Interval {
+            min: self.min + rhs.min,
+            max: self.max + rhs.max,
+        }
+    }
+}
+
+impl Sub for Interval {
+    type Output = Interval;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Interval {
+            min: self.min - rhs.max,
+            max: self.max - rhs.min,
+        }
+    }
+}
+
+impl Mul<f32> for Interval {
+    type Output = Interval;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        let a: f32 = self.min * rhs;
+        let b: f32 = self.max * rhs;
+
+        Interval {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+}
+
+impl Mul for Interval {
+    type Output = Interval;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let a: f32 = self.min * rhs.min;
+        let b: f32 = self.min * rhs.max;
+        let c: f32 = self.max * rhs.min;
+        let d: f32 = self.max * rhs.max;
+
+        Interval {
+            min: a.min(b).min(c.min(d)),
+            max: a.max(b).max(c.max(d)),
+        }
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Interval {
+            min: -self.max,
+            max: -self.min,
+        }
+    }
+}
+
+pub mod interval_math {
+    use crate::Interval;
+
+    #[inline]
+    pub fn is_empty_interval(interval: Interval) -> bool {
+        interval.min > interval.max
+    }
+
+    #[inline]
+    pub fn width_interval(interval: Interval) -> f32 {
+        (interval.max - interval.min).max(0.0)
+    }
+
+    #[inline]
+    pub fn center_interval(interval: Interval) -> f32 {
+        (interval.min + interval.max) * 0.5
+    }
+
+    #[inline]
+    pub fn contains_interval(interval: Interval, value: f32) -> bool {
+        (value >= interval.min) & (value <= interval.max)
+    }
+
+    #[inline]
+    pub fn overlap_interval(interval: Interval, interval1: Interval) -> bool {
+        (interval.min <= interval1.max) & (interval1.min <= interval.max)
+    }
+
+    #[inline]
+    pub fn union_interval(interval: Interval, interval1: Interval) -> Interval {
+        Interval {
+            min: interval.min.min(interval1.min),
+            max: interval.max.max(interval1.max),
+        }
+    }
+
+    #[inline]
+    pub fn intersection_interval(interval: Interval, interval1: Interval) -> Option<Interval> {
+        let intersection: Interval = Interval {
+            min: interval.min.max(interval1.min),
+            max: interval.max.min(interval1.max),
+        };
+
+        (!is_empty_interval(intersection)).then_some(intersection)
+    }
+
+    #[inline]
+    pub fn expand_interval(interval: Interval, value: f32) -> Interval {
+        Interval {
+            min: interval.min.min(value),
+            max: interval.max.max(value),
+        }
+    }
+
+    #[inline]
+    pub fn pad_interval(interval: Interval, margin: f32) -> Interval {
+        Interval {
+            min: interval.min - margin,
+            max: interval.max + margin,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod interval_test {
+    use crate::interval_math::*;
+    use crate::Interval;
+
+    #[test]
+    fn arithmetic() {
+        let a: Interval = Interval::new(-1.0, 2.0);
+        let b: Interval = Interval::new(3.0, 4.0);
+
+        assert_eq!(a + b, Interval::new(2.0, 6.0));
+        assert_eq!(a - b, Interval::new(-5.0, -1.0));
+        assert_eq!(a * b, Interval::new(-4.0, 8.0));
+        assert_eq!(a * -2.0, Interval::new(-4.0, 2.0));
+        assert_eq!(-a, Interval::new(-2.0, 1.0));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: Interval = Interval::new(-1.0, 2.0);
+        let b: Interval = Interval::new(1.0, 4.0);
+
+        assert_eq!(union_interval(a, b), Interval::new(-1.0, 4.0));
+        assert_eq!(intersection_interval(a, b), Some(Interval::new(1.0, 2.0)));
+        assert_eq!(intersection_interval(a, Interval::new(3.0, 4.0)), None);
+        assert_eq!(
+            expand_interval(expand_interval(Interval::EMPTY, 3.0), -1.0),
+            Interval::new(-1.0, 3.0)
+        );
+
+        assert!(is_empty_interval(Interval::EMPTY));
+        assert_eq!(width_interval(Interval::EMPTY), 0.0);
+        assert!(contains_interval(a, 2.0) && !contains_interval(a, 2.5));
+    }
+}
@@ -0,0 +1,19 @@
+mod aabb;
+mod capsule;
+mod frustum;
+mod interval;
+mod obb;
+mod plane;
+mod ray;
+mod sphere;
+mod triangle;
+
+pub use aabb::*;
+pub use capsule::*;
+pub use frustum::*;
+pub use interval::*;
+pub use obb::*;
+pub use plane::*;
+pub use ray::*;
+pub use sphere::*;
+pub use triangle::*;
@@ -0,0 +1,240 @@
+use crate::{Quaternion, Vector3};
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Obb {
+    pub center: Vector3,
+    pub half_extent: Vector3,
+    pub rotation: Quaternion,
+}
+
+impl Default for Obb {
+    fn default() -> Self {
+        Obb {
+            center: Vector3::ZERO,
+            half_extent: Vector3::broadcast(0.5),
+            rotation: Quaternion::IDENTITY,
+        }
+    }
+}
+
+impl Obb {
+    #[inline(always)]
+    pub const fn new(center: Vector3, half_extent: Vector3, rotation: Quaternion) -> Obb {
+        Obb {
+            center,
+            half_extent,
+            rotation,
+        }
+    }
+}
+
+impl Display for Obb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Obb (center : {}, half_extent : {}, rotation : {})",
+            self.center, self.half_extent, self.rotation
+        )
+    }
+}
+
+pub mod obb_math {
+    use crate::{Aabb, Matrix3x3, Obb, Vector3};
+
+    use crate::matrix3x3_math::{from_quat_mat3, transpose_mat3};
+    use crate::vector_math::{abs, clamp, cross, distance_squared, dot, ge, le};
+
+    #[inline]
+    pub fn from_aabb_obb(aabb: Aabb) -> Obb {
+        Obb {
+            center: (aabb.min + aabb.max) * 0.5,
+            half_extent: (aabb.max - aabb.min) * 0.5,
+            ..Default::default()
+        }
+    }
+
+    // The smallest Aabb enclosing the Obb.
+    #[inline]
+    pub fn to_aabb_obb(obb: Obb) -> Aabb {
+        let axes: Matrix3x3 = from_quat_mat3(obb.rotation);
+
+        let world_half_extent: Vector3 = Vector3 {
+            value: abs(axes.column_x.value) * std::simd::f32x4::splat(obb.half_extent.x())
+                + abs(axes.column_y.value) * std::simd::f32x4::splat(obb.half_extent.y())
+                + abs(axes.column_z.value) * std::simd::f32x4::splat(obb.half_extent.z()),
+        };
+
+        Aabb {
+            min: obb.center - world_half_extent,
+            max: obb.center + world_half_extent,
+        }
+    }
+
+    #[inline]
+    pub fn contains_point_obb(obb: Obb, point: Vector3) -> bool {
+        let local_point: Vector3 =
+            transpose_mat3(from_quat_mat3(obb.rotation)) * (point - obb.center);
+
+        (ge(local_point.value, -obb.half_extent.value)
+            & le(local_point.value, obb.half_extent.value))
+        .all()
+    }
+
+    #[inline]
+    pub fn closest_point_obb(obb: Obb, point: Vector3) -> Vector3 {
+        let axes: Matrix3x3 = from_quat_mat3(obb.rotation);
+
+        let local_point: Vector3 = transpose_mat3(axes) * (point - obb.center);
+
+        let clamped_point: Vector3 = Vector3 {
+            value: clamp(
+                local_point.value,
+                -obb.half_extent.value,
+                obb.half_extent.value,
+            ),
+        };
+
+        obb.center + axes * clamped_point
+    }
+
+    #[inline]
+    pub fn distance_sqr_obb(obb: Obb, point: Vector3) -> f32 {
+        distance_squared(closest_point_obb(obb, point).value, point.value)
+    }
+
+    #[inline]
+    pub fn distance_obb(obb: Obb, point: Vector3) -> f32 {
+        distance_sqr_obb(obb, point).sqrt()
+    }
+
+    // Real-Time Collision Detection 4.4.1
+    // Separating axis test over the 3 + 3 face normals and the 9 edge cross products.
+    pub fn intersect_obb_obb(obb: Obb, obb1: Obb) -> bool {
+        let axes: [Vector3; 3] = {
+            let matrix: Matrix3x3 = from_quat_mat3(obb.rotation);
+
+            [matrix.column_x, matrix.column_y, matrix.column_z]
+        };
+
+        let axes1: [Vector3; 3] = {
+            let matrix: Matrix3x3 = from_quat_mat3(obb1.rotation);
+
+            [matrix.column_x, matrix.column_y, matrix.column_z]
+        };
+
+        let center_difference: Vector3 = obb1.center - obb.center;
+
+        let half_extent: [f32; 3] = obb.half_extent.to_primitive();
+        let half_extent1: [f32; 3] = obb1.half_extent.to_primitive();
+
+        let projected_radius = |axis: std::simd::f32x4, axes: &[Vector3; 3], extent: &[f32; 3]| {
+            dot(axes[0].value, axis).abs() * extent[0]
+                + dot(axes[1].value, axis).abs() * extent[1]
+                + dot(axes[2].value, axis).abs() * extent[2]
+        };
+
+        let is_separated = |axis: std::simd::f32x4| {
+            let distance: f32 = dot(center_difference.value, axis).abs();
+
+            distance
+                > projected_radius(axis, &axes, &half_extent)
+                    + projected_radius(axis, &axes1, &half_extent1)
+        };
+
+        for index in 0..3 {
+            if is_separated(axes[index].value) | is_separated(axes1[index].value) {
+                return false;
+            }
+        }
+
+        for axis in axes.iter() {
+            for axis1 in axes1.iter() {
+                let edge_axis: std::simd::f32x4 = cross(axis.value, axis1.value);
+
+                // Parallel edges are already covered by the face normals.
+                if dot(edge_axis, edge_axis) < f32::EPSILON {
+                    continue;
+                }
+
+                if is_separated(edge_axis) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod obb_test {
+    use crate::obb_math::*;
+    use crate::quaternion_math::{rotate_y_quat, rotate_z_quat};
+    use crate::{Obb, Quaternion, Vector3};
+
+    #[test]
+    fn separating_axis() {
+        let obb: Obb = Obb::new(Vector3::ZERO, Vector3::ONE, Quaternion::IDENTITY);
+
+        let touching: Obb = Obb::new(
+            Vector3::set(1.9, 0.0, 0.0),
+            Vector3::ONE,
+            Quaternion::IDENTITY,
+        );
+        let separate: Obb = Obb::new(
+            Vector3::set(2.1, 0.0, 0.0),
+            Vector3::ONE,
+            Quaternion::IDENTITY,
+        );
+
+        assert!(intersect_obb_obb(obb, touching));
+        assert!(!intersect_obb_obb(obb, separate));
+
+        // Rotated 45 degrees the corner reaches sqrt(2) along x.
+        let rotated: Obb = Obb::new(
+            Vector3::set(2.3, 0.0, 0.0),
+            Vector3::ONE,
+            rotate_z_quat(std::f32::consts::FRAC_PI_4),
+        );
+        let rotated_separate: Obb = Obb::new(
+            Vector3::set(2.5, 0.0, 0.0),
+            Vector3::ONE,
+            rotate_z_quat(std::f32::consts::FRAC_PI_4),
+        );
+
+        assert!(intersect_obb_obb(obb, rotated));
+        assert!(!intersect_obb_obb(obb, rotated_separate));
+
+        // Diagonal neighbour rotated about two axes.
+        let edge: Obb = Obb::new(
+            Vector3::set(2.2, 2.2, 0.0),
+            Vector3::ONE,
+            rotate_z_quat(std::f32::consts::FRAC_PI_4) * rotate_y_quat(std::f32::consts::FRAC_PI_4),
+        );
+
+        assert!(!intersect_obb_obb(obb, edge));
+    }
+
+    #[test]
+    fn closest_point() {
+        let obb: Obb = Obb::new(
+            Vector3::set(1.0, 0.0, 0.0),
+            Vector3::set(1.0, 2.0, 1.0),
+            rotate_z_quat(std::f32::consts::FRAC_PI_2),
+        );
+
+        let closest: Vector3 = closest_point_obb(obb, Vector3::set(5.0, 0.0, 0.0));
+
+        assert!((closest - Vector3::set(3.0, 0.0, 0.0))
+            .value
+            .to_array()
+            .iter()
+            .all(|v| v.abs() <= 0.0001));
+        assert!(contains_point_obb(obb, Vector3::set(2.5, 0.5, 0.0)));
+        assert!(!contains_point_obb(obb, Vector3::set(2.5, 1.5, 0.0)));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+// Stored as (normal, distance) for the equation dot(normal, point) + distance = 0,
+// the positive half space is in front of the plane.
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Plane {
+    pub value: std::simd::f32x4,
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Plane::set(Vector3::UP, 0.0)
+    }
+}
+
+impl Plane {
+    #[inline(always)]
+    pub const fn set(normal: Vector3, distance: f32) -> Plane {
+        let normal_array: [f32; 4] = normal.value.to_array();
+
+        Plane {
+            value: std::simd::f32x4::from_array([
+                normal_array[0],
+                normal_array[1],
+                normal_array[2],
+                distance,
+            ]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn normal(self) -> Vector3 {
+        let array_plane: [f32; 4] = self.value.to_array();
+
+        Vector3::set(array_plane[0], array_plane[1], array_plane[2])
+    }
+
+    #[inline(always)]
+    pub const fn distance(self) -> f32 {
+        let array_plane: [f32; 4] = self.value.to_array();
+
+        array_plane[3]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f32; 4] {
+        self.value.to_array()
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f32; 4]) -> Plane {
+        Plane {
+            value: std::simd::f32x4::from_array(array),
+        }
+    }
+}
+
+impl Display for Plane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Plane (normal : {}, distance : {})",
+            self.normal(),
+            self.distance()
+        )
+    }
+}
+
+pub mod plane_math {
+    use crate::{Plane, Ray, Vector3};
+
+    use crate::vector_math::{cross, dot, length, normalize};
+
+    const POINT_W: std::simd::f32x4 = std::simd::f32x4::from_array([0.0, 0.0, 0.0, 1.0]);
+
+    #[inline]
+    pub fn from_point_normal_plane(point: Vector3, normal: Vector3) -> Plane {
+        Plane::set(normal, -dot(normal.value, point.value))
+    }
+
+    // Counter clockwise winding faces the normal.
+    #[inline]
+    pub fn from_points_plane(point_a: Vector3, point_b: Vector3, point_c: Vector3) -> Plane {
+        let normal: Vector3 = Vector3 {
+            value: normalize(cross((point_b - point_a).value, (point_c - point_a).value)),
+        };
+
+        from_point_normal_plane(point_a, normal)
+    }
+
+    #[inline]
+    pub fn normalize_plane(plane: Plane) -> Plane {
+        let rcp_normal_length: f32 = length(plane.normal().value).recip();
+
+        Plane {
+            value: plane.value * std::simd::f32x4::splat(rcp_normal_length),
+        }
+    }
+
+    #[inline]
+    pub fn signed_distance_plane(plane: Plane, point: Vector3) -> f32 {
+        dot(plane.value, point.value + POINT_W)
+    }
+
+    #[inline]
+    pub fn closest_point_plane(plane: Plane, point: Vector3) -> Vector3 {
+        point - plane.normal() * signed_distance_plane(plane, point)
+    }
+
+    #[inline]
+    pub fn intersect_ray_plane(ray: Ray, plane: Plane) -> Option<f32> {
+        let denominator: f32 = dot(plane.normal().value, ray.direction.value);
+
+        if std::intrinsics::unlikely(denominator.abs() < f32::EPSILON) {
+            return None;
+        }
+
+        let distance: f32 = -signed_distance_plane(plane, ray.origin) / denominator;
+
+        (distance >= 0.0).then_some(distance)
+    }
+}
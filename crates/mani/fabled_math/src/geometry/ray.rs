@@ -0,0 +1,75 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Ray {
+    pub origin: Vector3,
+    pub direction: Vector3,
+}
+
+impl Default for Ray {
+    fn default() -> Self {
+        Ray {
+            origin: Vector3::ZERO,
+            direction: Vector3::FORWARD,
+        }
+    }
+}
+
+impl Ray {
+    #[inline(always)]
+    pub const fn new(origin: Vector3, direction: Vector3) -> Ray {
+        Ray { origin, direction }
+    }
+}
+
+impl Display for Ray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Ray (origin : {}, direction : {})",
+            self.origin, self.direction
+        )
+    }
+}
+
+pub mod ray_math {
+    use crate::{Ray, Vector3};
+
+    use crate::vector_math::{dot, length_squared, normalize};
+
+    #[inline]
+    pub fn from_points_ray(origin: Vector3, target: Vector3) -> Ray {
+        Ray {
+            origin,
+            direction: Vector3 {
+                value: normalize((target - origin).value),
+            },
+        }
+    }
+
+    #[inline]
+    pub fn point_at_ray(ray: Ray, distance: f32) -> Vector3 {
+        ray.origin + ray.direction * distance
+    }
+
+    #[inline]
+    pub fn closest_point_ray(ray: Ray, point: Vector3) -> Vector3 {
+        let distance: f32 = dot((point - ray.origin).value, ray.direction.value).max(0.0);
+
+        point_at_ray(ray, distance)
+    }
+
+    #[inline]
+    pub fn distance_sqr_ray(ray: Ray, point: Vector3) -> f32 {
+        length_squared((point - closest_point_ray(ray, point)).value)
+    }
+
+    #[inline]
+    pub fn distance_ray(ray: Ray, point: Vector3) -> f32 {
+        distance_sqr_ray(ray, point).sqrt()
+    }
+}
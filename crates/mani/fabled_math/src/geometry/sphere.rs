@@ -0,0 +1,148 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+// Stored as (center, radius).
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Sphere {
+    pub value: std::simd::f32x4,
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Sphere::set(Vector3::ZERO, 1.0)
+    }
+}
+
+impl Sphere {
+    #[inline(always)]
+    pub const fn set(center: Vector3, radius: f32) -> Sphere {
+        let center_array: [f32; 4] = center.value.to_array();
+
+        Sphere {
+            value: std::simd::f32x4::from_array([
+                center_array[0],
+                center_array[1],
+                center_array[2],
+                radius,
+            ]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn center(self) -> Vector3 {
+        let array_sphere: [f32; 4] = self.value.to_array();
+
+        Vector3::set(array_sphere[0], array_sphere[1], array_sphere[2])
+    }
+
+    #[inline(always)]
+    pub const fn radius(self) -> f32 {
+        let array_sphere: [f32; 4] = self.value.to_array();
+
+        array_sphere[3]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f32; 4] {
+        self.value.to_array()
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f32; 4]) -> Sphere {
+        Sphere {
+            value: std::simd::f32x4::from_array(array),
+        }
+    }
+}
+
+impl Display for Sphere {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Sphere (center : {}, radius : {})",
+            self.center(),
+            self.radius()
+        )
+    }
+}
+
+pub mod sphere_math {
+    use crate::{Ray, Sphere, Vector3};
+
+    use crate::vector_math::{distance, distance_squared, dot, length_squared, normalize};
+
+    #[inline]
+    pub fn contains_point_sphere(sphere: Sphere, point: Vector3) -> bool {
+        distance_squared(sphere.center().value, point.value) <= sphere.radius() * sphere.radius()
+    }
+
+    // Every surface point is closest to the center, up is picked.
+    #[inline]
+    pub fn closest_point_sphere(sphere: Sphere, point: Vector3) -> Vector3 {
+        let center: Vector3 = sphere.center();
+        let offset: Vector3 = point - center;
+
+        if length_squared(offset.value) <= f32::EPSILON * f32::EPSILON {
+            return center + Vector3::UP * sphere.radius();
+        }
+
+        let center_to_point: Vector3 = Vector3 {
+            value: normalize(offset.value),
+        };
+
+        center + center_to_point * sphere.radius()
+    }
+
+    // Negative when the point is inside the sphere.
+    #[inline]
+    pub fn distance_sphere(sphere: Sphere, point: Vector3) -> f32 {
+        distance(sphere.center().value, point.value) - sphere.radius()
+    }
+
+    #[inline]
+    pub fn intersect_sphere_sphere(sphere: Sphere, sphere1: Sphere) -> bool {
+        let radius_sum: f32 = sphere.radius() + sphere1.radius();
+
+        distance_squared(sphere.center().value, sphere1.center().value) <= radius_sum * radius_sum
+    }
+
+    // Returns the distance along the ray to the first hit, zero if the ray starts inside.
+    pub fn intersect_ray_sphere(ray: Ray, sphere: Sphere) -> Option<f32> {
+        let center_to_origin: Vector3 = ray.origin - sphere.center();
+
+        let b: f32 = dot(center_to_origin.value, ray.direction.value);
+        let c: f32 = length_squared(center_to_origin.value) - sphere.radius() * sphere.radius();
+
+        if (c > 0.0) & (b > 0.0) {
+            return None;
+        }
+
+        let discriminant: f32 = b * b - c;
+
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        Some((-b - discriminant.sqrt()).max(0.0))
+    }
+}
+
+#[cfg(test)]
+mod sphere_test {
+    use crate::sphere_math::*;
+    use crate::vector_math::distance;
+    use crate::{Sphere, Vector3};
+
+    #[test]
+    fn closest_point_from_center() {
+        let sphere: Sphere = Sphere::set(Vector3::set(1.0, -2.0, 3.0), 2.0);
+
+        for point in [Vector3::set(1.0, -2.0, 3.0), Vector3::set(4.0, -2.0, 3.0)] {
+            let closest: Vector3 = closest_point_sphere(sphere, point);
+
+            assert!((distance(closest.value, sphere.center().value) - 2.0).abs() <= 0.0001);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use crate::Vector3;
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Triangle {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub c: Vector3,
+}
+
+impl Default for Triangle {
+    fn default() -> Self {
+        Triangle {
+            a: Vector3::ZERO,
+            b: Vector3::UP,
+            c: Vector3::RIGHT,
+        }
+    }
+}
+
+impl Triangle {
+    #[inline(always)]
+    pub const fn new(a: Vector3, b: Vector3, c: Vector3) -> Triangle {
+        Triangle { a, b, c }
+    }
+}
+
+impl Display for Triangle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Triangle (a : {}, b : {}, c : {})",
+            self.a, self.b, self.c
+        )
+    }
+}
+
+pub mod triangle_math {
+    use crate::{Ray, Triangle, Vector3};
+
+    use crate::vector_math::{cross, distance_squared, dot, length, normalize};
+
+    #[inline]
+    pub fn normal_triangle(triangle: Triangle) -> Vector3 {
+        Vector3 {
+            value: normalize(cross(
+                (triangle.b - triangle.a).value,
+                (triangle.c - triangle.a).value,
+            )),
+        }
+    }
+
+    #[inline]
+    pub fn area_triangle(triangle: Triangle) -> f32 {
+        length(cross(
+            (triangle.b - triangle.a).value,
+            (triangle.c - triangle.a).value,
+        )) * 0.5
+    }
+
+    #[inline]
+    pub fn centroid_triangle(triangle: Triangle) -> Vector3 {
+        (triangle.a + triangle.b + triangle.c) * (1.0 / 3.0)
+    }
+
+    // Returns the (a, b, c) weights of the point projected onto the triangle's plane.
+    pub fn barycentric_triangle(triangle: Triangle, point: Vector3) -> Vector3 {
+        let ab: Vector3 = triangle.b - triangle.a;
+        let ac: Vector3 = triangle.c - triangle.a;
+        let ap: Vector3 = point - triangle.a;
+
+        let d00: f32 = dot(ab.value, ab.value);
+        let d01: f32 = dot(ab.value, ac.value);
+        let d11: f32 = dot(ac.value, ac.value);
+        let d20: f32 = dot(ap.value, ab.value);
+        let d21: f32 = dot(ap.value, ac.value);
+
+        let rcp_denominator: f32 = (d00 * d11 - d01 * d01).recip();
+
+        let v: f32 = (d11 * d20 - d01 * d21) * rcp_denominator;
+        let w: f32 = (d00 * d21 - d01 * d20) * rcp_denominator;
+
+        Vector3::set(1.0 - v - w, v, w)
+    }
+
+    // Real-Time Collision Detection 5.1.5
+    pub fn closest_point_triangle(triangle: Triangle, point: Vector3) -> Vector3 {
+        let ab: Vector3 = triangle.b - triangle.a;
+        let ac: Vector3 = triangle.c - triangle.a;
+
+        let ap: Vector3 = point - triangle.a;
+
+        let d1: f32 = dot(ab.value, ap.value);
+        let d2: f32 = dot(ac.value, ap.value);
+
+        if (d1 <= 0.0) & (d2 <= 0.0) {
+            return triangle.a;
+        }
+
+        let bp: Vector3 = point - triangle.b;
+
+        let d3: f32 = dot(ab.value, bp.value);
+        let d4: f32 = dot(ac.value, bp.value);
+
+        if (d3 >= 0.0) & (d4 <= d3) {
+            return triangle.b;
+        }
+
+        let vc: f32 = d1 * d4 - d3 * d2;
+
+        if (vc <= 0.0) & (d1 >= 0.0) & (d3 <= 0.0) {
+            return triangle.a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp: Vector3 = point - triangle.c;
+
+        let d5: f32 = dot(ab.value, cp.value);
+        let d6: f32 = dot(ac.value, cp.value);
+
+        if (d6 >= 0.0) & (d5 <= d6) {
+            return triangle.c;
+        }
+
+        let vb: f32 = d5 * d2 - d1 * d6;
+
+        if (vb <= 0.0) & (d2 >= 0.0) & (d6 <= 0.0) {
+            return triangle.a + ac * (d2 / (d2 - d6));
+        }
+
+        let va: f32 = d3 * d6 - d5 * d4;
+
+        if (va <= 0.0) & ((d4 - d3) >= 0.0) & ((d5 - d6) >= 0.0) {
+            let bc_weight: f32 = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+
+            return triangle.b + (triangle.c - triangle.b) * bc_weight;
+        }
+
+        let rcp_denominator: f32 = (va + vb + vc).recip();
+
+        triangle.a + ab * (vb * rcp_denominator) + ac * (vc * rcp_denominator)
+    }
+
+    #[inline]
+    pub fn distance_sqr_triangle(triangle: Triangle, point: Vector3) -> f32 {
+        distance_squared(closest_point_triangle(triangle, point).value, point.value)
+    }
+
+    #[inline]
+    pub fn distance_triangle(triangle: Triangle, point: Vector3) -> f32 {
+        distance_sqr_triangle(triangle, point).sqrt()
+    }
+
+    // https://www.graphics.cornell.edu/pubs/1997/MT97.pdf
+    // Double sided, returns the distance along the ray to the hit.
+    pub fn intersect_ray_triangle(ray: Ray, triangle: Triangle) -> Option<f32> {
+        let edge_ab: Vector3 = triangle.b - triangle.a;
+        let edge_ac: Vector3 = triangle.c - triangle.a;
+
+        let p_vector: std::simd::f32x4 = cross(ray.direction.value, edge_ac.value);
+
+        let determinant: f32 = dot(edge_ab.value, p_vector);
+
+        if std::intrinsics::unlikely(determinant.abs() < f32::EPSILON) {
+            return None;
+        }
+
+        let rcp_determinant: f32 = determinant.recip();
+
+        let t_vector: Vector3 = ray.origin - triangle.a;
+
+        let u: f32 = dot(t_vector.value, p_vector) * rcp_determinant;
+
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q_vector: std::simd::f32x4 = cross(t_vector.value, edge_ab.value);
+
+        let v: f32 = dot(ray.direction.value, q_vector) * rcp_determinant;
+
+        if (v < 0.0) | (u + v > 1.0) {
+            return None;
+        }
+
+        let distance: f32 = dot(edge_ac.value, q_vector) * rcp_determinant;
+
+        (distance >= 0.0).then_some(distance)
+    }
+}
+
+#[cfg(test)]
+mod triangle_test {
+    use crate::approx::{approx_vec3, THRESHOLD};
+    use crate::triangle_math::*;
+    use crate::{Ray, Triangle, Vector3};
+
+    const TRIANGLE: Triangle = Triangle::new(
+        Vector3::set(0.0, 0.0, 0.0),
+        Vector3::set(2.0, 0.0, 0.0),
+        Vector3::set(0.0, 2.0, 0.0),
+    );
+
+    #[test]
+    fn ray_intersection() {
+        let hit: Option<f32> = intersect_ray_triangle(
+            Ray::new(Vector3::set(0.5, 0.5, -3.0), Vector3::FORWARD),
+            TRIANGLE,
+        );
+        assert!((hit.unwrap() - 3.0).abs() <= THRESHOLD);
+
+        let back_face: Option<f32> = intersect_ray_triangle(
+            Ray::new(Vector3::set(0.5, 0.5, 3.0), -Vector3::FORWARD),
+            TRIANGLE,
+        );
+        assert!((back_face.unwrap() - 3.0).abs() <= THRESHOLD);
+
+        let outside: Option<f32> = intersect_ray_triangle(
+            Ray::new(Vector3::set(1.5, 1.5, -3.0), Vector3::FORWARD),
+            TRIANGLE,
+        );
+        assert!(outside.is_none());
+
+        let behind: Option<f32> = intersect_ray_triangle(
+            Ray::new(Vector3::set(0.5, 0.5, -3.0), -Vector3::FORWARD),
+            TRIANGLE,
+        );
+        assert!(behind.is_none());
+    }
+
+    #[test]
+    fn closest_point() {
+        assert!(approx_vec3(
+            closest_point_triangle(TRIANGLE, Vector3::set(0.5, 0.5, 4.0)),
+            Vector3::set(0.5, 0.5, 0.0)
+        ));
+        assert!(approx_vec3(
+            closest_point_triangle(TRIANGLE, Vector3::set(-1.0, -1.0, 0.0)),
+            TRIANGLE.a
+        ));
+        assert!(approx_vec3(
+            closest_point_triangle(TRIANGLE, Vector3::set(1.0, -1.0, 0.0)),
+            Vector3::set(1.0, 0.0, 0.0)
+        ));
+        assert!(approx_vec3(
+            closest_point_triangle(TRIANGLE, Vector3::set(2.0, 2.0, 0.0)),
+            Vector3::set(1.0, 1.0, 0.0)
+        ));
+        assert!(
+            (distance_triangle(TRIANGLE, Vector3::set(2.0, 2.0, 0.0)) - 2.0f32.sqrt()).abs()
+                <= THRESHOLD
+        );
+    }
+
+    #[test]
+    fn barycentric() {
+        assert!(approx_vec3(
+            barycentric_triangle(TRIANGLE, Vector3::set(1.0, 1.0, 0.0)),
+            Vector3::set(0.0, 0.5, 0.5)
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    Aabb, Affine2, Affine3, Bivector, DMatrix4x4, DQuaternion, DVector3, DVector4, DualNumber,
+    DualQuaternion, EulerOrder, Frustum, Half, IVector3, IVector4, Matrix2x2, Matrix3x3, Matrix4x4,
+    Obb, Plane, Quaternion, Ray, Rotor, Sphere, Triangle, UVector3, UVector4, Vector2, Vector3,
+    Vector4,
+};
+
+// Every type here is #[repr(C)] and built only from f32, f64, i32 or u32 lanes
+// with no padding. Three lane types keep their fourth lane, so Vector3 and the
+// Matrix3x3 columns are 16 bytes as in a std140/std430 vec3 array element.
+// Capsule and the boolean masks are left out since they have padding or
+// invalid bit patterns.
+macro_rules! impl_pod {
+    ($($target:ty),+ $(,)?) => {
+        $(
+            unsafe impl bytemuck::Zeroable for $target {}
+            unsafe impl bytemuck::Pod for $target {}
+        )+
+    };
+}
+
+impl_pod!(
+    Vector2,
+    Vector3,
+    Vector4,
+    DVector3,
+    DVector4,
+    IVector3,
+    IVector4,
+    UVector3,
+    UVector4,
+    Quaternion,
+    DQuaternion,
+    DualQuaternion,
+    DualNumber,
+    Rotor,
+    Bivector,
+    EulerOrder,
+    Matrix2x2,
+    Matrix3x3,
+    Matrix4x4,
+    DMatrix4x4,
+    Affine2,
+    Affine3,
+    Plane,
+    Sphere,
+    Ray,
+    Aabb,
+    Obb,
+    Triangle,
+    Frustum,
+    Half,
+);
+
+
+#[cfg(test)]
+mod bytemuck_test {
+    use crate::{Affine3, Matrix3x3, Matrix4x4, Vector3, Vector4};
+
+    #[test]
+    fn gpu_layout() {
+        assert_eq!(std::mem::size_of::<Vector3>(), 16);
+        assert_eq!(std::mem::size_of::<Matrix3x3>(), 48);
+        assert_eq!(std::mem::size_of::<Matrix4x4>(), 64);
+        assert_eq!(std::mem::size_of::<Affine3>(), 64);
+
+        let matrix: Matrix4x4 = Matrix4x4::set(
+            Vector4::set(1.0, 2.0, 3.0, 4.0),
+            Vector4::set(5.0, 6.0, 7.0, 8.0),
+            Vector4::set(9.0, 10.0, 11.0, 12.0),
+            Vector4::set(13.0, 14.0, 15.0, 16.0),
+        );
+
+        let floats: &[f32] = bytemuck::cast_slice(std::slice::from_ref(&matrix));
+        assert_eq!(floats, &matrix.to_primitive());
+
+        let positions: [Vector3; 2] = [Vector3::set(1.0, 2.0, 3.0), Vector3::set(4.0, 5.0, 6.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&positions);
+        assert_eq!(bytes.len(), 32);
+
+        let back: &[Vector3] = bytemuck::cast_slice(bytes);
+        assert_eq!(back[1].to_primitive(), [4.0, 5.0, 6.0]);
+    }
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
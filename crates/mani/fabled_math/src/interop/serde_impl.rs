@@ -0,0 +1,188 @@
+use crate::{
+    Affine2, Affine3, Bivector, Bool2, Bool3, Bool4, DMatrix4x4, DQuaternion, DVector3, DVector4,
+    DualNumber, DualQuaternion, EulerOrder, Half, IVector3, IVector4, Matrix2x2, Matrix3x3,
+    Matrix4x4, Plane, Quaternion, Rotor, Sphere, UVector3, UVector4, Vector2, Vector3, Vector4,
+};
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Types are written as flat arrays in the same order as to_primitive,
+// matrices being column-major.
+macro_rules! impl_serde_primitive {
+    ($($target:ty => $primitive:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $target {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.to_primitive().serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $target {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let primitive: $primitive = Deserialize::deserialize(deserializer)?;
+
+                    Ok(<$target>::from_primitive(primitive))
+                }
+            }
+        )+
+    };
+}
+
+impl_serde_primitive!(
+    Vector2 => [f32; 2],
+    Vector3 => [f32; 3],
+    Vector4 => [f32; 4],
+    DVector3 => [f64; 3],
+    DVector4 => [f64; 4],
+    IVector3 => [i32; 3],
+    IVector4 => [i32; 4],
+    UVector3 => [u32; 3],
+    UVector4 => [u32; 4],
+    Bool2 => [bool; 2],
+    Bool3 => [bool; 3],
+    Bool4 => [bool; 4],
+    Quaternion => [f32; 4],
+    DQuaternion => [f64; 4],
+    DualQuaternion => [f32; 8],
+    DualNumber => [f32; 2],
+    Rotor => [f32; 4],
+    Bivector => [f32; 3],
+    Matrix2x2 => [f32; 4],
+    Matrix3x3 => [f32; 9],
+    Matrix4x4 => [f32; 16],
+    DMatrix4x4 => [f64; 16],
+    Affine2 => [f32; 6],
+    Affine3 => [f32; 12],
+    Plane => [f32; 4],
+    Sphere => [f32; 4],
+);
+
+const EULER_ORDER_NAMES: [(&str, EulerOrder); 6] = [
+    ("XYZ", EulerOrder::XYZ),
+    ("YXZ", EulerOrder::YXZ),
+    ("ZXY", EulerOrder::ZXY),
+    ("ZYX", EulerOrder::ZYX),
+    ("YZX", EulerOrder::YZX),
+    ("XZY", EulerOrder::XZY),
+];
+
+// Half is written as its bit pattern so the value round trips exactly.
+impl Serialize for Half {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_bits().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Half {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Half::from_bits(Deserialize::deserialize(deserializer)?))
+    }
+}
+
+// Euler order is an implementation detail sign mask, so it is written by name.
+impl Serialize for EulerOrder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name: &str = EULER_ORDER_NAMES
+            .iter()
+            .find(|(_, order)| order == self)
+            .map(|(name, _)| *name)
+            .ok_or_else(|| serde::ser::Error::custom("unknown euler order"))?;
+
+        serializer.serialize_str(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for EulerOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name: String = Deserialize::deserialize(deserializer)?;
+
+        EULER_ORDER_NAMES
+            .iter()
+            .find(|(order_name, _)| *order_name == name)
+            .map(|(_, order)| *order)
+            .ok_or_else(|| {
+                D::Error::unknown_variant(&name, &["XYZ", "YXZ", "ZXY", "ZYX", "YZX", "XZY"])
+            })
+    }
+}
+
+
+#[cfg(test)]
+mod serde_test {
+    use crate::{
+        Affine3, DMatrix4x4, DVector3, DualQuaternion, EulerOrder, Frustum, IVector3, Matrix3x3,
+        Matrix4x4, Quaternion, Vector3, Vector4,
+    };
+
+    #[test]
+    fn vector_as_float_array() {
+        let vector: Vector3 = Vector3::set(1.0, 2.5, -3.0);
+
+        let json: String = serde_json::to_string(&vector).unwrap();
+        assert_eq!(json, "[1.0,2.5,-3.0]");
+
+        let back: Vector3 = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.to_primitive(), vector.to_primitive());
+
+        let ivector: IVector3 = serde_json::from_str("[1,-2,3]").unwrap();
+        assert_eq!(ivector.to_primitive(), [1, -2, 3]);
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let matrix: Matrix4x4 = Matrix4x4::set(
+            Vector4::set(1.0, 2.0, 3.0, 4.0),
+            Vector4::set(5.0, 6.0, 7.0, 8.0),
+            Vector4::set(9.0, 10.0, 11.0, 12.0),
+            Vector4::set(13.0, 14.0, 15.0, 16.0),
+        );
+
+        let json: String = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            json,
+            "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0,10.0,11.0,12.0,13.0,14.0,15.0,16.0]"
+        );
+        assert!(serde_json::from_str::<Matrix4x4>(&json).unwrap() == matrix);
+
+        let affine: Affine3 = Affine3 {
+            translation: Vector3::set(10.0, 11.0, 12.0),
+            matrix3: Matrix3x3::from_primitive([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+        };
+        let back: Affine3 = serde_json::from_str(&serde_json::to_string(&affine).unwrap()).unwrap();
+        assert_eq!(back.to_primitive(), affine.to_primitive());
+
+        let dmatrix: DMatrix4x4 = DMatrix4x4::from_primitive([
+            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0e9, 2.0, 3.0, 1.0,
+        ]);
+        let back: DMatrix4x4 =
+            serde_json::from_str(&serde_json::to_string(&dmatrix).unwrap()).unwrap();
+        assert!(back == dmatrix);
+
+        let position: DVector3 = serde_json::from_str("[1e12,0.5,-2]").unwrap();
+        assert_eq!(position.to_primitive(), [1.0e12, 0.5, -2.0]);
+    }
+
+    #[test]
+    fn quaternion_and_euler_order() {
+        let dual: DualQuaternion = DualQuaternion {
+            real: Quaternion::set(0.0, 0.0, 0.0, 1.0),
+            dual: Quaternion::set(0.5, 1.0, 1.5, 0.0),
+        };
+        let back: DualQuaternion =
+            serde_json::from_str(&serde_json::to_string(&dual).unwrap()).unwrap();
+        assert_eq!(back.to_primitive(), dual.to_primitive());
+
+        assert_eq!(serde_json::to_string(&EulerOrder::ZXY).unwrap(), "\"ZXY\"");
+        assert!(serde_json::from_str::<EulerOrder>("\"YZX\"").unwrap() == EulerOrder::YZX);
+        assert!(serde_json::from_str::<EulerOrder>("\"XXX\"").is_err());
+    }
+
+    #[test]
+    fn derived_composite() {
+        let frustum: Frustum = Frustum::default();
+
+        let json: String = serde_json::to_string(&frustum).unwrap();
+        assert!(serde_json::from_str::<Frustum>(&json).unwrap() == frustum);
+    }
+}
@@ -6,18 +6,33 @@
 
 extern crate core;
 
+#[cfg(test)]
+mod approx;
+mod batch;
 mod boolean;
+mod curve;
 mod easing;
 mod geometric;
+mod geometry;
+mod interop;
 mod linear;
 mod math_trait;
+mod noise;
+mod packing;
+mod random;
 mod transformation;
 
+pub use batch::*;
 pub use boolean::*;
+pub use curve::*;
 pub use easing::*;
 pub use geometric::*;
+pub use geometry::*;
 pub use linear::*;
 pub use math_trait::*;
+pub use noise::*;
+pub use packing::*;
+pub use random::*;
 pub use transformation::*;
 
 
@@ -663,3 +678,402 @@ pub mod vector_math {
         simd_vector.reduce_sum()
     }
 }
+
+pub mod dvector_math {
+    use std::simd::{SimdFloat, SimdPartialEq, SimdPartialOrd, StdFloat};
+
+    use crate::{DVector3, Vector3};
+
+    // Narrows a double precision comparison so it can back a Bool3 or Bool4.
+    #[inline(always)]
+    fn narrow_mask(mask: std::simd::mask64x4) -> std::simd::mask32x4 {
+        std::simd::mask32x4::from_array(mask.to_array())
+    }
+
+    #[inline(always)]
+    pub fn dot(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> f64 {
+        (simd_vector * simd_vector1).reduce_sum()
+    }
+
+    #[inline(always)]
+    pub fn length_squared(simd_vector: std::simd::f64x4) -> f64 {
+        dot(simd_vector, simd_vector)
+    }
+
+    #[inline(always)]
+    pub fn length(simd_vector: std::simd::f64x4) -> f64 {
+        length_squared(simd_vector).sqrt()
+    }
+
+    #[inline(always)]
+    pub fn distance_squared(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> f64 {
+        length_squared(simd_vector1 - simd_vector)
+    }
+
+    #[inline(always)]
+    pub fn distance(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> f64 {
+        distance_squared(simd_vector, simd_vector1).sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector * std::simd::f64x4::splat(length(simd_vector).recip())
+    }
+
+    #[inline]
+    pub fn cross(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::f64x4 {
+        const BIT_SHUFFLE_MASK: [usize; 4] = [1, 2, 0, 3];
+
+        let simd_yzx = std::simd::simd_swizzle!(simd_vector, BIT_SHUFFLE_MASK);
+        let simd1_yzx = std::simd::simd_swizzle!(simd_vector1, BIT_SHUFFLE_MASK);
+
+        let res = simd_vector * simd1_yzx - simd_vector1 * simd_yzx;
+
+        std::simd::simd_swizzle!(res, BIT_SHUFFLE_MASK)
+    }
+
+    #[inline]
+    pub fn lerp(
+        simd_vector: std::simd::f64x4,
+        simd_vector1: std::simd::f64x4,
+        delta: std::simd::f64x4,
+    ) -> std::simd::f64x4 {
+        (simd_vector1 - simd_vector).mul_add(delta, simd_vector)
+    }
+
+    #[inline(always)]
+    pub fn min(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.simd_min(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn max(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.simd_max(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn clamp(
+        simd_vector: std::simd::f64x4,
+        min: std::simd::f64x4,
+        max: std::simd::f64x4,
+    ) -> std::simd::f64x4 {
+        simd_vector.simd_clamp(min, max)
+    }
+
+    #[inline(always)]
+    pub fn abs(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.abs()
+    }
+
+    #[inline(always)]
+    pub fn floor(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.floor()
+    }
+
+    #[inline(always)]
+    pub fn ceil(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.ceil()
+    }
+
+    #[inline(always)]
+    pub fn round(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.round()
+    }
+
+    #[inline(always)]
+    pub fn trunc(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.trunc()
+    }
+
+    #[inline(always)]
+    pub fn fract(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.fract()
+    }
+
+    #[inline(always)]
+    pub fn sqrt(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.sqrt()
+    }
+
+    #[inline(always)]
+    pub fn rcp(simd_vector: std::simd::f64x4) -> std::simd::f64x4 {
+        simd_vector.recip()
+    }
+
+    #[inline(always)]
+    pub fn select(
+        simd_vector: std::simd::f64x4,
+        simd_vector1: std::simd::f64x4,
+        mask: std::simd::mask32x4,
+    ) -> std::simd::f64x4 {
+        std::simd::mask64x4::from_array(mask.to_array()).select(simd_vector, simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn component_sum(simd_vector: std::simd::f64x4) -> f64 {
+        simd_vector.reduce_sum()
+    }
+
+    #[inline(always)]
+    pub fn component_max(simd_vector: std::simd::f64x4) -> f64 {
+        simd_vector.reduce_max()
+    }
+
+    #[inline(always)]
+    pub fn component_min(simd_vector: std::simd::f64x4) -> f64 {
+        simd_vector.reduce_min()
+    }
+
+    #[inline(always)]
+    pub fn eq(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::mask32x4 {
+        narrow_mask(simd_vector.simd_eq(simd_vector1))
+    }
+
+    #[inline(always)]
+    pub fn gt(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::mask32x4 {
+        narrow_mask(simd_vector.simd_gt(simd_vector1))
+    }
+
+    #[inline(always)]
+    pub fn ge(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::mask32x4 {
+        narrow_mask(simd_vector.simd_ge(simd_vector1))
+    }
+
+    #[inline(always)]
+    pub fn le(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::mask32x4 {
+        narrow_mask(simd_vector.simd_le(simd_vector1))
+    }
+
+    #[inline(always)]
+    pub fn lt(simd_vector: std::simd::f64x4, simd_vector1: std::simd::f64x4) -> std::simd::mask32x4 {
+        narrow_mask(simd_vector.simd_lt(simd_vector1))
+    }
+
+    #[inline(always)]
+    pub fn is_finite(simd_vector: std::simd::f64x4) -> std::simd::mask32x4 {
+        narrow_mask(simd_vector.is_finite())
+    }
+
+    #[inline(always)]
+    pub fn is_nan(simd_vector: std::simd::f64x4) -> std::simd::mask32x4 {
+        narrow_mask(simd_vector.is_nan())
+    }
+
+    // The position relative to the camera, subtracted in double precision before narrowing.
+    #[inline]
+    pub fn to_camera_relative_vec3(position: DVector3, camera_position: DVector3) -> Vector3 {
+        (position - camera_position).as_vec3()
+    }
+}
+
+pub mod ivector_math {
+    use std::simd::{SimdInt, SimdOrd, SimdPartialEq, SimdPartialOrd};
+
+    #[inline(always)]
+    pub fn dot(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> i32 {
+        (simd_vector * simd_vector1).reduce_sum()
+    }
+
+    #[inline(always)]
+    pub fn length_squared(simd_vector: std::simd::i32x4) -> i32 {
+        dot(simd_vector, simd_vector)
+    }
+
+    #[inline(always)]
+    pub fn manhattan_distance(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> i32 {
+        (simd_vector1 - simd_vector).abs().reduce_sum()
+    }
+
+    #[inline(always)]
+    pub fn min(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> std::simd::i32x4 {
+        simd_vector.simd_min(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn max(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> std::simd::i32x4 {
+        simd_vector.simd_max(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn clamp(
+        simd_vector: std::simd::i32x4,
+        min: std::simd::i32x4,
+        max: std::simd::i32x4,
+    ) -> std::simd::i32x4 {
+        simd_vector.simd_clamp(min, max)
+    }
+
+    #[inline(always)]
+    pub fn abs(simd_vector: std::simd::i32x4) -> std::simd::i32x4 {
+        simd_vector.abs()
+    }
+
+    #[inline(always)]
+    pub fn signum(simd_vector: std::simd::i32x4) -> std::simd::i32x4 {
+        simd_vector.signum()
+    }
+
+    // Floored division, so negative coordinates map to the cell below rather than toward zero.
+    // Every lane divides by its own divisor, a zero one panics like the scalar division.
+    #[inline]
+    pub fn div_euclid(simd_vector: std::simd::i32x4, divisor: std::simd::i32x4) -> std::simd::i32x4 {
+        let dividend: [i32; 4] = simd_vector.to_array();
+        let divisor: [i32; 4] = divisor.to_array();
+
+        std::simd::i32x4::from_array([
+            dividend[0].div_euclid(divisor[0]),
+            dividend[1].div_euclid(divisor[1]),
+            dividend[2].div_euclid(divisor[2]),
+            dividend[3].div_euclid(divisor[3]),
+        ])
+    }
+
+    #[inline]
+    pub fn rem_euclid(simd_vector: std::simd::i32x4, divisor: std::simd::i32x4) -> std::simd::i32x4 {
+        let dividend: [i32; 4] = simd_vector.to_array();
+        let divisor: [i32; 4] = divisor.to_array();
+
+        std::simd::i32x4::from_array([
+            dividend[0].rem_euclid(divisor[0]),
+            dividend[1].rem_euclid(divisor[1]),
+            dividend[2].rem_euclid(divisor[2]),
+            dividend[3].rem_euclid(divisor[3]),
+        ])
+    }
+
+    #[inline(always)]
+    pub fn select(
+        simd_vector: std::simd::i32x4,
+        simd_vector1: std::simd::i32x4,
+        mask: std::simd::mask32x4,
+    ) -> std::simd::i32x4 {
+        mask.select(simd_vector, simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn component_sum(simd_vector: std::simd::i32x4) -> i32 {
+        simd_vector.reduce_sum()
+    }
+
+    #[inline(always)]
+    pub fn component_product(simd_vector: std::simd::i32x4) -> i32 {
+        simd_vector.reduce_product()
+    }
+
+    #[inline(always)]
+    pub fn component_max(simd_vector: std::simd::i32x4) -> i32 {
+        simd_vector.reduce_max()
+    }
+
+    #[inline(always)]
+    pub fn component_min(simd_vector: std::simd::i32x4) -> i32 {
+        simd_vector.reduce_min()
+    }
+
+    #[inline(always)]
+    pub fn eq(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_eq(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn gt(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_gt(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn ge(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_ge(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn le(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_le(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn lt(simd_vector: std::simd::i32x4, simd_vector1: std::simd::i32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_lt(simd_vector1)
+    }
+}
+
+pub mod uvector_math {
+    use std::simd::{SimdOrd, SimdPartialEq, SimdPartialOrd, SimdUint};
+
+    #[inline(always)]
+    pub fn dot(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> u32 {
+        (simd_vector * simd_vector1).reduce_sum()
+    }
+
+    #[inline(always)]
+    pub fn min(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> std::simd::u32x4 {
+        simd_vector.simd_min(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn max(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> std::simd::u32x4 {
+        simd_vector.simd_max(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn clamp(
+        simd_vector: std::simd::u32x4,
+        min: std::simd::u32x4,
+        max: std::simd::u32x4,
+    ) -> std::simd::u32x4 {
+        simd_vector.simd_clamp(min, max)
+    }
+
+    #[inline(always)]
+    pub fn select(
+        simd_vector: std::simd::u32x4,
+        simd_vector1: std::simd::u32x4,
+        mask: std::simd::mask32x4,
+    ) -> std::simd::u32x4 {
+        mask.select(simd_vector, simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn component_sum(simd_vector: std::simd::u32x4) -> u32 {
+        simd_vector.reduce_sum()
+    }
+
+    #[inline(always)]
+    pub fn component_product(simd_vector: std::simd::u32x4) -> u32 {
+        simd_vector.reduce_product()
+    }
+
+    #[inline(always)]
+    pub fn component_max(simd_vector: std::simd::u32x4) -> u32 {
+        simd_vector.reduce_max()
+    }
+
+    #[inline(always)]
+    pub fn component_min(simd_vector: std::simd::u32x4) -> u32 {
+        simd_vector.reduce_min()
+    }
+
+    #[inline(always)]
+    pub fn eq(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_eq(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn gt(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_gt(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn ge(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_ge(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn le(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_le(simd_vector1)
+    }
+
+    #[inline(always)]
+    pub fn lt(simd_vector: std::simd::u32x4, simd_vector1: std::simd::u32x4) -> std::simd::mask32x4 {
+        simd_vector.simd_lt(simd_vector1)
+    }
+}
@@ -0,0 +1,368 @@
+use crate::{IVector3, UVector3, Vector3};
+
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+// Keeps the unused w lane from dividing by zero.
+const DIVISOR_W: std::simd::f64x4 = std::simd::f64x4::from_array([0.0, 0.0, 0.0, 1.0]);
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct DVector3 {
+    pub value: std::simd::f64x4,
+}
+
+impl Default for DVector3 {
+    fn default() -> Self {
+        DVector3::ZERO
+    }
+}
+
+impl DVector3 {
+    pub const BACKWARD: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([0.0, 0.0, -1.0, 0.0]),
+    };
+
+    pub const FORWARD: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([0.0, 0.0, 1.0, 0.0]),
+    };
+
+    pub const UP: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([0.0, 1.0, 0.0, 0.0]),
+    };
+
+    pub const DOWN: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([0.0, -1.0, 0.0, 0.0]),
+    };
+
+    pub const RIGHT: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([1.0, 0.0, 0.0, 0.0]),
+    };
+
+    pub const LEFT: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([-1.0, 0.0, 0.0, 0.0]),
+    };
+
+    pub const ONE: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([1.0, 1.0, 1.0, 0.0]),
+    };
+
+    pub const NEG_ONE: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([-1.0, -1.0, -1.0, 0.0]),
+    };
+
+    pub const ZERO: DVector3 = DVector3 {
+        value: std::simd::f64x4::from_array([0.0, 0.0, 0.0, 0.0]),
+    };
+
+    #[inline(always)]
+    pub const fn set(x: f64, y: f64, z: f64) -> DVector3 {
+        DVector3 {
+            value: std::simd::f64x4::from_array([x, y, z, 0.0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: f64) -> DVector3 {
+        DVector3 {
+            value: std::simd::f64x4::from_array([val, val, val, 0.0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn x(self) -> f64 {
+        let array_dvector3: [f64; 4] = self.value.to_array();
+
+        array_dvector3[0]
+    }
+
+    #[inline(always)]
+    pub const fn y(self) -> f64 {
+        let array_dvector3: [f64; 4] = self.value.to_array();
+
+        array_dvector3[1]
+    }
+
+    #[inline(always)]
+    pub const fn z(self) -> f64 {
+        let array_dvector3: [f64; 4] = self.value.to_array();
+
+        array_dvector3[2]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f64; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f64; 3]) -> DVector3 {
+        DVector3::set(array[0], array[1], array[2])
+    }
+
+    #[inline]
+    pub fn as_vec3(self) -> Vector3 {
+        Vector3 {
+            value: self.value.cast::<f32>(),
+        }
+    }
+
+    #[inline]
+    pub fn as_ivec3(self) -> IVector3 {
+        IVector3 {
+            value: self.value.cast::<i32>(),
+        }
+    }
+}
+
+impl From<Vector3> for DVector3 {
+    #[inline]
+    fn from(value: Vector3) -> Self {
+        DVector3 {
+            value: value.value.cast::<f64>(),
+        }
+    }
+}
+
+impl From<IVector3> for DVector3 {
+    #[inline]
+    fn from(value: IVector3) -> Self {
+        DVector3 {
+            value: value.value.cast::<f64>(),
+        }
+    }
+}
+
+impl From<UVector3> for DVector3 {
+    #[inline]
+    fn from(value: UVector3) -> Self {
+        DVector3 {
+            value: value.value.cast::<f64>(),
+        }
+    }
+}
+
+impl Display for DVector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DVector3 (x : {}, y : {}, z : {})",
+            self.x(),
+            self.y(),
+            self.z()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<DVector3> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn mul(self, rhs: DVector3) -> Self::Output {
+        DVector3 {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl MulAssign<DVector3> for DVector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DVector3) {
+        self.value *= rhs.value;
+    }
+}
+
+impl Div<DVector3> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn div(self, rhs: DVector3) -> Self::Output {
+        DVector3 {
+            value: self.value / (rhs.value + DIVISOR_W),
+        }
+    }
+}
+
+impl DivAssign<DVector3> for DVector3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: DVector3) {
+        self.value /= rhs.value + DIVISOR_W;
+    }
+}
+
+impl Rem<DVector3> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn rem(self, rhs: DVector3) -> Self::Output {
+        DVector3 {
+            value: self.value % (rhs.value + DIVISOR_W),
+        }
+    }
+}
+
+impl RemAssign<DVector3> for DVector3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: DVector3) {
+        self.value %= rhs.value + DIVISOR_W;
+    }
+}
+
+impl Add<f64> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn add(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector3 {
+            value: self.value + splat_f64x4,
+        }
+    }
+}
+
+impl AddAssign<f64> for DVector3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value += splat_f64x4;
+    }
+}
+
+impl Sub<f64> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn sub(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector3 {
+            value: self.value - splat_f64x4,
+        }
+    }
+}
+
+impl SubAssign<f64> for DVector3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value -= splat_f64x4;
+    }
+}
+
+// Vector-Wise
+impl Mul<f64> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector3 {
+            value: self.value * splat_f64x4,
+        }
+    }
+}
+
+impl MulAssign<f64> for DVector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value *= splat_f64x4;
+    }
+}
+
+impl Div<f64> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector3 {
+            value: self.value / splat_f64x4,
+        }
+    }
+}
+
+impl DivAssign<f64> for DVector3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value /= splat_f64x4;
+    }
+}
+
+impl Rem<f64> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn rem(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector3 {
+            value: self.value % splat_f64x4,
+        }
+    }
+}
+
+impl RemAssign<f64> for DVector3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value %= splat_f64x4;
+    }
+}
+
+impl Add<DVector3> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn add(self, rhs: DVector3) -> Self::Output {
+        DVector3 {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign<DVector3> for DVector3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: DVector3) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub<DVector3> for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn sub(self, rhs: DVector3) -> Self::Output {
+        DVector3 {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign<DVector3> for DVector3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: DVector3) {
+        self.value -= rhs.value;
+    }
+}
+
+impl Neg for DVector3 {
+    type Output = DVector3;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DVector3 { value: -self.value }
+    }
+}
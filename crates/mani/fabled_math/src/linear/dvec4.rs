@@ -0,0 +1,347 @@
+use crate::{DVector3, Vector4};
+
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct DVector4 {
+    pub value: std::simd::f64x4,
+}
+
+impl Default for DVector4 {
+    fn default() -> Self {
+        DVector4::ZERO
+    }
+}
+
+impl DVector4 {
+    pub const W: DVector4 = DVector4 {
+        value: std::simd::f64x4::from_array([0.0, 0.0, 0.0, 1.0]),
+    };
+
+    pub const FORWARD: DVector4 = DVector4 {
+        value: std::simd::f64x4::from_array([0.0, 0.0, 1.0, 0.0]),
+    };
+
+    pub const UP: DVector4 = DVector4 {
+        value: std::simd::f64x4::from_array([0.0, 1.0, 0.0, 0.0]),
+    };
+
+    pub const RIGHT: DVector4 = DVector4 {
+        value: std::simd::f64x4::from_array([1.0, 0.0, 0.0, 0.0]),
+    };
+
+    pub const ONE: DVector4 = DVector4 {
+        value: std::simd::f64x4::from_array([1.0, 1.0, 1.0, 1.0]),
+    };
+
+    pub const NEG_ONE: DVector4 = DVector4 {
+        value: std::simd::f64x4::from_array([-1.0, -1.0, -1.0, -1.0]),
+    };
+
+    pub const ZERO: DVector4 = DVector4 {
+        value: std::simd::f64x4::from_array([0.0, 0.0, 0.0, 0.0]),
+    };
+
+    #[inline(always)]
+    pub const fn set(x: f64, y: f64, z: f64, w: f64) -> DVector4 {
+        DVector4 {
+            value: std::simd::f64x4::from_array([x, y, z, w]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: f64) -> DVector4 {
+        DVector4 {
+            value: std::simd::f64x4::from_array([val, val, val, val]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn x(self) -> f64 {
+        let array_dvector4: [f64; 4] = self.value.to_array();
+
+        array_dvector4[0]
+    }
+
+    #[inline(always)]
+    pub const fn y(self) -> f64 {
+        let array_dvector4: [f64; 4] = self.value.to_array();
+
+        array_dvector4[1]
+    }
+
+    #[inline(always)]
+    pub const fn z(self) -> f64 {
+        let array_dvector4: [f64; 4] = self.value.to_array();
+
+        array_dvector4[2]
+    }
+
+    #[inline(always)]
+    pub const fn w(self) -> f64 {
+        let array_dvector4: [f64; 4] = self.value.to_array();
+
+        array_dvector4[3]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f64; 4] {
+        self.value.to_array()
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f64; 4]) -> DVector4 {
+        DVector4 {
+            value: std::simd::f64x4::from_array(array),
+        }
+    }
+
+    #[inline]
+    pub const fn trunc_dvec3(self) -> DVector3 {
+        DVector3::set(self.x(), self.y(), self.z())
+    }
+
+    #[inline]
+    pub fn as_vec4(self) -> Vector4 {
+        Vector4 {
+            value: self.value.cast::<f32>(),
+        }
+    }
+}
+
+impl From<Vector4> for DVector4 {
+    #[inline]
+    fn from(value: Vector4) -> Self {
+        DVector4 {
+            value: value.value.cast::<f64>(),
+        }
+    }
+}
+
+impl Display for DVector4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DVector4 (x : {}, y : {}, z : {}, w : {})",
+            self.x(),
+            self.y(),
+            self.z(),
+            self.w()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<DVector4> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn mul(self, rhs: DVector4) -> Self::Output {
+        DVector4 {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl MulAssign<DVector4> for DVector4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: DVector4) {
+        self.value *= rhs.value;
+    }
+}
+
+impl Div<DVector4> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn div(self, rhs: DVector4) -> Self::Output {
+        DVector4 {
+            value: self.value / rhs.value,
+        }
+    }
+}
+
+impl DivAssign<DVector4> for DVector4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: DVector4) {
+        self.value /= rhs.value;
+    }
+}
+
+impl Rem<DVector4> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn rem(self, rhs: DVector4) -> Self::Output {
+        DVector4 {
+            value: self.value % rhs.value,
+        }
+    }
+}
+
+impl RemAssign<DVector4> for DVector4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: DVector4) {
+        self.value %= rhs.value;
+    }
+}
+
+impl Add<f64> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn add(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector4 {
+            value: self.value + splat_f64x4,
+        }
+    }
+}
+
+impl AddAssign<f64> for DVector4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value += splat_f64x4;
+    }
+}
+
+impl Sub<f64> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn sub(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector4 {
+            value: self.value - splat_f64x4,
+        }
+    }
+}
+
+impl SubAssign<f64> for DVector4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value -= splat_f64x4;
+    }
+}
+
+// Vector-Wise
+impl Mul<f64> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector4 {
+            value: self.value * splat_f64x4,
+        }
+    }
+}
+
+impl MulAssign<f64> for DVector4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value *= splat_f64x4;
+    }
+}
+
+impl Div<f64> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector4 {
+            value: self.value / splat_f64x4,
+        }
+    }
+}
+
+impl DivAssign<f64> for DVector4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value /= splat_f64x4;
+    }
+}
+
+impl Rem<f64> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn rem(self, rhs: f64) -> Self::Output {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        DVector4 {
+            value: self.value % splat_f64x4,
+        }
+    }
+}
+
+impl RemAssign<f64> for DVector4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: f64) {
+        let splat_f64x4: std::simd::f64x4 = std::simd::f64x4::splat(rhs);
+
+        self.value %= splat_f64x4;
+    }
+}
+
+impl Add<DVector4> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn add(self, rhs: DVector4) -> Self::Output {
+        DVector4 {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign<DVector4> for DVector4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: DVector4) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub<DVector4> for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn sub(self, rhs: DVector4) -> Self::Output {
+        DVector4 {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign<DVector4> for DVector4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: DVector4) {
+        self.value -= rhs.value;
+    }
+}
+
+impl Neg for DVector4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        DVector4 { value: -self.value }
+    }
+}
@@ -0,0 +1,490 @@
+use crate::{DVector3, UVector3, Vector3};
+
+use crate::ivector_math::{div_euclid, rem_euclid};
+use crate::vector_math::floor;
+
+use std::simd::StdFloat;
+
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign,
+};
+
+// Keeps the unused w lane from dividing by zero.
+const DIVISOR_W: std::simd::i32x4 = std::simd::i32x4::from_array([0, 0, 0, 1]);
+
+const LANE_MASK: std::simd::i32x4 = std::simd::i32x4::from_array([-1, -1, -1, 0]);
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct IVector3 {
+    pub value: std::simd::i32x4,
+}
+
+impl Default for IVector3 {
+    fn default() -> Self {
+        IVector3::ZERO
+    }
+}
+
+impl IVector3 {
+    pub const BACKWARD: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([0, 0, -1, 0]),
+    };
+
+    pub const FORWARD: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([0, 0, 1, 0]),
+    };
+
+    pub const UP: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([0, 1, 0, 0]),
+    };
+
+    pub const DOWN: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([0, -1, 0, 0]),
+    };
+
+    pub const RIGHT: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([1, 0, 0, 0]),
+    };
+
+    pub const LEFT: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([-1, 0, 0, 0]),
+    };
+
+    pub const ONE: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([1, 1, 1, 0]),
+    };
+
+    pub const NEG_ONE: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([-1, -1, -1, 0]),
+    };
+
+    pub const ZERO: IVector3 = IVector3 {
+        value: std::simd::i32x4::from_array([0, 0, 0, 0]),
+    };
+
+    #[inline(always)]
+    pub const fn set(x: i32, y: i32, z: i32) -> IVector3 {
+        IVector3 {
+            value: std::simd::i32x4::from_array([x, y, z, 0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: i32) -> IVector3 {
+        IVector3 {
+            value: std::simd::i32x4::from_array([val, val, val, 0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn x(self) -> i32 {
+        let array_ivector3: [i32; 4] = self.value.to_array();
+
+        array_ivector3[0]
+    }
+
+    #[inline(always)]
+    pub const fn y(self) -> i32 {
+        let array_ivector3: [i32; 4] = self.value.to_array();
+
+        array_ivector3[1]
+    }
+
+    #[inline(always)]
+    pub const fn z(self) -> i32 {
+        let array_ivector3: [i32; 4] = self.value.to_array();
+
+        array_ivector3[2]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [i32; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [i32; 3]) -> IVector3 {
+        IVector3::set(array[0], array[1], array[2])
+    }
+
+    // The containing cell of the position, rounding toward negative infinity.
+    #[inline]
+    pub fn from_vec3_floor(vector: Vector3) -> IVector3 {
+        IVector3 {
+            value: floor(vector.value).cast::<i32>(),
+        }
+    }
+
+    #[inline]
+    pub fn from_dvec3_floor(vector: DVector3) -> IVector3 {
+        IVector3 {
+            value: vector.value.floor().cast::<i32>(),
+        }
+    }
+
+    #[inline]
+    pub fn as_vec3(self) -> Vector3 {
+        Vector3 {
+            value: self.value.cast::<f32>(),
+        }
+    }
+
+    #[inline]
+    pub fn as_uvec3(self) -> UVector3 {
+        UVector3 {
+            value: self.value.cast::<u32>(),
+        }
+    }
+
+    // Floored division, the unused w lane divides by one.
+    #[inline]
+    pub fn div_euclid(self, rhs: IVector3) -> IVector3 {
+        IVector3 {
+            value: div_euclid(self.value, rhs.value + DIVISOR_W),
+        }
+    }
+
+    #[inline]
+    pub fn rem_euclid(self, rhs: IVector3) -> IVector3 {
+        IVector3 {
+            value: rem_euclid(self.value, rhs.value + DIVISOR_W),
+        }
+    }
+}
+
+impl Display for IVector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IVector3 (x : {}, y : {}, z : {})",
+            self.x(),
+            self.y(),
+            self.z()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn mul(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl MulAssign<IVector3> for IVector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: IVector3) {
+        self.value *= rhs.value;
+    }
+}
+
+impl Div<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn div(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value / (rhs.value + DIVISOR_W),
+        }
+    }
+}
+
+impl DivAssign<IVector3> for IVector3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: IVector3) {
+        self.value /= rhs.value + DIVISOR_W;
+    }
+}
+
+impl Rem<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn rem(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value % (rhs.value + DIVISOR_W),
+        }
+    }
+}
+
+impl RemAssign<IVector3> for IVector3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: IVector3) {
+        self.value %= rhs.value + DIVISOR_W;
+    }
+}
+
+impl Add<i32> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector3 {
+            value: self.value + splat_i32x4,
+        }
+    }
+}
+
+impl AddAssign<i32> for IVector3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value += splat_i32x4;
+    }
+}
+
+impl Sub<i32> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector3 {
+            value: self.value - splat_i32x4,
+        }
+    }
+}
+
+impl SubAssign<i32> for IVector3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value -= splat_i32x4;
+    }
+}
+
+// Vector-Wise
+impl Mul<i32> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector3 {
+            value: self.value * splat_i32x4,
+        }
+    }
+}
+
+impl MulAssign<i32> for IVector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value *= splat_i32x4;
+    }
+}
+
+impl Div<i32> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector3 {
+            value: self.value / splat_i32x4,
+        }
+    }
+}
+
+impl DivAssign<i32> for IVector3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value /= splat_i32x4;
+    }
+}
+
+impl Rem<i32> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector3 {
+            value: self.value % splat_i32x4,
+        }
+    }
+}
+
+impl RemAssign<i32> for IVector3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value %= splat_i32x4;
+    }
+}
+
+impl Add<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn add(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign<IVector3> for IVector3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: IVector3) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn sub(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign<IVector3> for IVector3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: IVector3) {
+        self.value -= rhs.value;
+    }
+}
+
+impl Neg for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        IVector3 { value: -self.value }
+    }
+}
+
+// Bit-Wise
+impl BitAnd<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn bitand(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitAndAssign<IVector3> for IVector3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: IVector3) {
+        self.value &= rhs.value;
+    }
+}
+
+impl BitOr<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn bitor(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign<IVector3> for IVector3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: IVector3) {
+        self.value |= rhs.value;
+    }
+}
+
+impl BitXor<IVector3> for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn bitxor(self, rhs: IVector3) -> Self::Output {
+        IVector3 {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl BitXorAssign<IVector3> for IVector3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: IVector3) {
+        self.value ^= rhs.value;
+    }
+}
+
+impl Not for IVector3 {
+    type Output = IVector3;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        IVector3 {
+            value: !self.value & LANE_MASK,
+        }
+    }
+}
+
+#[cfg(test)]
+mod ivector3_test {
+    use crate::ivector_math::{div_euclid, lt};
+    use crate::{Bool3, DVector3, IVector3, Vector3};
+
+    #[test]
+    fn floor_conversion() {
+        let cell: IVector3 = IVector3::from_vec3_floor(Vector3::set(1.5, -0.25, -3.0));
+        assert_eq!(cell.to_primitive(), [1, -1, -3]);
+
+        let far_cell: IVector3 =
+            IVector3::from_dvec3_floor(DVector3::set(1e9 + 0.5, -1e9 - 0.5, 0.0));
+        assert_eq!(far_cell.to_primitive(), [1_000_000_000, -1_000_000_001, 0]);
+
+        assert_eq!(DVector3::from(cell).to_primitive(), [1.0, -1.0, -3.0]);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let position: IVector3 = IVector3::set(-17, 33, 16);
+        let chunk_size: IVector3 = IVector3::broadcast(16);
+
+        assert_eq!((position / chunk_size).to_primitive(), [-1, 2, 1]);
+        assert_eq!(position.div_euclid(chunk_size).to_primitive(), [-2, 2, 1]);
+        assert_eq!(position.rem_euclid(chunk_size).to_primitive(), [15, 1, 0]);
+
+        // The simd helper divides every lane by its own divisor, w included.
+        assert_eq!(
+            div_euclid(
+                std::simd::i32x4::from_array([7, -7, 7, -7]),
+                std::simd::i32x4::from_array([2, 2, -2, -2])
+            )
+            .to_array(),
+            [3, -4, -3, 4]
+        );
+
+        assert_eq!((!IVector3::ZERO).value.to_array(), [-1, -1, -1, 0]);
+
+        let mask: Bool3 = Bool3 {
+            value: lt(position.value, IVector3::ZERO.value),
+        };
+        assert_eq!(mask.to_primitive(), [true, false, false]);
+    }
+}
@@ -0,0 +1,398 @@
+use crate::{IVector3, UVector4, Vector4};
+
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct IVector4 {
+    pub value: std::simd::i32x4,
+}
+
+impl Default for IVector4 {
+    fn default() -> Self {
+        IVector4::ZERO
+    }
+}
+
+impl IVector4 {
+    pub const W: IVector4 = IVector4 {
+        value: std::simd::i32x4::from_array([0, 0, 0, 1]),
+    };
+
+    pub const ONE: IVector4 = IVector4 {
+        value: std::simd::i32x4::from_array([1, 1, 1, 1]),
+    };
+
+    pub const NEG_ONE: IVector4 = IVector4 {
+        value: std::simd::i32x4::from_array([-1, -1, -1, -1]),
+    };
+
+    pub const ZERO: IVector4 = IVector4 {
+        value: std::simd::i32x4::from_array([0, 0, 0, 0]),
+    };
+
+    #[inline(always)]
+    pub const fn set(x: i32, y: i32, z: i32, w: i32) -> IVector4 {
+        IVector4 {
+            value: std::simd::i32x4::from_array([x, y, z, w]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: i32) -> IVector4 {
+        IVector4 {
+            value: std::simd::i32x4::from_array([val, val, val, val]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn x(self) -> i32 {
+        let array_ivector4: [i32; 4] = self.value.to_array();
+
+        array_ivector4[0]
+    }
+
+    #[inline(always)]
+    pub const fn y(self) -> i32 {
+        let array_ivector4: [i32; 4] = self.value.to_array();
+
+        array_ivector4[1]
+    }
+
+    #[inline(always)]
+    pub const fn z(self) -> i32 {
+        let array_ivector4: [i32; 4] = self.value.to_array();
+
+        array_ivector4[2]
+    }
+
+    #[inline(always)]
+    pub const fn w(self) -> i32 {
+        let array_ivector4: [i32; 4] = self.value.to_array();
+
+        array_ivector4[3]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [i32; 4] {
+        self.value.to_array()
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [i32; 4]) -> IVector4 {
+        IVector4 {
+            value: std::simd::i32x4::from_array(array),
+        }
+    }
+
+    #[inline]
+    pub const fn trunc_ivec3(self) -> IVector3 {
+        IVector3::set(self.x(), self.y(), self.z())
+    }
+
+    #[inline]
+    pub fn as_vec4(self) -> Vector4 {
+        Vector4 {
+            value: self.value.cast::<f32>(),
+        }
+    }
+
+    #[inline]
+    pub fn as_uvec4(self) -> UVector4 {
+        UVector4 {
+            value: self.value.cast::<u32>(),
+        }
+    }
+}
+
+impl Display for IVector4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IVector4 (x : {}, y : {}, z : {}, w : {})",
+            self.x(),
+            self.y(),
+            self.z(),
+            self.w()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn mul(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl MulAssign<IVector4> for IVector4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: IVector4) {
+        self.value *= rhs.value;
+    }
+}
+
+impl Div<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn div(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value / rhs.value,
+        }
+    }
+}
+
+impl DivAssign<IVector4> for IVector4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: IVector4) {
+        self.value /= rhs.value;
+    }
+}
+
+impl Rem<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn rem(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value % rhs.value,
+        }
+    }
+}
+
+impl RemAssign<IVector4> for IVector4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: IVector4) {
+        self.value %= rhs.value;
+    }
+}
+
+impl Add<i32> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn add(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector4 {
+            value: self.value + splat_i32x4,
+        }
+    }
+}
+
+impl AddAssign<i32> for IVector4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value += splat_i32x4;
+    }
+}
+
+impl Sub<i32> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn sub(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector4 {
+            value: self.value - splat_i32x4,
+        }
+    }
+}
+
+impl SubAssign<i32> for IVector4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value -= splat_i32x4;
+    }
+}
+
+// Vector-Wise
+impl Mul<i32> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector4 {
+            value: self.value * splat_i32x4,
+        }
+    }
+}
+
+impl MulAssign<i32> for IVector4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value *= splat_i32x4;
+    }
+}
+
+impl Div<i32> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector4 {
+            value: self.value / splat_i32x4,
+        }
+    }
+}
+
+impl DivAssign<i32> for IVector4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value /= splat_i32x4;
+    }
+}
+
+impl Rem<i32> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn rem(self, rhs: i32) -> Self::Output {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        IVector4 {
+            value: self.value % splat_i32x4,
+        }
+    }
+}
+
+impl RemAssign<i32> for IVector4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: i32) {
+        let splat_i32x4: std::simd::i32x4 = std::simd::i32x4::splat(rhs);
+
+        self.value %= splat_i32x4;
+    }
+}
+
+impl Add<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn add(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign<IVector4> for IVector4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: IVector4) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn sub(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign<IVector4> for IVector4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: IVector4) {
+        self.value -= rhs.value;
+    }
+}
+
+impl Neg for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        IVector4 { value: -self.value }
+    }
+}
+
+// Bit-Wise
+impl BitAnd<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn bitand(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitAndAssign<IVector4> for IVector4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: IVector4) {
+        self.value &= rhs.value;
+    }
+}
+
+impl BitOr<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn bitor(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign<IVector4> for IVector4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: IVector4) {
+        self.value |= rhs.value;
+    }
+}
+
+impl BitXor<IVector4> for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn bitxor(self, rhs: IVector4) -> Self::Output {
+        IVector4 {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl BitXorAssign<IVector4> for IVector4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: IVector4) {
+        self.value ^= rhs.value;
+    }
+}
+
+impl Not for IVector4 {
+    type Output = IVector4;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        IVector4 { value: !self.value }
+    }
+}
@@ -1,7 +1,19 @@
+mod dvec3;
+mod dvec4;
+mod ivec3;
+mod ivec4;
+mod uvec3;
+mod uvec4;
 mod vec2;
 mod vec3;
 mod vec4;
 
+pub use dvec3::*;
+pub use dvec4::*;
+pub use ivec3::*;
+pub use ivec4::*;
+pub use uvec3::*;
+pub use uvec4::*;
 pub use vec2::*;
 pub use vec3::*;
 pub use vec4::*;
@@ -0,0 +1,385 @@
+use crate::{IVector3, Vector3};
+
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Rem, RemAssign, Sub, SubAssign,
+};
+
+// Keeps the unused w lane from dividing by zero.
+const DIVISOR_W: std::simd::u32x4 = std::simd::u32x4::from_array([0, 0, 0, 1]);
+
+const LANE_MASK: std::simd::u32x4 = std::simd::u32x4::from_array([u32::MAX, u32::MAX, u32::MAX, 0]);
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct UVector3 {
+    pub value: std::simd::u32x4,
+}
+
+impl Default for UVector3 {
+    fn default() -> Self {
+        UVector3::ZERO
+    }
+}
+
+impl UVector3 {
+    pub const FORWARD: UVector3 = UVector3 {
+        value: std::simd::u32x4::from_array([0, 0, 1, 0]),
+    };
+
+    pub const UP: UVector3 = UVector3 {
+        value: std::simd::u32x4::from_array([0, 1, 0, 0]),
+    };
+
+    pub const RIGHT: UVector3 = UVector3 {
+        value: std::simd::u32x4::from_array([1, 0, 0, 0]),
+    };
+
+    pub const ONE: UVector3 = UVector3 {
+        value: std::simd::u32x4::from_array([1, 1, 1, 0]),
+    };
+
+    pub const ZERO: UVector3 = UVector3 {
+        value: std::simd::u32x4::from_array([0, 0, 0, 0]),
+    };
+
+    #[inline(always)]
+    pub const fn set(x: u32, y: u32, z: u32) -> UVector3 {
+        UVector3 {
+            value: std::simd::u32x4::from_array([x, y, z, 0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: u32) -> UVector3 {
+        UVector3 {
+            value: std::simd::u32x4::from_array([val, val, val, 0]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn x(self) -> u32 {
+        let array_uvector3: [u32; 4] = self.value.to_array();
+
+        array_uvector3[0]
+    }
+
+    #[inline(always)]
+    pub const fn y(self) -> u32 {
+        let array_uvector3: [u32; 4] = self.value.to_array();
+
+        array_uvector3[1]
+    }
+
+    #[inline(always)]
+    pub const fn z(self) -> u32 {
+        let array_uvector3: [u32; 4] = self.value.to_array();
+
+        array_uvector3[2]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [u32; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [u32; 3]) -> UVector3 {
+        UVector3::set(array[0], array[1], array[2])
+    }
+
+    #[inline]
+    pub fn as_vec3(self) -> Vector3 {
+        Vector3 {
+            value: self.value.cast::<f32>(),
+        }
+    }
+
+    #[inline]
+    pub fn as_ivec3(self) -> IVector3 {
+        IVector3 {
+            value: self.value.cast::<i32>(),
+        }
+    }
+}
+
+impl Display for UVector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UVector3 (x : {}, y : {}, z : {})",
+            self.x(),
+            self.y(),
+            self.z()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn mul(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl MulAssign<UVector3> for UVector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: UVector3) {
+        self.value *= rhs.value;
+    }
+}
+
+impl Div<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn div(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value / (rhs.value + DIVISOR_W),
+        }
+    }
+}
+
+impl DivAssign<UVector3> for UVector3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: UVector3) {
+        self.value /= rhs.value + DIVISOR_W;
+    }
+}
+
+impl Rem<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn rem(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value % (rhs.value + DIVISOR_W),
+        }
+    }
+}
+
+impl RemAssign<UVector3> for UVector3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: UVector3) {
+        self.value %= rhs.value + DIVISOR_W;
+    }
+}
+
+impl Add<u32> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn add(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector3 {
+            value: self.value + splat_u32x4,
+        }
+    }
+}
+
+impl AddAssign<u32> for UVector3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value += splat_u32x4;
+    }
+}
+
+impl Sub<u32> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn sub(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector3 {
+            value: self.value - splat_u32x4,
+        }
+    }
+}
+
+impl SubAssign<u32> for UVector3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value -= splat_u32x4;
+    }
+}
+
+// Vector-Wise
+impl Mul<u32> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector3 {
+            value: self.value * splat_u32x4,
+        }
+    }
+}
+
+impl MulAssign<u32> for UVector3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value *= splat_u32x4;
+    }
+}
+
+impl Div<u32> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn div(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector3 {
+            value: self.value / splat_u32x4,
+        }
+    }
+}
+
+impl DivAssign<u32> for UVector3 {
+    #[inline]
+    fn div_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value /= splat_u32x4;
+    }
+}
+
+impl Rem<u32> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn rem(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector3 {
+            value: self.value % splat_u32x4,
+        }
+    }
+}
+
+impl RemAssign<u32> for UVector3 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value %= splat_u32x4;
+    }
+}
+
+impl Add<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn add(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign<UVector3> for UVector3 {
+    #[inline]
+    fn add_assign(&mut self, rhs: UVector3) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn sub(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign<UVector3> for UVector3 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: UVector3) {
+        self.value -= rhs.value;
+    }
+}
+
+// Bit-Wise
+impl BitAnd<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn bitand(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitAndAssign<UVector3> for UVector3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: UVector3) {
+        self.value &= rhs.value;
+    }
+}
+
+impl BitOr<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn bitor(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign<UVector3> for UVector3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: UVector3) {
+        self.value |= rhs.value;
+    }
+}
+
+impl BitXor<UVector3> for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn bitxor(self, rhs: UVector3) -> Self::Output {
+        UVector3 {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl BitXorAssign<UVector3> for UVector3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: UVector3) {
+        self.value ^= rhs.value;
+    }
+}
+
+impl Not for UVector3 {
+    type Output = UVector3;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        UVector3 {
+            value: !self.value & LANE_MASK,
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{IVector4, UVector3, Vector4};
+
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Rem, RemAssign, Sub, SubAssign,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct UVector4 {
+    pub value: std::simd::u32x4,
+}
+
+impl Default for UVector4 {
+    fn default() -> Self {
+        UVector4::ZERO
+    }
+}
+
+impl UVector4 {
+    pub const ONE: UVector4 = UVector4 {
+        value: std::simd::u32x4::from_array([1, 1, 1, 1]),
+    };
+
+    pub const ZERO: UVector4 = UVector4 {
+        value: std::simd::u32x4::from_array([0, 0, 0, 0]),
+    };
+
+    #[inline(always)]
+    pub const fn set(x: u32, y: u32, z: u32, w: u32) -> UVector4 {
+        UVector4 {
+            value: std::simd::u32x4::from_array([x, y, z, w]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: u32) -> UVector4 {
+        UVector4 {
+            value: std::simd::u32x4::from_array([val, val, val, val]),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn x(self) -> u32 {
+        let array_uvector4: [u32; 4] = self.value.to_array();
+
+        array_uvector4[0]
+    }
+
+    #[inline(always)]
+    pub const fn y(self) -> u32 {
+        let array_uvector4: [u32; 4] = self.value.to_array();
+
+        array_uvector4[1]
+    }
+
+    #[inline(always)]
+    pub const fn z(self) -> u32 {
+        let array_uvector4: [u32; 4] = self.value.to_array();
+
+        array_uvector4[2]
+    }
+
+    #[inline(always)]
+    pub const fn w(self) -> u32 {
+        let array_uvector4: [u32; 4] = self.value.to_array();
+
+        array_uvector4[3]
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [u32; 4] {
+        self.value.to_array()
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [u32; 4]) -> UVector4 {
+        UVector4 {
+            value: std::simd::u32x4::from_array(array),
+        }
+    }
+
+    #[inline]
+    pub const fn trunc_uvec3(self) -> UVector3 {
+        UVector3::set(self.x(), self.y(), self.z())
+    }
+
+    #[inline]
+    pub fn as_vec4(self) -> Vector4 {
+        Vector4 {
+            value: self.value.cast::<f32>(),
+        }
+    }
+
+    #[inline]
+    pub fn as_ivec4(self) -> IVector4 {
+        IVector4 {
+            value: self.value.cast::<i32>(),
+        }
+    }
+}
+
+impl Display for UVector4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UVector4 (x : {}, y : {}, z : {}, w : {})",
+            self.x(),
+            self.y(),
+            self.z(),
+            self.w()
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn mul(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value * rhs.value,
+        }
+    }
+}
+
+impl MulAssign<UVector4> for UVector4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: UVector4) {
+        self.value *= rhs.value;
+    }
+}
+
+impl Div<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn div(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value / rhs.value,
+        }
+    }
+}
+
+impl DivAssign<UVector4> for UVector4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: UVector4) {
+        self.value /= rhs.value;
+    }
+}
+
+impl Rem<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn rem(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value % rhs.value,
+        }
+    }
+}
+
+impl RemAssign<UVector4> for UVector4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: UVector4) {
+        self.value %= rhs.value;
+    }
+}
+
+impl Add<u32> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn add(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector4 {
+            value: self.value + splat_u32x4,
+        }
+    }
+}
+
+impl AddAssign<u32> for UVector4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value += splat_u32x4;
+    }
+}
+
+impl Sub<u32> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn sub(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector4 {
+            value: self.value - splat_u32x4,
+        }
+    }
+}
+
+impl SubAssign<u32> for UVector4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value -= splat_u32x4;
+    }
+}
+
+// Vector-Wise
+impl Mul<u32> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector4 {
+            value: self.value * splat_u32x4,
+        }
+    }
+}
+
+impl MulAssign<u32> for UVector4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value *= splat_u32x4;
+    }
+}
+
+impl Div<u32> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn div(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector4 {
+            value: self.value / splat_u32x4,
+        }
+    }
+}
+
+impl DivAssign<u32> for UVector4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value /= splat_u32x4;
+    }
+}
+
+impl Rem<u32> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn rem(self, rhs: u32) -> Self::Output {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        UVector4 {
+            value: self.value % splat_u32x4,
+        }
+    }
+}
+
+impl RemAssign<u32> for UVector4 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: u32) {
+        let splat_u32x4: std::simd::u32x4 = std::simd::u32x4::splat(rhs);
+
+        self.value %= splat_u32x4;
+    }
+}
+
+impl Add<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn add(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign<UVector4> for UVector4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: UVector4) {
+        self.value += rhs.value;
+    }
+}
+
+impl Sub<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn sub(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value - rhs.value,
+        }
+    }
+}
+
+impl SubAssign<UVector4> for UVector4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: UVector4) {
+        self.value -= rhs.value;
+    }
+}
+
+// Bit-Wise
+impl BitAnd<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn bitand(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl BitAndAssign<UVector4> for UVector4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: UVector4) {
+        self.value &= rhs.value;
+    }
+}
+
+impl BitOr<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn bitor(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value | rhs.value,
+        }
+    }
+}
+
+impl BitOrAssign<UVector4> for UVector4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: UVector4) {
+        self.value |= rhs.value;
+    }
+}
+
+impl BitXor<UVector4> for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn bitxor(self, rhs: UVector4) -> Self::Output {
+        UVector4 {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl BitXorAssign<UVector4> for UVector4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: UVector4) {
+        self.value ^= rhs.value;
+    }
+}
+
+impl Not for UVector4 {
+    type Output = UVector4;
+
+    #[inline]
+    fn not(self) -> Self::Output {
+        UVector4 { value: !self.value }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Vector3, Vector4};
+use crate::{Matrix2x2, Vector3, Vector4};
 
 use crate::math_trait::Swizzles2;
 
@@ -9,6 +9,7 @@ use std::ops::{
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct Vector2 {
     pub value: [f32; 2],
 }
@@ -77,6 +78,22 @@ impl Display for Vector2 {
     }
 }
 
+impl Mul<Vector2> for Matrix2x2 {
+    type Output = Vector2;
+
+    #[inline]
+    fn mul(self, rhs: Vector2) -> Self::Output {
+        self.column_x * rhs.x() + self.column_y * rhs.y()
+    }
+}
+
+impl MulAssign<Matrix2x2> for Vector2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Matrix2x2) {
+        self.value = (rhs * *self).value;
+    }
+}
+
 // Component-Wise
 impl Mul<Vector2> for Vector2 {
     type Output = Vector2;
@@ -84,7 +101,7 @@ impl Mul<Vector2> for Vector2 {
     #[inline]
     fn mul(self, rhs: Vector2) -> Self::Output {
         let res_y = self.value[1] * rhs.value[1];
-        let res_x = self.value[0] * self.value[0];
+        let res_x = self.value[0] * rhs.value[0];
 
         Vector2 {
             value: [res_x, res_y],
@@ -271,8 +288,8 @@ impl Sub<Vector2> for Vector2 {
 
     #[inline]
     fn sub(self, rhs: Vector2) -> Self::Output {
-        let y = self.value[1] + rhs.value[1];
-        let x = self.value[0] + rhs.value[0];
+        let y = self.value[1] - rhs.value[1];
+        let x = self.value[0] - rhs.value[0];
 
         Vector2 { value: [x, y] }
     }
@@ -436,3 +453,416 @@ impl Swizzles2 for Vector2 {
         Vector4::broadcast(self.y())
     }
 }
+
+pub mod vector2_math {
+    use crate::{Bool2, Vector2};
+
+    #[inline(always)]
+    fn map(vector: Vector2, function: impl Fn(f32) -> f32) -> Vector2 {
+        Vector2::set(function(vector.x()), function(vector.y()))
+    }
+
+    #[inline(always)]
+    fn zip(vector: Vector2, vector1: Vector2, function: impl Fn(f32, f32) -> f32) -> Vector2 {
+        Vector2::set(
+            function(vector.x(), vector1.x()),
+            function(vector.y(), vector1.y()),
+        )
+    }
+
+    #[inline(always)]
+    fn compare(vector: Vector2, vector1: Vector2, function: impl Fn(f32, f32) -> bool) -> Bool2 {
+        Bool2::set(
+            function(vector.x(), vector1.x()),
+            function(vector.y(), vector1.y()),
+        )
+    }
+
+    #[inline]
+    pub fn degrees(vector: Vector2) -> Vector2 {
+        map(vector, f32::to_degrees)
+    }
+
+    #[inline]
+    pub fn radians(vector: Vector2) -> Vector2 {
+        map(vector, f32::to_radians)
+    }
+
+    #[inline]
+    pub fn rcp(vector: Vector2) -> Vector2 {
+        map(vector, f32::recip)
+    }
+
+    #[inline]
+    pub fn dot(vector: Vector2, vector1: Vector2) -> f32 {
+        vector.x() * vector1.x() + vector.y() * vector1.y()
+    }
+
+    // The z component of the 3d cross product, positive when vector1 is counter clockwise of vector.
+    #[inline]
+    pub fn cross(vector: Vector2, vector1: Vector2) -> f32 {
+        vector.x() * vector1.y() - vector.y() * vector1.x()
+    }
+
+    // Rotated 90 degrees counter clockwise.
+    #[inline]
+    pub fn perpendicular(vector: Vector2) -> Vector2 {
+        Vector2::set(-vector.y(), vector.x())
+    }
+
+    #[inline]
+    pub fn pow(vector: Vector2, exponent: Vector2) -> Vector2 {
+        zip(vector, exponent, f32::powf)
+    }
+
+    #[inline]
+    pub fn max(vector: Vector2, vector1: Vector2) -> Vector2 {
+        zip(vector, vector1, f32::max)
+    }
+
+    #[inline]
+    pub fn min(vector: Vector2, vector1: Vector2) -> Vector2 {
+        zip(vector, vector1, f32::min)
+    }
+
+    #[inline]
+    pub fn clamp(vector: Vector2, min_vector: Vector2, max_vector: Vector2) -> Vector2 {
+        min(max(vector, min_vector), max_vector)
+    }
+
+    #[inline]
+    pub fn length_squared(vector: Vector2) -> f32 {
+        dot(vector, vector)
+    }
+
+    #[inline]
+    pub fn length(vector: Vector2) -> f32 {
+        length_squared(vector).sqrt()
+    }
+
+    #[inline]
+    pub fn distance_squared(vector: Vector2, vector1: Vector2) -> f32 {
+        length_squared(vector1 - vector)
+    }
+
+    #[inline]
+    pub fn distance(vector: Vector2, vector1: Vector2) -> f32 {
+        distance_squared(vector, vector1).sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(vector: Vector2) -> Vector2 {
+        vector * length(vector).recip()
+    }
+
+    #[inline]
+    pub fn mul_add(vector: Vector2, mul_vector: Vector2, add_vector: Vector2) -> Vector2 {
+        Vector2::set(
+            vector.x().mul_add(mul_vector.x(), add_vector.x()),
+            vector.y().mul_add(mul_vector.y(), add_vector.y()),
+        )
+    }
+
+    #[inline]
+    pub fn lerp(start: Vector2, end: Vector2, delta: Vector2) -> Vector2 {
+        mul_add(end - start, delta, start)
+    }
+
+    #[inline]
+    pub fn unlerp(vector: Vector2, min_vector: Vector2, max_vector: Vector2) -> Vector2 {
+        (vector - min_vector) / (max_vector - min_vector)
+    }
+
+    #[inline]
+    pub fn select(vector: Vector2, vector1: Vector2, mask: Bool2) -> Vector2 {
+        Vector2::set(
+            if mask.x() { vector.x() } else { vector1.x() },
+            if mask.y() { vector.y() } else { vector1.y() },
+        )
+    }
+
+    #[inline]
+    pub fn saturate(vector: Vector2) -> Vector2 {
+        clamp(vector, Vector2::ZERO, Vector2::ONE)
+    }
+
+    #[inline]
+    pub fn fract(vector: Vector2) -> Vector2 {
+        map(vector, f32::fract)
+    }
+
+    #[inline]
+    pub fn round(vector: Vector2) -> Vector2 {
+        map(vector, f32::round)
+    }
+
+    #[inline]
+    pub fn trunc(vector: Vector2) -> Vector2 {
+        map(vector, f32::trunc)
+    }
+
+    #[inline]
+    pub fn ceil(vector: Vector2) -> Vector2 {
+        map(vector, f32::ceil)
+    }
+
+    #[inline]
+    pub fn floor(vector: Vector2) -> Vector2 {
+        map(vector, f32::floor)
+    }
+
+    #[inline]
+    pub fn angle(vector: Vector2, vector1: Vector2) -> f32 {
+        dot(normalize(vector), normalize(vector1))
+            .clamp(-1.0, 1.0)
+            .acos()
+    }
+
+    // Counter clockwise from vector to vector1 in the range [-pi, pi].
+    #[inline]
+    pub fn signed_angle(vector: Vector2, vector1: Vector2) -> f32 {
+        cross(vector, vector1).atan2(dot(vector, vector1))
+    }
+
+    #[inline]
+    pub fn sqrt(vector: Vector2) -> Vector2 {
+        map(vector, f32::sqrt)
+    }
+
+    #[inline]
+    pub fn rsqrt(vector: Vector2) -> Vector2 {
+        map(vector, |val| val.sqrt().recip())
+    }
+
+    #[inline]
+    pub fn abs(vector: Vector2) -> Vector2 {
+        map(vector, f32::abs)
+    }
+
+    #[inline]
+    pub fn step(edge: Vector2, vector: Vector2) -> Vector2 {
+        zip(edge, vector, |edge, val| if val >= edge { 1.0 } else { 0.0 })
+    }
+
+    #[inline]
+    pub fn smooth_step(min_edge: Vector2, max_edge: Vector2, vector: Vector2) -> Vector2 {
+        let delta: Vector2 = saturate(unlerp(vector, min_edge, max_edge));
+
+        delta * delta * (Vector2::broadcast(3.0) - delta * 2.0)
+    }
+
+    #[inline]
+    pub fn all(vector: Vector2) -> bool {
+        (vector.x() > 0.0) & (vector.y() > 0.0)
+    }
+
+    #[inline]
+    pub fn any(vector: Vector2) -> bool {
+        (vector.x() > 0.0) | (vector.y() > 0.0)
+    }
+
+    #[inline]
+    pub fn gt(vector: Vector2, vector1: Vector2) -> Bool2 {
+        compare(vector, vector1, |lhs, rhs| lhs > rhs)
+    }
+
+    #[inline]
+    pub fn ge(vector: Vector2, vector1: Vector2) -> Bool2 {
+        compare(vector, vector1, |lhs, rhs| lhs >= rhs)
+    }
+
+    #[inline]
+    pub fn le(vector: Vector2, vector1: Vector2) -> Bool2 {
+        compare(vector, vector1, |lhs, rhs| lhs <= rhs)
+    }
+
+    #[inline]
+    pub fn lt(vector: Vector2, vector1: Vector2) -> Bool2 {
+        compare(vector, vector1, |lhs, rhs| lhs < rhs)
+    }
+
+    #[inline]
+    pub fn component_max(vector: Vector2) -> f32 {
+        vector.x().max(vector.y())
+    }
+
+    #[inline]
+    pub fn component_min(vector: Vector2) -> f32 {
+        vector.x().min(vector.y())
+    }
+
+    #[inline]
+    pub fn component_sum(vector: Vector2) -> f32 {
+        vector.x() + vector.y()
+    }
+
+    #[inline]
+    pub fn component_product(vector: Vector2) -> f32 {
+        vector.x() * vector.y()
+    }
+
+    #[inline]
+    pub fn mean(vector: Vector2) -> f32 {
+        component_sum(vector) * 0.5
+    }
+
+    #[inline]
+    pub fn signum(vector: Vector2) -> Vector2 {
+        map(vector, f32::signum)
+    }
+
+    #[inline]
+    pub fn copysign(vector: Vector2, sign_vector: Vector2) -> Vector2 {
+        zip(vector, sign_vector, f32::copysign)
+    }
+
+    #[inline]
+    pub fn is_positive(vector: Vector2) -> Bool2 {
+        Bool2::set(vector.x().is_sign_positive(), vector.y().is_sign_positive())
+    }
+
+    #[inline]
+    pub fn is_negative(vector: Vector2) -> Bool2 {
+        Bool2::set(vector.x().is_sign_negative(), vector.y().is_sign_negative())
+    }
+
+    #[inline]
+    pub fn is_finite(vector: Vector2) -> Bool2 {
+        Bool2::set(vector.x().is_finite(), vector.y().is_finite())
+    }
+
+    #[inline]
+    pub fn is_infinite(vector: Vector2) -> Bool2 {
+        Bool2::set(vector.x().is_infinite(), vector.y().is_infinite())
+    }
+
+    #[inline]
+    pub fn is_nan(vector: Vector2) -> Bool2 {
+        Bool2::set(vector.x().is_nan(), vector.y().is_nan())
+    }
+
+    #[inline]
+    pub fn bias(vector: Vector2, bias_vector: Vector2) -> Vector2 {
+        vector / ((rcp(bias_vector) - 2.0) * (Vector2::ONE - vector) + 1.0)
+    }
+
+    #[inline]
+    pub fn gain(vector: Vector2, gain_vector: Vector2) -> Vector2 {
+        let lhs: Vector2 = bias(vector * 2.0, gain_vector) * 0.5;
+        let rhs: Vector2 = bias(vector * 2.0 - 1.0, Vector2::ONE - gain_vector) * 0.5 + 0.5;
+
+        select(lhs, rhs, lt(vector, Vector2::broadcast(0.5)))
+    }
+
+    #[inline]
+    pub fn orthonormalize(vector: Vector2, normal: Vector2) -> Vector2 {
+        normalize(vector - normal * dot(vector, normal))
+    }
+
+    #[inline]
+    pub fn sin_cos(vector: Vector2) -> (Vector2, Vector2) {
+        (sin(vector), cos(vector))
+    }
+
+    #[inline]
+    pub fn sin(vector: Vector2) -> Vector2 {
+        map(vector, f32::sin)
+    }
+
+    #[inline]
+    pub fn cos(vector: Vector2) -> Vector2 {
+        map(vector, f32::cos)
+    }
+
+    #[inline]
+    pub fn exp(vector: Vector2) -> Vector2 {
+        map(vector, f32::exp)
+    }
+
+    #[inline]
+    pub fn exp2(vector: Vector2) -> Vector2 {
+        map(vector, f32::exp2)
+    }
+
+    #[inline]
+    pub fn log(vector: Vector2) -> Vector2 {
+        map(vector, f32::ln)
+    }
+
+    #[inline]
+    pub fn log2(vector: Vector2) -> Vector2 {
+        map(vector, f32::log2)
+    }
+
+    #[inline]
+    pub fn log10(vector: Vector2) -> Vector2 {
+        map(vector, f32::log10)
+    }
+
+    #[inline]
+    pub fn cosh(vector: Vector2) -> Vector2 {
+        map(vector, f32::cosh)
+    }
+
+    #[inline]
+    pub fn sinh(vector: Vector2) -> Vector2 {
+        map(vector, f32::sinh)
+    }
+
+    #[inline]
+    pub fn tanh(vector: Vector2) -> Vector2 {
+        map(vector, f32::tanh)
+    }
+
+    #[inline]
+    pub fn ldexp(mul_vector: Vector2, power_vector: Vector2) -> Vector2 {
+        mul_vector * exp2(power_vector)
+    }
+
+    #[inline]
+    pub fn face_forward(normal: Vector2, incident: Vector2, geo_normal: Vector2) -> Vector2 {
+        if dot(incident, geo_normal).is_sign_positive() {
+            -normal
+        } else {
+            normal
+        }
+    }
+
+    #[inline]
+    pub fn project(vector: Vector2, target: Vector2) -> Vector2 {
+        target * (dot(vector, target) / length_squared(target))
+    }
+
+    #[inline]
+    pub fn reject(vector: Vector2, target: Vector2) -> Vector2 {
+        vector - project(vector, target)
+    }
+
+    #[inline]
+    pub fn reflect(incident: Vector2, normal: Vector2) -> Vector2 {
+        incident - normal * (2.0 * dot(incident, normal))
+    }
+
+    #[inline]
+    pub fn refract(incident: Vector2, normal: Vector2, eta: f32) -> Vector2 {
+        let ni: f32 = dot(normal, incident);
+
+        let k: f32 = 1.0 - eta * eta * (1.0 - ni * ni);
+
+        if k < 0.0 {
+            return Vector2::ZERO;
+        }
+
+        incident * eta - normal * (eta * ni + k.sqrt())
+    }
+
+    #[inline]
+    pub fn rotate(vector: Vector2, angle_radian: f32) -> Vector2 {
+        let (sin_angle, cos_angle) = angle_radian.sin_cos();
+
+        Vector2::set(
+            vector.x() * cos_angle - vector.y() * sin_angle,
+            vector.x() * sin_angle + vector.y() * cos_angle,
+        )
+    }
+}
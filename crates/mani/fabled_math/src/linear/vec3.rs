@@ -11,6 +11,7 @@ use std::ops::{
 };
 
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct Vector3 {
     pub value: std::simd::f32x4,
 }
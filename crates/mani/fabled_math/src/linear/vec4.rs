@@ -12,6 +12,7 @@ use std::ops::{
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct Vector4 {
     pub value: std::simd::f32x4,
 }
@@ -0,0 +1,351 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fractal {
+    pub octaves: u32,
+    pub frequency: f32,
+    pub lacunarity: f32,
+    pub gain: f32,
+}
+
+impl Default for Fractal {
+    fn default() -> Self {
+        Fractal {
+            octaves: 5,
+            frequency: 1.0,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+impl Fractal {
+    #[inline(always)]
+    pub const fn set(octaves: u32, frequency: f32, lacunarity: f32, gain: f32) -> Fractal {
+        Fractal {
+            octaves,
+            frequency,
+            lacunarity,
+            gain,
+        }
+    }
+}
+
+impl Display for Fractal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Fractal (octaves : {}, frequency : {}, lacunarity : {}, gain : {})",
+            self.octaves, self.frequency, self.lacunarity, self.gain
+        )
+    }
+}
+
+// The noise argument is any seeded 2d or 3d four lane function such as
+// perlin3_x4 or simplex3_x4. Each octave uses the next seed so octaves are
+// uncorrelated. fbm returns [-1, 1], ridged and turbulence return [0, 1].
+pub mod fractal_math {
+    use crate::{Fractal, Vector3};
+
+    use std::simd::SimdFloat;
+
+    // Offsets decorrelating the per axis warp fields. Any non integer values
+    // work, 5.2 follows Inigo Quilez's "Domain Warping" article.
+    const WARP_OFFSET: [f32; 3] = [0.0, 5.2, 9.7];
+
+    #[inline(always)]
+    fn accumulate<F: Fn(u32, std::simd::f32x4) -> std::simd::f32x4>(
+        seed: u32,
+        fractal: Fractal,
+        octave_noise: F,
+    ) -> std::simd::f32x4 {
+        let mut sum: std::simd::f32x4 = std::simd::f32x4::splat(0.0);
+
+        let mut amplitude: f32 = 1.0;
+        let mut amplitude_sum: f32 = 0.0;
+        let mut frequency: f32 = fractal.frequency;
+
+        for octave in 0..fractal.octaves.max(1) {
+            sum += octave_noise(
+                seed.wrapping_add(octave),
+                std::simd::f32x4::splat(frequency),
+            ) * std::simd::f32x4::splat(amplitude);
+
+            amplitude_sum += amplitude;
+            amplitude *= fractal.gain;
+            frequency *= fractal.lacunarity;
+        }
+
+        sum / std::simd::f32x4::splat(amplitude_sum)
+    }
+
+    #[inline(always)]
+    fn ridge(value: std::simd::f32x4) -> std::simd::f32x4 {
+        let signal: std::simd::f32x4 = std::simd::f32x4::splat(1.0) - value.abs();
+
+        signal * signal
+    }
+
+    #[inline]
+    pub fn fbm2_x4<F: Fn(u32, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4>(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        accumulate(seed, fractal, |octave_seed, frequency| {
+            noise(octave_seed, x * frequency, y * frequency)
+        })
+    }
+
+    #[inline]
+    pub fn fbm3_x4<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        accumulate(seed, fractal, |octave_seed, frequency| {
+            noise(octave_seed, x * frequency, y * frequency, z * frequency)
+        })
+    }
+
+    #[inline]
+    pub fn ridged2_x4<F: Fn(u32, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4>(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        accumulate(seed, fractal, |octave_seed, frequency| {
+            ridge(noise(octave_seed, x * frequency, y * frequency))
+        })
+    }
+
+    #[inline]
+    pub fn ridged3_x4<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        accumulate(seed, fractal, |octave_seed, frequency| {
+            ridge(noise(
+                octave_seed,
+                x * frequency,
+                y * frequency,
+                z * frequency,
+            ))
+        })
+    }
+
+    #[inline]
+    pub fn turbulence2_x4<F: Fn(u32, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4>(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        accumulate(seed, fractal, |octave_seed, frequency| {
+            noise(octave_seed, x * frequency, y * frequency).abs()
+        })
+    }
+
+    #[inline]
+    pub fn turbulence3_x4<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        accumulate(seed, fractal, |octave_seed, frequency| {
+            noise(octave_seed, x * frequency, y * frequency, z * frequency).abs()
+        })
+    }
+
+    // Offsets the sample position by fbm of the noise, feed the result back
+    // into any noise function to warp its domain.
+    #[inline]
+    pub fn domain_warp2_x4<F: Fn(u32, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4>(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        amplitude: f32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+    ) -> (std::simd::f32x4, std::simd::f32x4) {
+        let amplitude: std::simd::f32x4 = std::simd::f32x4::splat(amplitude);
+        let offset: std::simd::f32x4 = std::simd::f32x4::splat(WARP_OFFSET[1]);
+
+        let warp_x: std::simd::f32x4 = fbm2_x4(&noise, seed, fractal, x, y);
+        let warp_y: std::simd::f32x4 = fbm2_x4(&noise, seed, fractal, x + offset, y + offset);
+
+        (x + warp_x * amplitude, y + warp_y * amplitude)
+    }
+
+    #[inline]
+    pub fn domain_warp3_x4<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        amplitude: f32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> (std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) {
+        let amplitude: std::simd::f32x4 = std::simd::f32x4::splat(amplitude);
+
+        let mut warp: [std::simd::f32x4; 3] = [std::simd::f32x4::splat(0.0); 3];
+
+        for (axis, warp_axis) in warp.iter_mut().enumerate() {
+            let offset: std::simd::f32x4 = std::simd::f32x4::splat(WARP_OFFSET[axis]);
+
+            *warp_axis = fbm3_x4(&noise, seed, fractal, x + offset, y + offset, z + offset);
+        }
+
+        (
+            x + warp[0] * amplitude,
+            y + warp[1] * amplitude,
+            z + warp[2] * amplitude,
+        )
+    }
+
+    #[inline]
+    pub fn fbm3<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        point: Vector3,
+    ) -> f32 {
+        fbm3_x4(
+            noise,
+            seed,
+            fractal,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+        )[0]
+    }
+
+    #[inline]
+    pub fn ridged3<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        point: Vector3,
+    ) -> f32 {
+        ridged3_x4(
+            noise,
+            seed,
+            fractal,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+        )[0]
+    }
+
+    #[inline]
+    pub fn turbulence3<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        point: Vector3,
+    ) -> f32 {
+        turbulence3_x4(
+            noise,
+            seed,
+            fractal,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+        )[0]
+    }
+
+    #[inline]
+    pub fn domain_warp3<
+        F: Fn(u32, std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) -> std::simd::f32x4,
+    >(
+        noise: F,
+        seed: u32,
+        fractal: Fractal,
+        amplitude: f32,
+        point: Vector3,
+    ) -> Vector3 {
+        let (x, y, z): (std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) = domain_warp3_x4(
+            noise,
+            seed,
+            fractal,
+            amplitude,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+        );
+
+        Vector3::set(x[0], y[0], z[0])
+    }
+}
+
+
+#[cfg(test)]
+mod fractal_test {
+    use crate::fractal_math::*;
+    use crate::perlin_math::perlin3_x4;
+    use crate::simplex_math::simplex3_x4;
+    use crate::{Fractal, Vector3};
+
+    #[test]
+    fn combinator_ranges() {
+        let fractal: Fractal = Fractal::default();
+
+        for index in 0..1_000 {
+            let t: f32 = index as f32 * 0.37;
+            let point: Vector3 = Vector3::set(t, t * 0.3 - 4.0, (t * 0.7).sin() * 9.0);
+
+            let fbm: f32 = fbm3(perlin3_x4, 2, fractal, point);
+            let ridged: f32 = ridged3(simplex3_x4, 2, fractal, point);
+            let turbulence: f32 = turbulence3(perlin3_x4, 2, fractal, point);
+
+            assert!(fbm.abs() <= 1.0);
+            assert!((0.0..=1.0).contains(&ridged));
+            assert!((0.0..=1.0).contains(&turbulence));
+        }
+    }
+
+    #[test]
+    fn domain_warp_moves_point() {
+        let point: Vector3 = Vector3::set(0.3, 0.6, 0.9);
+        let warped: Vector3 = domain_warp3(perlin3_x4, 4, Fractal::default(), 2.0, point);
+
+        let offset: Vector3 = warped - point;
+
+        assert!(offset.x().abs() <= 2.0 && offset.y().abs() <= 2.0 && offset.z().abs() <= 2.0);
+        assert!(offset.x() != 0.0 || offset.y() != 0.0 || offset.z() != 0.0);
+
+        let again: Vector3 = domain_warp3(perlin3_x4, 4, Fractal::default(), 2.0, point);
+        assert_eq!(again.to_primitive(), warped.to_primitive());
+    }
+}
@@ -0,0 +1,91 @@
+use std::simd::{SimdPartialEq, StdFloat};
+
+// Odd multipliers spreading each lattice axis over the word, the multiplier
+// of Thomas Wang's hash32shiftmult followed by xxHash32's PRIME32_5, PRIME32_1
+// and PRIME32_2.
+const PRIME_X: u32 = 0x27d4_eb2d;
+const PRIME_Y: u32 = 0x1656_67b1;
+const PRIME_Z: u32 = 0x9e37_79b1;
+const PRIME_W: u32 = 0x85eb_ca77;
+
+// Xorshift multiply finalizer with the structure of MurmurHash3's fmix32.
+// Changing the constants changes every noise output.
+#[inline(always)]
+fn avalanche(hash: std::simd::u32x4) -> std::simd::u32x4 {
+    let mut hash: std::simd::u32x4 = hash;
+
+    hash ^= hash >> std::simd::u32x4::splat(15);
+    hash *= std::simd::u32x4::splat(0x2c1b_3c6d);
+    hash ^= hash >> std::simd::u32x4::splat(12);
+    hash *= std::simd::u32x4::splat(0x297a_2d39);
+    hash ^= hash >> std::simd::u32x4::splat(15);
+
+    hash
+}
+
+#[inline(always)]
+fn lattice(coordinate: std::simd::i32x4, prime: u32) -> std::simd::u32x4 {
+    coordinate.cast::<u32>() * std::simd::u32x4::splat(prime)
+}
+
+#[inline(always)]
+pub(crate) fn hash1(seed: u32, x: std::simd::i32x4) -> std::simd::u32x4 {
+    avalanche(std::simd::u32x4::splat(seed) ^ lattice(x, PRIME_X))
+}
+
+#[inline(always)]
+pub(crate) fn hash2(seed: u32, x: std::simd::i32x4, y: std::simd::i32x4) -> std::simd::u32x4 {
+    avalanche(std::simd::u32x4::splat(seed) ^ lattice(x, PRIME_X) ^ lattice(y, PRIME_Y))
+}
+
+#[inline(always)]
+pub(crate) fn hash3(
+    seed: u32,
+    x: std::simd::i32x4,
+    y: std::simd::i32x4,
+    z: std::simd::i32x4,
+) -> std::simd::u32x4 {
+    avalanche(
+        std::simd::u32x4::splat(seed)
+            ^ lattice(x, PRIME_X)
+            ^ lattice(y, PRIME_Y)
+            ^ lattice(z, PRIME_Z),
+    )
+}
+
+#[inline(always)]
+pub(crate) fn hash4(
+    seed: u32,
+    x: std::simd::i32x4,
+    y: std::simd::i32x4,
+    z: std::simd::i32x4,
+    w: std::simd::i32x4,
+) -> std::simd::u32x4 {
+    avalanche(
+        std::simd::u32x4::splat(seed)
+            ^ lattice(x, PRIME_X)
+            ^ lattice(y, PRIME_Y)
+            ^ lattice(z, PRIME_Z)
+            ^ lattice(w, PRIME_W),
+    )
+}
+
+// Maps the upper 24 bits of the hash to [0, 1).
+#[inline(always)]
+pub(crate) fn unit_float(hash: std::simd::u32x4) -> std::simd::f32x4 {
+    const INVERSE_24_BIT: f32 = 1.0 / 16_777_216.0;
+
+    (hash >> std::simd::u32x4::splat(8)).cast::<f32>() * std::simd::f32x4::splat(INVERSE_24_BIT)
+}
+
+#[inline(always)]
+pub(crate) fn bit_set(hash: std::simd::u32x4, bit: u32) -> std::simd::mask32x4 {
+    (hash & std::simd::u32x4::splat(bit)).simd_ne(std::simd::u32x4::splat(0))
+}
+
+#[inline(always)]
+pub(crate) fn floor_to_int(value: std::simd::f32x4) -> (std::simd::f32x4, std::simd::i32x4) {
+    let floored: std::simd::f32x4 = value.floor();
+
+    (floored, floored.cast::<i32>())
+}
@@ -0,0 +1,14 @@
+// Seeded procedural noise. Every function evaluates four samples at once in
+// f32x4 lanes and only uses integer hashing and plain float arithmetic, so
+// the same seed and input always produce bit-identical output. The scalar
+// variants run the same lane code.
+mod fractal;
+mod hash;
+mod perlin;
+mod simplex;
+mod worley;
+
+pub use fractal::*;
+pub use perlin::*;
+pub use simplex::*;
+pub use worley::*;
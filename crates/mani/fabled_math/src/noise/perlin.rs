@@ -0,0 +1,351 @@
+pub mod perlin_math {
+    use crate::{Vector2, Vector3, Vector4};
+
+    use crate::noise::hash::{bit_set, floor_to_int, hash1, hash2, hash3, hash4, unit_float};
+
+    use std::simd::{SimdPartialEq, SimdPartialOrd};
+
+    // Scales the raw gradient noise to roughly [-1, 1]. Measured by sampling
+    // rather than derived, the observed peaks stay between 0.91 and 0.94.
+    const SCALE_1D: f32 = 2.0;
+    const SCALE_2D: f32 = 0.62;
+    const SCALE_3D: f32 = 0.936;
+    const SCALE_4D: f32 = 0.82;
+
+    // Quintic 6t^5 - 15t^4 + 10t^3 from Perlin's "Improving Noise".
+    #[inline(always)]
+    fn fade(t: std::simd::f32x4) -> std::simd::f32x4 {
+        let six: std::simd::f32x4 = std::simd::f32x4::splat(6.0);
+        let fifteen: std::simd::f32x4 = std::simd::f32x4::splat(15.0);
+        let ten: std::simd::f32x4 = std::simd::f32x4::splat(10.0);
+
+        t * t * t * (t * (t * six - fifteen) + ten)
+    }
+
+    #[inline(always)]
+    fn lerp(a: std::simd::f32x4, b: std::simd::f32x4, t: std::simd::f32x4) -> std::simd::f32x4 {
+        a + (b - a) * t
+    }
+
+    #[inline(always)]
+    fn negate_if(value: std::simd::f32x4, mask: std::simd::mask32x4) -> std::simd::f32x4 {
+        mask.select(-value, value)
+    }
+
+    #[inline(always)]
+    fn gradient1(hash: std::simd::u32x4, x: std::simd::f32x4) -> std::simd::f32x4 {
+        let slope: std::simd::f32x4 =
+            unit_float(hash) * std::simd::f32x4::splat(2.0) - std::simd::f32x4::splat(1.0);
+
+        slope * x
+    }
+
+    // Eight directions of the form (±1, ±2) and (±2, ±1).
+    #[inline(always)]
+    fn gradient2(
+        hash: std::simd::u32x4,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let swap: std::simd::mask32x4 = bit_set(hash, 4);
+
+        let u: std::simd::f32x4 = swap.select(y, x);
+        let v: std::simd::f32x4 = swap.select(x, y);
+
+        negate_if(u, bit_set(hash, 1))
+            + negate_if(v * std::simd::f32x4::splat(2.0), bit_set(hash, 2))
+    }
+
+    // The twelve cube edge directions from improved perlin noise.
+    #[inline(always)]
+    fn gradient3(
+        hash: std::simd::u32x4,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let h: std::simd::u32x4 = hash & std::simd::u32x4::splat(15);
+
+        let u: std::simd::f32x4 = h.simd_lt(std::simd::u32x4::splat(8)).select(x, y);
+
+        let x_instead: std::simd::mask32x4 =
+            h.simd_eq(std::simd::u32x4::splat(12)) | h.simd_eq(std::simd::u32x4::splat(14));
+        let v: std::simd::f32x4 = h
+            .simd_lt(std::simd::u32x4::splat(4))
+            .select(y, x_instead.select(x, z));
+
+        negate_if(u, bit_set(h, 1)) + negate_if(v, bit_set(h, 2))
+    }
+
+    // Thirty two directions with one zero component.
+    #[inline(always)]
+    fn gradient4(
+        hash: std::simd::u32x4,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+        w: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let h: std::simd::u32x4 = hash & std::simd::u32x4::splat(31);
+
+        let u: std::simd::f32x4 = h.simd_lt(std::simd::u32x4::splat(24)).select(x, y);
+        let v: std::simd::f32x4 = h.simd_lt(std::simd::u32x4::splat(16)).select(y, z);
+        let t: std::simd::f32x4 = h.simd_lt(std::simd::u32x4::splat(8)).select(z, w);
+
+        negate_if(u, bit_set(h, 1)) + negate_if(v, bit_set(h, 2)) + negate_if(t, bit_set(h, 4))
+    }
+
+    #[inline]
+    pub fn perlin1_x4(seed: u32, x: std::simd::f32x4) -> std::simd::f32x4 {
+        let one_i: std::simd::i32x4 = std::simd::i32x4::splat(1);
+        let one: std::simd::f32x4 = std::simd::f32x4::splat(1.0);
+
+        let (floor_x, x0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x);
+        let fx: std::simd::f32x4 = x - floor_x;
+
+        let n0: std::simd::f32x4 = gradient1(hash1(seed, x0), fx);
+        let n1: std::simd::f32x4 = gradient1(hash1(seed, x0 + one_i), fx - one);
+
+        lerp(n0, n1, fade(fx)) * std::simd::f32x4::splat(SCALE_1D)
+    }
+
+    #[inline]
+    pub fn perlin2_x4(seed: u32, x: std::simd::f32x4, y: std::simd::f32x4) -> std::simd::f32x4 {
+        let one_i: std::simd::i32x4 = std::simd::i32x4::splat(1);
+        let one: std::simd::f32x4 = std::simd::f32x4::splat(1.0);
+
+        let (floor_x, x0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x);
+        let (floor_y, y0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(y);
+
+        let fx: std::simd::f32x4 = x - floor_x;
+        let fy: std::simd::f32x4 = y - floor_y;
+
+        let x1: std::simd::i32x4 = x0 + one_i;
+        let y1: std::simd::i32x4 = y0 + one_i;
+
+        let n00: std::simd::f32x4 = gradient2(hash2(seed, x0, y0), fx, fy);
+        let n10: std::simd::f32x4 = gradient2(hash2(seed, x1, y0), fx - one, fy);
+        let n01: std::simd::f32x4 = gradient2(hash2(seed, x0, y1), fx, fy - one);
+        let n11: std::simd::f32x4 = gradient2(hash2(seed, x1, y1), fx - one, fy - one);
+
+        let u: std::simd::f32x4 = fade(fx);
+        let v: std::simd::f32x4 = fade(fy);
+
+        lerp(lerp(n00, n10, u), lerp(n01, n11, u), v) * std::simd::f32x4::splat(SCALE_2D)
+    }
+
+    #[inline]
+    pub fn perlin3_x4(
+        seed: u32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let one_i: std::simd::i32x4 = std::simd::i32x4::splat(1);
+        let one: std::simd::f32x4 = std::simd::f32x4::splat(1.0);
+
+        let (floor_x, x0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x);
+        let (floor_y, y0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(y);
+        let (floor_z, z0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(z);
+
+        let fx: std::simd::f32x4 = x - floor_x;
+        let fy: std::simd::f32x4 = y - floor_y;
+        let fz: std::simd::f32x4 = z - floor_z;
+
+        let (x1, y1, z1): (std::simd::i32x4, std::simd::i32x4, std::simd::i32x4) =
+            (x0 + one_i, y0 + one_i, z0 + one_i);
+        let (gx, gy, gz): (std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) =
+            (fx - one, fy - one, fz - one);
+
+        let n000: std::simd::f32x4 = gradient3(hash3(seed, x0, y0, z0), fx, fy, fz);
+        let n100: std::simd::f32x4 = gradient3(hash3(seed, x1, y0, z0), gx, fy, fz);
+        let n010: std::simd::f32x4 = gradient3(hash3(seed, x0, y1, z0), fx, gy, fz);
+        let n110: std::simd::f32x4 = gradient3(hash3(seed, x1, y1, z0), gx, gy, fz);
+        let n001: std::simd::f32x4 = gradient3(hash3(seed, x0, y0, z1), fx, fy, gz);
+        let n101: std::simd::f32x4 = gradient3(hash3(seed, x1, y0, z1), gx, fy, gz);
+        let n011: std::simd::f32x4 = gradient3(hash3(seed, x0, y1, z1), fx, gy, gz);
+        let n111: std::simd::f32x4 = gradient3(hash3(seed, x1, y1, z1), gx, gy, gz);
+
+        let u: std::simd::f32x4 = fade(fx);
+        let v: std::simd::f32x4 = fade(fy);
+        let w: std::simd::f32x4 = fade(fz);
+
+        let near: std::simd::f32x4 = lerp(lerp(n000, n100, u), lerp(n010, n110, u), v);
+        let far: std::simd::f32x4 = lerp(lerp(n001, n101, u), lerp(n011, n111, u), v);
+
+        lerp(near, far, w) * std::simd::f32x4::splat(SCALE_3D)
+    }
+
+    #[inline]
+    pub fn perlin4_x4(
+        seed: u32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+        w: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let one_i: std::simd::i32x4 = std::simd::i32x4::splat(1);
+        let one: std::simd::f32x4 = std::simd::f32x4::splat(1.0);
+
+        let (floor_x, x0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x);
+        let (floor_y, y0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(y);
+        let (floor_z, z0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(z);
+        let (floor_w, w0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(w);
+
+        let fraction: [std::simd::f32x4; 4] = [x - floor_x, y - floor_y, z - floor_z, w - floor_w];
+        let cell: [std::simd::i32x4; 4] = [x0, y0, z0, w0];
+
+        // Corner index bit n selects the upper cell boundary along axis n.
+        let mut corners: [std::simd::f32x4; 16] = [std::simd::f32x4::splat(0.0); 16];
+
+        for (corner, value) in corners.iter_mut().enumerate() {
+            let mut lattice: [std::simd::i32x4; 4] = cell;
+            let mut offset: [std::simd::f32x4; 4] = fraction;
+
+            for axis in 0..4 {
+                if corner & (1 << axis) != 0 {
+                    lattice[axis] += one_i;
+                    offset[axis] -= one;
+                }
+            }
+
+            *value = gradient4(
+                hash4(seed, lattice[0], lattice[1], lattice[2], lattice[3]),
+                offset[0],
+                offset[1],
+                offset[2],
+                offset[3],
+            );
+        }
+
+        let mut width: usize = 16;
+
+        for fraction_axis in fraction {
+            let t: std::simd::f32x4 = fade(fraction_axis);
+
+            width /= 2;
+
+            for index in 0..width {
+                corners[index] = lerp(corners[index * 2], corners[index * 2 + 1], t);
+            }
+        }
+
+        corners[0] * std::simd::f32x4::splat(SCALE_4D)
+    }
+
+    #[inline]
+    pub fn perlin1(seed: u32, x: f32) -> f32 {
+        perlin1_x4(seed, std::simd::f32x4::splat(x))[0]
+    }
+
+    #[inline]
+    pub fn perlin2(seed: u32, point: Vector2) -> f32 {
+        perlin2_x4(
+            seed,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+        )[0]
+    }
+
+    #[inline]
+    pub fn perlin3(seed: u32, point: Vector3) -> f32 {
+        perlin3_x4(
+            seed,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+        )[0]
+    }
+
+    #[inline]
+    pub fn perlin4(seed: u32, point: Vector4) -> f32 {
+        perlin4_x4(
+            seed,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+            std::simd::f32x4::splat(point.w()),
+        )[0]
+    }
+}
+
+
+#[cfg(test)]
+mod noise_test {
+    use crate::perlin_math::*;
+    use crate::simplex_math::*;
+    use crate::{Vector2, Vector3, Vector4};
+
+    fn sample_points(count: usize) -> Vec<Vector4> {
+        let mut state: u32 = 0x1234_5678;
+
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+
+            (state as f32 / u32::MAX as f32) * 64.0 - 32.0
+        };
+
+        (0..count)
+            .map(|_| Vector4::set(next(), next(), next(), next()))
+            .collect()
+    }
+
+    #[test]
+    fn bounded_and_zero_on_lattice() {
+        for point in sample_points(20_000) {
+            let plane: Vector2 = Vector2::set(point.x(), point.y());
+            let volume: Vector3 = Vector3::set(point.x(), point.y(), point.z());
+
+            for value in [
+                perlin1(7, point.x()),
+                perlin2(7, plane),
+                perlin3(7, volume),
+                perlin4(7, point),
+                simplex2(7, plane),
+                simplex3(7, volume),
+            ] {
+                assert!(value.abs() <= 1.0);
+            }
+        }
+
+        assert_eq!(perlin3(3, Vector3::set(4.0, -2.0, 9.0)), 0.0);
+        assert_eq!(perlin4(3, Vector4::set(1.0, 2.0, 3.0, -4.0)), 0.0);
+    }
+
+    #[test]
+    fn lanes_match_scalar() {
+        let x: std::simd::f32x4 = std::simd::f32x4::from_array([0.3, -7.25, 12.5, 100.125]);
+        let y: std::simd::f32x4 = std::simd::f32x4::from_array([1.7, 3.5, -0.75, 42.0]);
+        let z: std::simd::f32x4 = std::simd::f32x4::from_array([-2.2, 0.0, 8.8, -13.3]);
+
+        let perlin: [f32; 4] = perlin3_x4(11, x, y, z).to_array();
+        let simplex: [f32; 4] = simplex3_x4(11, x, y, z).to_array();
+
+        for lane in 0..4 {
+            let point: Vector3 = Vector3::set(x[lane], y[lane], z[lane]);
+
+            assert_eq!(perlin[lane].to_bits(), perlin3(11, point).to_bits());
+            assert_eq!(simplex[lane].to_bits(), simplex3(11, point).to_bits());
+        }
+    }
+
+    #[test]
+    fn seeded_and_deterministic() {
+        let point: Vector3 = Vector3::set(1.25, -3.5, 0.75);
+
+        assert_ne!(perlin3(1, point), perlin3(2, point));
+        assert_ne!(simplex3(1, point), simplex3(2, point));
+
+        // Pinned output, any change here breaks saved procedural content.
+        assert_eq!(perlin3(1, point).to_bits(), PERLIN3_GOLDEN);
+        assert_eq!(
+            simplex2(1, Vector2::set(1.25, -3.5)).to_bits(),
+            SIMPLEX2_GOLDEN
+        );
+    }
+
+    const PERLIN3_GOLDEN: u32 = 0xbf0f_41c4;
+    const SIMPLEX2_GOLDEN: u32 = 0x3e29_2613;
+}
@@ -0,0 +1,244 @@
+// Simplex style gradient noise on the lattices of KdotJPG's OpenSimplex2
+// (github.com/KdotJPG/OpenSimplex2). The 2d variant uses the triangular
+// simplex lattice with the r^2 = 0.5 kernel of OpenSimplex2. The 3d variant
+// sums the body centered cubic lattice, two cubic grids offset by half a
+// cell, with the wider r^2 = 0.75 kernel of the smooth OpenSimplex2S, in the
+// rotated domain OpenSimplex2 falls back to. Both use their own hash and
+// gradient sets, so the output is not bit compatible with the reference
+// implementations.
+pub mod simplex_math {
+    use crate::{Vector2, Vector3};
+
+    use crate::noise::hash::{bit_set, floor_to_int, hash2, hash3};
+
+    use std::simd::{SimdFloat, SimdPartialEq, SimdPartialOrd};
+
+    // (sqrt(3) - 1) / 2 and (3 - sqrt(3)) / 6, the 2d skew factors from
+    // Gustavson's "Simplex noise demystified".
+    const SKEW_2D: f32 = 0.366_025_4;
+    const UNSKEW_2D: f32 = 0.211_324_87;
+
+    // Squared kernel radius. In 2d it is the squared height of a triangle, so a
+    // corner reaches the opposite edge. In 3d it is the squared half diagonal
+    // of a cube, the reach of OpenSimplex2S.
+    const RADIUS_SQUARED_2D: f32 = 0.5;
+    const RADIUS_SQUARED_3D: f32 = 0.75;
+
+    // Scales the summed contributions to roughly [-1, 1]. Measured by sampling
+    // rather than derived, the observed peaks stay near 0.95.
+    const SCALE_2D: f32 = 43.0;
+    const SCALE_3D: f32 = 8.6;
+
+    // Seed offset for the second cubic grid of the 3d lattice. This is the
+    // MurmurHash2 multiplier, but any large odd constant works.
+    const LATTICE_SEED_OFFSET: u32 = 0x5bd1_e995;
+
+    #[inline(always)]
+    fn negate_if(value: std::simd::f32x4, mask: std::simd::mask32x4) -> std::simd::f32x4 {
+        mask.select(-value, value)
+    }
+
+    #[inline(always)]
+    fn attenuate(
+        radius_squared: f32,
+        distance_squared: std::simd::f32x4,
+        gradient: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let falloff: std::simd::f32x4 = (std::simd::f32x4::splat(radius_squared)
+            - distance_squared)
+            .simd_max(std::simd::f32x4::splat(0.0));
+
+        let falloff_squared: std::simd::f32x4 = falloff * falloff;
+
+        falloff_squared * falloff_squared * gradient
+    }
+
+    // Eight directions of the form (±1, ±2) and (±2, ±1), picked by three hash
+    // bits instead of the 24 direction table of OpenSimplex2.
+    #[inline(always)]
+    fn gradient2(
+        hash: std::simd::u32x4,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let swap: std::simd::mask32x4 = bit_set(hash, 4);
+
+        let u: std::simd::f32x4 = swap.select(y, x);
+        let v: std::simd::f32x4 = swap.select(x, y);
+
+        negate_if(u, bit_set(hash, 1))
+            + negate_if(v * std::simd::f32x4::splat(2.0), bit_set(hash, 2))
+    }
+
+    // The twelve cube edge directions of Perlin's "Improving Noise" instead of
+    // the 48 direction table of OpenSimplex2S.
+    #[inline(always)]
+    fn gradient3(
+        hash: std::simd::u32x4,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let h: std::simd::u32x4 = hash & std::simd::u32x4::splat(15);
+
+        let u: std::simd::f32x4 = h.simd_lt(std::simd::u32x4::splat(8)).select(x, y);
+
+        let x_instead: std::simd::mask32x4 =
+            h.simd_eq(std::simd::u32x4::splat(12)) | h.simd_eq(std::simd::u32x4::splat(14));
+        let v: std::simd::f32x4 = h
+            .simd_lt(std::simd::u32x4::splat(4))
+            .select(y, x_instead.select(x, z));
+
+        negate_if(u, bit_set(h, 1)) + negate_if(v, bit_set(h, 2))
+    }
+
+    #[inline]
+    pub fn simplex2_x4(seed: u32, x: std::simd::f32x4, y: std::simd::f32x4) -> std::simd::f32x4 {
+        let one_i: std::simd::i32x4 = std::simd::i32x4::splat(1);
+        let zero_i: std::simd::i32x4 = std::simd::i32x4::splat(0);
+        let unskew: std::simd::f32x4 = std::simd::f32x4::splat(UNSKEW_2D);
+
+        let skew: std::simd::f32x4 = (x + y) * std::simd::f32x4::splat(SKEW_2D);
+
+        let (floor_i, i): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x + skew);
+        let (floor_j, j): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(y + skew);
+
+        let unskew_origin: std::simd::f32x4 = (floor_i + floor_j) * unskew;
+
+        let x0: std::simd::f32x4 = x - (floor_i - unskew_origin);
+        let y0: std::simd::f32x4 = y - (floor_j - unskew_origin);
+
+        // Upper or lower triangle of the skewed cell.
+        let lower: std::simd::mask32x4 = x0.simd_gt(y0);
+
+        let i1: std::simd::i32x4 = lower.select(one_i, zero_i);
+        let j1: std::simd::i32x4 = lower.select(zero_i, one_i);
+
+        let x1: std::simd::f32x4 = x0 - i1.cast::<f32>() + unskew;
+        let y1: std::simd::f32x4 = y0 - j1.cast::<f32>() + unskew;
+
+        let corner_offset: std::simd::f32x4 = std::simd::f32x4::splat(2.0 * UNSKEW_2D - 1.0);
+        let x2: std::simd::f32x4 = x0 + corner_offset;
+        let y2: std::simd::f32x4 = y0 + corner_offset;
+
+        let n0: std::simd::f32x4 = attenuate(
+            RADIUS_SQUARED_2D,
+            x0 * x0 + y0 * y0,
+            gradient2(hash2(seed, i, j), x0, y0),
+        );
+        let n1: std::simd::f32x4 = attenuate(
+            RADIUS_SQUARED_2D,
+            x1 * x1 + y1 * y1,
+            gradient2(hash2(seed, i + i1, j + j1), x1, y1),
+        );
+        let n2: std::simd::f32x4 = attenuate(
+            RADIUS_SQUARED_2D,
+            x2 * x2 + y2 * y2,
+            gradient2(hash2(seed, i + one_i, j + one_i), x2, y2),
+        );
+
+        (n0 + n1 + n2) * std::simd::f32x4::splat(SCALE_2D)
+    }
+
+    #[inline(always)]
+    fn cubic_lattice3(
+        seed: u32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        let one_i: std::simd::i32x4 = std::simd::i32x4::splat(1);
+        let one: std::simd::f32x4 = std::simd::f32x4::splat(1.0);
+
+        let (floor_x, x0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x);
+        let (floor_y, y0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(y);
+        let (floor_z, z0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(z);
+
+        let fx: std::simd::f32x4 = x - floor_x;
+        let fy: std::simd::f32x4 = y - floor_y;
+        let fz: std::simd::f32x4 = z - floor_z;
+
+        let mut sum: std::simd::f32x4 = std::simd::f32x4::splat(0.0);
+
+        // Every lattice point outside the cell is at least one unit away along
+        // some axis, beyond the sqrt(0.75) falloff radius, so the eight corners
+        // of both grids are the exact set of contributing points. OpenSimplex2S
+        // reaches the same set through a lookup table.
+        for corner in 0..8 {
+            let (mut lx, mut ly, mut lz): (std::simd::i32x4, std::simd::i32x4, std::simd::i32x4) =
+                (x0, y0, z0);
+            let (mut dx, mut dy, mut dz): (std::simd::f32x4, std::simd::f32x4, std::simd::f32x4) =
+                (fx, fy, fz);
+
+            if corner & 1 != 0 {
+                lx += one_i;
+                dx -= one;
+            }
+
+            if corner & 2 != 0 {
+                ly += one_i;
+                dy -= one;
+            }
+
+            if corner & 4 != 0 {
+                lz += one_i;
+                dz -= one;
+            }
+
+            sum += attenuate(
+                RADIUS_SQUARED_3D,
+                dx * dx + dy * dy + dz * dz,
+                gradient3(hash3(seed, lx, ly, lz), dx, dy, dz),
+            );
+        }
+
+        sum
+    }
+
+    #[inline]
+    pub fn simplex3_x4(
+        seed: u32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> std::simd::f32x4 {
+        // Orthonormal rotation, half a turn around the main diagonal. This is
+        // the fallback domain rotation of OpenSimplex2.
+        let rotation: std::simd::f32x4 = (x + y + z) * std::simd::f32x4::splat(2.0 / 3.0);
+
+        let rx: std::simd::f32x4 = rotation - x;
+        let ry: std::simd::f32x4 = rotation - y;
+        let rz: std::simd::f32x4 = rotation - z;
+
+        let half: std::simd::f32x4 = std::simd::f32x4::splat(0.5);
+
+        let primary: std::simd::f32x4 = cubic_lattice3(seed, rx, ry, rz);
+        let secondary: std::simd::f32x4 = cubic_lattice3(
+            seed.wrapping_add(LATTICE_SEED_OFFSET),
+            rx + half,
+            ry + half,
+            rz + half,
+        );
+
+        (primary + secondary) * std::simd::f32x4::splat(SCALE_3D)
+    }
+
+    #[inline]
+    pub fn simplex2(seed: u32, point: Vector2) -> f32 {
+        simplex2_x4(
+            seed,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+        )[0]
+    }
+
+    #[inline]
+    pub fn simplex3(seed: u32, point: Vector3) -> f32 {
+        simplex3_x4(
+            seed,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+        )[0]
+    }
+}
@@ -0,0 +1,243 @@
+// Cellular noise with one jittered feature point per unit cell. Returns the
+// euclidean distance to the closest (F1) and second closest (F2) point.
+//
+// The feature point of the containing cell and of its neighbour across the
+// nearest face bound F2 below sqrt(2.5) in 2d and sqrt(3) in 3d, so no point
+// more than two cells away can matter. The full 5x5 or 5x5x5 block is
+// searched ring by ring, and a cell is skipped once its closest possible
+// point is no nearer than the current F2 in every lane.
+pub mod worley_math {
+    use crate::{Vector2, Vector3};
+
+    use crate::noise::hash::{floor_to_int, hash2, hash3, unit_float};
+
+    use std::simd::{SimdFloat, SimdPartialOrd, StdFloat};
+
+    const SEARCH_RADIUS: i32 = 2;
+
+    // Independent jitter per axis is drawn from rehashing the cell hash. The
+    // constants are the bit noise primes of Squirrel Eiserloh's Squirrel3.
+    const AXIS_SEED: [u32; 3] = [0x68e3_1da4, 0xb529_7a4d, 0x1b56_c4e9];
+
+    #[inline(always)]
+    fn jitter(hash: std::simd::u32x4, axis: usize) -> std::simd::f32x4 {
+        let mixed: std::simd::u32x4 = (hash ^ std::simd::u32x4::splat(AXIS_SEED[axis]))
+            * std::simd::u32x4::splat(0x2c1b_3c6d);
+
+        unit_float(mixed ^ (mixed >> std::simd::u32x4::splat(16)))
+    }
+
+    // Distance along one axis from the sample to the nearest edge of the cell
+    // at the given offset, zero for the containing cell.
+    #[inline(always)]
+    fn axis_gap(offset: i32, fraction: std::simd::f32x4) -> std::simd::f32x4 {
+        match offset {
+            0 => std::simd::f32x4::splat(0.0),
+            _ if offset > 0 => std::simd::f32x4::splat(offset as f32) - fraction,
+            _ => fraction - std::simd::f32x4::splat((offset + 1) as f32),
+        }
+    }
+
+    // Cell offsets whose largest component is exactly ring, so walking the
+    // rings outwards visits the containing cell and its neighbours first.
+    #[inline(always)]
+    fn ring2(ring: i32) -> impl Iterator<Item = (i32, i32)> {
+        (-ring..=ring)
+            .flat_map(move |y| (-ring..=ring).map(move |x| (x, y)))
+            .filter(move |(x, y)| x.abs().max(y.abs()) == ring)
+    }
+
+    #[inline(always)]
+    fn ring3(ring: i32) -> impl Iterator<Item = (i32, i32, i32)> {
+        (-ring..=ring)
+            .flat_map(move |z| {
+                (-ring..=ring).flat_map(move |y| (-ring..=ring).map(move |x| (x, y, z)))
+            })
+            .filter(move |(x, y, z)| x.abs().max(y.abs()).max(z.abs()) == ring)
+    }
+
+    #[inline(always)]
+    fn insert_distance(
+        closest: &mut std::simd::f32x4,
+        second: &mut std::simd::f32x4,
+        distance_squared: std::simd::f32x4,
+    ) {
+        *second = second.simd_min(closest.simd_max(distance_squared));
+        *closest = closest.simd_min(distance_squared);
+    }
+
+    #[inline]
+    pub fn worley2_x4(
+        seed: u32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+    ) -> (std::simd::f32x4, std::simd::f32x4) {
+        let (floor_x, x0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x);
+        let (floor_y, y0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(y);
+
+        let fx: std::simd::f32x4 = x - floor_x;
+        let fy: std::simd::f32x4 = y - floor_y;
+
+        let mut closest: std::simd::f32x4 = std::simd::f32x4::splat(f32::MAX);
+        let mut second: std::simd::f32x4 = std::simd::f32x4::splat(f32::MAX);
+
+        for (offset_x, offset_y) in (0..=SEARCH_RADIUS).flat_map(ring2) {
+            let gap_x: std::simd::f32x4 = axis_gap(offset_x, fx);
+            let gap_y: std::simd::f32x4 = axis_gap(offset_y, fy);
+
+            if (gap_x * gap_x + gap_y * gap_y).simd_ge(second).all() {
+                continue;
+            }
+
+            let hash: std::simd::u32x4 = hash2(
+                seed,
+                x0 + std::simd::i32x4::splat(offset_x),
+                y0 + std::simd::i32x4::splat(offset_y),
+            );
+
+            let dx: std::simd::f32x4 =
+                std::simd::f32x4::splat(offset_x as f32) + jitter(hash, 0) - fx;
+            let dy: std::simd::f32x4 =
+                std::simd::f32x4::splat(offset_y as f32) + jitter(hash, 1) - fy;
+
+            insert_distance(&mut closest, &mut second, dx * dx + dy * dy);
+        }
+
+        (closest.sqrt(), second.sqrt())
+    }
+
+    #[inline]
+    pub fn worley3_x4(
+        seed: u32,
+        x: std::simd::f32x4,
+        y: std::simd::f32x4,
+        z: std::simd::f32x4,
+    ) -> (std::simd::f32x4, std::simd::f32x4) {
+        let (floor_x, x0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(x);
+        let (floor_y, y0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(y);
+        let (floor_z, z0): (std::simd::f32x4, std::simd::i32x4) = floor_to_int(z);
+
+        let fx: std::simd::f32x4 = x - floor_x;
+        let fy: std::simd::f32x4 = y - floor_y;
+        let fz: std::simd::f32x4 = z - floor_z;
+
+        let mut closest: std::simd::f32x4 = std::simd::f32x4::splat(f32::MAX);
+        let mut second: std::simd::f32x4 = std::simd::f32x4::splat(f32::MAX);
+
+        for (offset_x, offset_y, offset_z) in (0..=SEARCH_RADIUS).flat_map(ring3) {
+            let gap_x: std::simd::f32x4 = axis_gap(offset_x, fx);
+            let gap_y: std::simd::f32x4 = axis_gap(offset_y, fy);
+            let gap_z: std::simd::f32x4 = axis_gap(offset_z, fz);
+
+            if (gap_x * gap_x + gap_y * gap_y + gap_z * gap_z)
+                .simd_ge(second)
+                .all()
+            {
+                continue;
+            }
+
+            let hash: std::simd::u32x4 = hash3(
+                seed,
+                x0 + std::simd::i32x4::splat(offset_x),
+                y0 + std::simd::i32x4::splat(offset_y),
+                z0 + std::simd::i32x4::splat(offset_z),
+            );
+
+            let dx: std::simd::f32x4 =
+                std::simd::f32x4::splat(offset_x as f32) + jitter(hash, 0) - fx;
+            let dy: std::simd::f32x4 =
+                std::simd::f32x4::splat(offset_y as f32) + jitter(hash, 1) - fy;
+            let dz: std::simd::f32x4 =
+                std::simd::f32x4::splat(offset_z as f32) + jitter(hash, 2) - fz;
+
+            insert_distance(&mut closest, &mut second, dx * dx + dy * dy + dz * dz);
+        }
+
+        (closest.sqrt(), second.sqrt())
+    }
+
+    #[inline]
+    pub fn worley2(seed: u32, point: Vector2) -> Vector2 {
+        let (closest, second): (std::simd::f32x4, std::simd::f32x4) = worley2_x4(
+            seed,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+        );
+
+        Vector2::set(closest[0], second[0])
+    }
+
+    #[inline]
+    pub fn worley3(seed: u32, point: Vector3) -> Vector2 {
+        let (closest, second): (std::simd::f32x4, std::simd::f32x4) = worley3_x4(
+            seed,
+            std::simd::f32x4::splat(point.x()),
+            std::simd::f32x4::splat(point.y()),
+            std::simd::f32x4::splat(point.z()),
+        );
+
+        Vector2::set(closest[0], second[0])
+    }
+}
+
+
+#[cfg(test)]
+mod worley_test {
+    use crate::worley_math::*;
+    use crate::{Vector2, Vector3};
+
+    #[test]
+    fn closest_before_second() {
+        for index in 0..2_000 {
+            let t: f32 = index as f32 * 0.173;
+            let point: Vector3 = Vector3::set(t.sin() * 20.0, t * 0.5, t.cos() * 7.0);
+
+            let planar: Vector2 = worley2(5, Vector2::set(point.x(), point.y()));
+            let volume: Vector2 = worley3(5, point);
+
+            assert!(planar.x() <= planar.y());
+            assert!(volume.x() <= volume.y());
+
+            assert!(planar.y() <= 2.5f32.sqrt());
+            assert!(volume.y() <= 3.0f32.sqrt());
+
+            // A feature point always lies in the containing cell.
+            assert!(planar.x() <= std::f32::consts::SQRT_2);
+            assert!(volume.x() <= 3.0f32.sqrt());
+        }
+
+        assert_eq!(
+            worley3(9, Vector3::set(0.5, 1.5, 2.5)).x().to_bits(),
+            worley3(9, Vector3::set(0.5, 1.5, 2.5)).x().to_bits()
+        );
+    }
+
+    #[test]
+    fn distances_are_continuous() {
+        // F1 and F2 move no faster than the sample, a feature point missed by
+        // the search shows up as a jump.
+        let step: f32 = 0.025;
+        let direction: Vector3 = Vector3::set(0.48, 0.6, 0.64);
+
+        let mut previous_planar: Vector2 = worley2(3, Vector2::set(0.0, 0.0));
+        let mut previous_volume: Vector2 = worley3(3, Vector3::ZERO);
+
+        for index in 1..40_000 {
+            let point: Vector3 = direction * (index as f32 * step);
+
+            let planar: Vector2 = worley2(3, Vector2::set(point.x(), point.y()));
+            let volume: Vector2 = worley3(3, point);
+
+            let planar_step: f32 = step * 0.8 + 1.0e-4;
+            let volume_step: f32 = step + 1.0e-4;
+
+            assert!((planar.x() - previous_planar.x()).abs() <= planar_step);
+            assert!((planar.y() - previous_planar.y()).abs() <= planar_step);
+            assert!((volume.x() - previous_volume.x()).abs() <= volume_step);
+            assert!((volume.y() - previous_volume.y()).abs() <= volume_step);
+
+            previous_planar = planar;
+            previous_volume = volume;
+        }
+    }
+}
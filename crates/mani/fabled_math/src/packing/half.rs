@@ -0,0 +1,280 @@
+use std::fmt::{Display, Formatter};
+
+// IEEE 754 binary16 (f16) storage. Arithmetic is done by widening to f32.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Half {
+    pub value: u16,
+}
+
+impl Half {
+    pub const ZERO: Half = Half { value: 0x0000 };
+
+    pub const NEG_ZERO: Half = Half { value: 0x8000 };
+
+    pub const ONE: Half = Half { value: 0x3C00 };
+
+    pub const NEG_ONE: Half = Half { value: 0xBC00 };
+
+    pub const MAX: Half = Half { value: 0x7BFF };
+
+    pub const MIN: Half = Half { value: 0xFBFF };
+
+    pub const MIN_POSITIVE: Half = Half { value: 0x0400 };
+
+    pub const MIN_POSITIVE_SUBNORMAL: Half = Half { value: 0x0001 };
+
+    pub const EPSILON: Half = Half { value: 0x1400 };
+
+    pub const INFINITY: Half = Half { value: 0x7C00 };
+
+    pub const NEG_INFINITY: Half = Half { value: 0xFC00 };
+
+    pub const NAN: Half = Half { value: 0x7E00 };
+}
+
+impl Half {
+    #[inline(always)]
+    pub const fn from_bits(bits: u16) -> Half {
+        Half { value: bits }
+    }
+
+    #[inline(always)]
+    pub const fn to_bits(self) -> u16 {
+        self.value
+    }
+
+    // Round to nearest, ties to even. Values past the half range become
+    // infinity and values below the smallest subnormal flush to signed zero.
+    #[inline]
+    pub fn from_f32(value: f32) -> Half {
+        let bits: u32 = value.to_bits();
+
+        let sign: u32 = (bits >> 16) & 0x8000;
+        let exponent: i32 = ((bits >> 23) & 0xFF) as i32;
+        let mantissa: u32 = bits & 0x007F_FFFF;
+
+        if std::intrinsics::unlikely(exponent == 0xFF) {
+            let nan: u32 = if mantissa != 0 {
+                0x0200 | (mantissa >> 13)
+            } else {
+                0
+            };
+
+            return Half {
+                value: (sign | 0x7C00 | nan) as u16,
+            };
+        }
+
+        let half_exponent: i32 = exponent - 127 + 15;
+
+        if std::intrinsics::unlikely(half_exponent >= 0x1F) {
+            return Half {
+                value: (sign | 0x7C00) as u16,
+            };
+        }
+
+        if std::intrinsics::unlikely(half_exponent <= 0) {
+            if half_exponent < -10 {
+                return Half { value: sign as u16 };
+            }
+
+            let mantissa: u32 = mantissa | 0x0080_0000;
+            let shift: u32 = (14 - half_exponent) as u32;
+
+            return Half {
+                value: (sign | round_shift(mantissa, shift)) as u16,
+            };
+        }
+
+        // A carry out of the mantissa moves into the exponent, which also
+        // rounds the largest finite values up to infinity.
+        let rounded: u32 = (((half_exponent as u32) << 23) | mantissa) >> 13;
+        let round_bit: u32 = 1 << 12;
+
+        let rounded: u32 = if (mantissa & round_bit) != 0 && (mantissa & (3 * round_bit - 1)) != 0 {
+            rounded + 1
+        } else {
+            rounded
+        };
+
+        Half {
+            value: (sign | rounded) as u16,
+        }
+    }
+
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        let bits: u32 = self.value as u32;
+
+        let sign: u32 = (bits & 0x8000) << 16;
+        let exponent: u32 = (bits >> 10) & 0x1F;
+        let mantissa: u32 = bits & 0x03FF;
+
+        match exponent {
+            0 => {
+                let magnitude: f32 = mantissa as f32 * Half::MIN_POSITIVE_SUBNORMAL_F32;
+
+                f32::from_bits(sign | magnitude.to_bits())
+            }
+            0x1F => f32::from_bits(sign | 0x7F80_0000 | (mantissa << 13)),
+            _ => f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13)),
+        }
+    }
+
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        (self.value & 0x7C00) == 0x7C00 && (self.value & 0x03FF) != 0
+    }
+
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        (self.value & 0x7FFF) == 0x7C00
+    }
+
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        (self.value & 0x7C00) != 0x7C00
+    }
+
+    const MIN_POSITIVE_SUBNORMAL_F32: f32 = 1.0 / 16_777_216.0;
+}
+
+#[inline]
+fn round_shift(mantissa: u32, shift: u32) -> u32 {
+    let round_bit: u32 = 1 << (shift - 1);
+    let shifted: u32 = mantissa >> shift;
+
+    if (mantissa & round_bit) != 0 && (mantissa & (3 * round_bit - 1)) != 0 {
+        shifted + 1
+    } else {
+        shifted
+    }
+}
+
+impl From<f32> for Half {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Half::from_f32(value)
+    }
+}
+
+impl From<Half> for f32 {
+    #[inline]
+    fn from(half: Half) -> Self {
+        half.to_f32()
+    }
+}
+
+impl Display for Half {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Half (value : {})", self.to_f32())
+    }
+}
+
+
+pub mod half_math {
+    use crate::{Half, Vector2, Vector4};
+
+    // Same layout as GLSL packHalf2x16.
+    #[inline]
+    pub fn pack_half2x16(vector: Vector2) -> u32 {
+        let x: u32 = Half::from_f32(vector.x()).value as u32;
+        let y: u32 = Half::from_f32(vector.y()).value as u32;
+
+        x | (y << 16)
+    }
+
+    #[inline]
+    pub fn unpack_half2x16(packed: u32) -> Vector2 {
+        Vector2::set(
+            Half::from_bits(packed as u16).to_f32(),
+            Half::from_bits((packed >> 16) as u16).to_f32(),
+        )
+    }
+
+    #[inline]
+    pub fn pack_half4x16(vector: Vector4) -> [Half; 4] {
+        [
+            Half::from_f32(vector.x()),
+            Half::from_f32(vector.y()),
+            Half::from_f32(vector.z()),
+            Half::from_f32(vector.w()),
+        ]
+    }
+
+    #[inline]
+    pub fn unpack_half4x16(packed: [Half; 4]) -> Vector4 {
+        Vector4::set(
+            packed[0].to_f32(),
+            packed[1].to_f32(),
+            packed[2].to_f32(),
+            packed[3].to_f32(),
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod half_test {
+    use crate::half_math::*;
+    use crate::{Half, Vector2};
+
+    #[test]
+    fn special_values() {
+        assert_eq!(Half::from_f32(1.0), Half::ONE);
+        assert_eq!(Half::from_f32(-1.0), Half::NEG_ONE);
+        assert_eq!(Half::from_f32(65504.0), Half::MAX);
+        assert_eq!(Half::from_f32(65520.0), Half::INFINITY);
+        assert_eq!(Half::from_f32(f32::NEG_INFINITY), Half::NEG_INFINITY);
+        assert_eq!(Half::from_f32(-0.0), Half::NEG_ZERO);
+        assert_eq!(Half::from_f32(6.103_515_6e-5), Half::MIN_POSITIVE);
+        assert_eq!(Half::from_f32(5.960_464_5e-8), Half::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(Half::from_f32(2.0e-8), Half::ZERO);
+
+        assert!(Half::from_f32(f32::NAN).is_nan());
+        assert!(Half::NAN.to_f32().is_nan());
+        assert_eq!(Half::EPSILON.to_f32(), 0.000_976_562_5);
+    }
+
+    #[test]
+    fn ties_round_to_even() {
+        // 1 + 2^-11 lies halfway between 1 and the next half, 1 + 2^-10.
+        assert_eq!(Half::from_f32(1.0 + 0.000_488_281_25), Half::ONE);
+        assert_eq!(
+            Half::from_f32(1.0 + 3.0 * 0.000_488_281_25).value,
+            Half::ONE.value + 2
+        );
+    }
+
+    #[test]
+    fn every_half_round_trips() {
+        for bits in 0..=u16::MAX {
+            let half: Half = Half::from_bits(bits);
+
+            if half.is_nan() {
+                assert!(Half::from_f32(half.to_f32()).is_nan());
+            } else {
+                assert_eq!(Half::from_f32(half.to_f32()), half);
+            }
+        }
+    }
+
+    #[test]
+    fn relative_error() {
+        let mut value: f32 = 1.0e-4;
+
+        while value < 6.0e4 {
+            let error: f32 = (Half::from_f32(value).to_f32() - value).abs() / value;
+            assert!(error <= 0.000_488_281_25);
+
+            value *= 1.037;
+        }
+
+        let vector: Vector2 = Vector2::set(0.25, -3.5);
+        assert_eq!(
+            unpack_half2x16(pack_half2x16(vector)).to_primitive(),
+            [0.25, -3.5]
+        );
+    }
+}
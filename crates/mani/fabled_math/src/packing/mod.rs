@@ -0,0 +1,11 @@
+// Compact storage formats for vertex attributes and render targets. Packing
+// functions return the raw integer layout, channel x in the lowest bits.
+mod half;
+mod octahedral;
+mod packed;
+mod quantize;
+
+pub use half::*;
+pub use octahedral::*;
+pub use packed::*;
+pub use quantize::*;
@@ -0,0 +1,151 @@
+pub mod octahedral_math {
+    use crate::{Vector2, Vector3};
+
+    use crate::quantize_math::{pack_snorm2x16, unpack_snorm2x16};
+    use crate::vector_math::normalize;
+
+    #[inline]
+    fn sign_not_zero(value: f32) -> f32 {
+        if value >= 0.0 {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    // Projects a unit vector onto the octahedron |x| + |y| + |z| = 1 and
+    // unfolds the lower half over the diagonals, the result is in [-1, 1]^2.
+    // A zero normal has no direction and encodes as the center, which decodes
+    // to +z.
+    #[inline]
+    pub fn encode_octahedral(normal: Vector3) -> Vector2 {
+        let l1: f32 = normal.x().abs() + normal.y().abs() + normal.z().abs();
+
+        if l1 < f32::MIN_POSITIVE {
+            return Vector2::ZERO;
+        }
+
+        let inverse_l1: f32 = l1.recip();
+
+        let x: f32 = normal.x() * inverse_l1;
+        let y: f32 = normal.y() * inverse_l1;
+
+        if normal.z() < 0.0 {
+            Vector2::set(
+                (1.0 - y.abs()) * sign_not_zero(x),
+                (1.0 - x.abs()) * sign_not_zero(y),
+            )
+        } else {
+            Vector2::set(x, y)
+        }
+    }
+
+    #[inline]
+    pub fn decode_octahedral(encoded: Vector2) -> Vector3 {
+        let x: f32 = encoded.x();
+        let y: f32 = encoded.y();
+        let z: f32 = 1.0 - x.abs() - y.abs();
+
+        let fold: f32 = (-z).max(0.0);
+
+        Vector3 {
+            value: normalize(
+                Vector3::set(x - fold * sign_not_zero(x), y - fold * sign_not_zero(y), z).value,
+            ),
+        }
+    }
+
+    #[inline]
+    pub fn pack_octahedral16x2(normal: Vector3) -> u32 {
+        pack_snorm2x16(encode_octahedral(normal))
+    }
+
+    #[inline]
+    pub fn unpack_octahedral16x2(packed: u32) -> Vector3 {
+        decode_octahedral(unpack_snorm2x16(packed))
+    }
+
+    #[inline]
+    pub fn pack_octahedral8x2(normal: Vector3) -> u16 {
+        let encoded: Vector2 = encode_octahedral(normal);
+
+        u16::from_le_bytes([
+            crate::quantize_math::to_snorm8(encoded.x()) as u8,
+            crate::quantize_math::to_snorm8(encoded.y()) as u8,
+        ])
+    }
+
+    #[inline]
+    pub fn unpack_octahedral8x2(packed: u16) -> Vector3 {
+        let bytes: [u8; 2] = packed.to_le_bytes();
+
+        decode_octahedral(Vector2::set(
+            crate::quantize_math::from_snorm8(bytes[0] as i8),
+            crate::quantize_math::from_snorm8(bytes[1] as i8),
+        ))
+    }
+}
+
+
+#[cfg(test)]
+mod octahedral_test {
+    use crate::octahedral_math::*;
+    use crate::vector_math::{cross, dot, length, normalize};
+    use crate::Vector3;
+
+    fn sphere_points() -> Vec<Vector3> {
+        let mut points: Vec<Vector3> = vec![
+            Vector3::RIGHT,
+            Vector3::UP,
+            Vector3::FORWARD,
+            -Vector3::RIGHT,
+            -Vector3::UP,
+            -Vector3::FORWARD,
+        ];
+
+        // Fibonacci sphere.
+        let count: usize = 2000;
+        for index in 0..count {
+            let z: f32 = 1.0 - 2.0 * (index as f32 + 0.5) / count as f32;
+            let radius: f32 = (1.0 - z * z).sqrt();
+            let phi: f32 = index as f32 * 2.399_963;
+
+            points.push(Vector3::set(radius * phi.cos(), radius * phi.sin(), z));
+        }
+
+        points
+    }
+
+    // atan2 keeps precision for tiny angles where acos of the dot does not.
+    fn angle(lhs: Vector3, rhs: Vector3) -> f32 {
+        let rhs: std::simd::f32x4 = normalize(rhs.value);
+
+        length(cross(lhs.value, rhs)).atan2(dot(lhs.value, rhs))
+    }
+
+    #[test]
+    fn exact_round_trip() {
+        for normal in sphere_points() {
+            let decoded: Vector3 = decode_octahedral(encode_octahedral(normal));
+
+            assert!(angle(normal, decoded) < 1.0e-3);
+        }
+    }
+
+    #[test]
+    fn quantized_round_trip_error() {
+        for normal in sphere_points() {
+            assert!(angle(normal, unpack_octahedral16x2(pack_octahedral16x2(normal))) < 1.0e-4);
+            assert!(angle(normal, unpack_octahedral8x2(pack_octahedral8x2(normal))) < 0.02);
+        }
+    }
+
+    #[test]
+    fn zero_normal_encodes_to_center() {
+        assert_eq!(encode_octahedral(Vector3::ZERO).to_primitive(), [0.0, 0.0]);
+        assert_eq!(
+            unpack_octahedral16x2(pack_octahedral16x2(Vector3::ZERO)).to_primitive(),
+            [0.0, 0.0, 1.0]
+        );
+    }
+}
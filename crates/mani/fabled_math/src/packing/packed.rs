@@ -0,0 +1,122 @@
+pub mod packed_math {
+    use crate::Vector4;
+
+    // 10-10-10-2 layouts (DXGI R10G10B10A2, Vulkan A2B10G10R10 pack32):
+    // x in bits 0..10, y in 10..20, z in 20..30 and w in the top two bits.
+
+    const MASK_10: u32 = 0x3FF;
+    const MASK_2: u32 = 0x3;
+
+    #[inline]
+    fn to_unorm(value: f32, max: f32) -> u32 {
+        (value.clamp(0.0, 1.0) * max).round() as u32
+    }
+
+    #[inline]
+    fn to_snorm(value: f32, max: f32, mask: u32) -> u32 {
+        ((value.clamp(-1.0, 1.0) * max).round() as i32) as u32 & mask
+    }
+
+    // Sign extends the low bit_count bits.
+    #[inline]
+    fn from_snorm(bits: u32, bit_count: u32, max: f32) -> f32 {
+        let shift: u32 = 32 - bit_count;
+        let signed: i32 = ((bits << shift) as i32) >> shift;
+
+        (signed as f32 / max).max(-1.0)
+    }
+
+    #[inline]
+    pub fn pack_unorm10_10_10_2(vector: Vector4) -> u32 {
+        to_unorm(vector.x(), 1023.0)
+            | to_unorm(vector.y(), 1023.0) << 10
+            | to_unorm(vector.z(), 1023.0) << 20
+            | to_unorm(vector.w(), 3.0) << 30
+    }
+
+    #[inline]
+    pub fn unpack_unorm10_10_10_2(packed: u32) -> Vector4 {
+        Vector4::set(
+            (packed & MASK_10) as f32 / 1023.0,
+            ((packed >> 10) & MASK_10) as f32 / 1023.0,
+            ((packed >> 20) & MASK_10) as f32 / 1023.0,
+            ((packed >> 30) & MASK_2) as f32 / 3.0,
+        )
+    }
+
+    #[inline]
+    pub fn pack_snorm10_10_10_2(vector: Vector4) -> u32 {
+        to_snorm(vector.x(), 511.0, MASK_10)
+            | to_snorm(vector.y(), 511.0, MASK_10) << 10
+            | to_snorm(vector.z(), 511.0, MASK_10) << 20
+            | to_snorm(vector.w(), 1.0, MASK_2) << 30
+    }
+
+    #[inline]
+    pub fn unpack_snorm10_10_10_2(packed: u32) -> Vector4 {
+        Vector4::set(
+            from_snorm(packed & MASK_10, 10, 511.0),
+            from_snorm((packed >> 10) & MASK_10, 10, 511.0),
+            from_snorm((packed >> 20) & MASK_10, 10, 511.0),
+            from_snorm((packed >> 30) & MASK_2, 2, 1.0),
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod packed_test {
+    use crate::packed_math::*;
+    use crate::Vector4;
+
+    fn max_error(lhs: Vector4, rhs: Vector4) -> [f32; 4] {
+        let mut error: [f32; 4] = (lhs - rhs).value.to_array();
+        error.iter_mut().for_each(|value| *value = value.abs());
+
+        error
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(
+            pack_unorm10_10_10_2(Vector4::set(1.0, 0.0, 0.0, 0.0)),
+            0x0000_03FF
+        );
+        assert_eq!(
+            pack_unorm10_10_10_2(Vector4::set(0.0, 0.0, 1.0, 1.0)),
+            0xFFF0_0000
+        );
+        assert_eq!(
+            pack_snorm10_10_10_2(Vector4::set(-1.0, 0.0, 0.0, 0.0)),
+            0x0000_0201
+        );
+        assert_eq!(unpack_snorm10_10_10_2(0x0000_0200).x(), -1.0);
+        assert_eq!(unpack_snorm10_10_10_2(0xC000_0000).w(), -1.0);
+    }
+
+    #[test]
+    fn round_trip_error() {
+        for step in 0..=200 {
+            let t: f32 = step as f32 / 200.0;
+            let alpha: f32 = (step % 4) as f32 / 3.0;
+
+            let unorm: Vector4 = Vector4::set(t, 1.0 - t, t * t, alpha);
+            let error: [f32; 4] =
+                max_error(unpack_unorm10_10_10_2(pack_unorm10_10_10_2(unorm)), unorm);
+            assert!(error[..3]
+                .iter()
+                .all(|value| *value <= 0.5 / 1023.0 + f32::EPSILON));
+            assert!(error[3] <= f32::EPSILON);
+
+            let snorm: Vector4 = unorm * 2.0 - 1.0;
+            let snorm: Vector4 =
+                Vector4::set(snorm.x(), snorm.y(), snorm.z(), (step % 3) as f32 - 1.0);
+            let error: [f32; 4] =
+                max_error(unpack_snorm10_10_10_2(pack_snorm10_10_10_2(snorm)), snorm);
+            assert!(error[..3]
+                .iter()
+                .all(|value| *value <= 0.5 / 511.0 + f32::EPSILON));
+            assert!(error[3] <= f32::EPSILON);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+pub mod quantize_math {
+    use crate::{Vector2, Vector4};
+
+    // Unorm maps [0, 1] onto [0, max], snorm maps [-1, 1] onto [-max, max]
+    // so zero is exact and the most negative integer decodes to -1 as well,
+    // matching the D3D and Vulkan conversion rules.
+
+    #[inline]
+    pub fn to_unorm8(value: f32) -> u8 {
+        (value.clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+
+    #[inline]
+    pub fn from_unorm8(value: u8) -> f32 {
+        value as f32 * 255.0f32.recip()
+    }
+
+    #[inline]
+    pub fn to_snorm8(value: f32) -> i8 {
+        (value.clamp(-1.0, 1.0) * 127.0).round() as i8
+    }
+
+    #[inline]
+    pub fn from_snorm8(value: i8) -> f32 {
+        (value as f32 * 127.0f32.recip()).max(-1.0)
+    }
+
+    #[inline]
+    pub fn to_unorm16(value: f32) -> u16 {
+        (value.clamp(0.0, 1.0) * 65535.0).round() as u16
+    }
+
+    #[inline]
+    pub fn from_unorm16(value: u16) -> f32 {
+        value as f32 * 65535.0f32.recip()
+    }
+
+    #[inline]
+    pub fn to_snorm16(value: f32) -> i16 {
+        (value.clamp(-1.0, 1.0) * 32767.0).round() as i16
+    }
+
+    #[inline]
+    pub fn from_snorm16(value: i16) -> f32 {
+        (value as f32 * 32767.0f32.recip()).max(-1.0)
+    }
+
+    // Same layouts as GLSL packUnorm4x8, packSnorm4x8, packUnorm2x16 and
+    // packSnorm2x16.
+
+    #[inline]
+    pub fn pack_unorm4x8(vector: Vector4) -> u32 {
+        u32::from_le_bytes([
+            to_unorm8(vector.x()),
+            to_unorm8(vector.y()),
+            to_unorm8(vector.z()),
+            to_unorm8(vector.w()),
+        ])
+    }
+
+    #[inline]
+    pub fn unpack_unorm4x8(packed: u32) -> Vector4 {
+        let bytes: [u8; 4] = packed.to_le_bytes();
+
+        Vector4::set(
+            from_unorm8(bytes[0]),
+            from_unorm8(bytes[1]),
+            from_unorm8(bytes[2]),
+            from_unorm8(bytes[3]),
+        )
+    }
+
+    #[inline]
+    pub fn pack_snorm4x8(vector: Vector4) -> u32 {
+        u32::from_le_bytes([
+            to_snorm8(vector.x()) as u8,
+            to_snorm8(vector.y()) as u8,
+            to_snorm8(vector.z()) as u8,
+            to_snorm8(vector.w()) as u8,
+        ])
+    }
+
+    #[inline]
+    pub fn unpack_snorm4x8(packed: u32) -> Vector4 {
+        let bytes: [u8; 4] = packed.to_le_bytes();
+
+        Vector4::set(
+            from_snorm8(bytes[0] as i8),
+            from_snorm8(bytes[1] as i8),
+            from_snorm8(bytes[2] as i8),
+            from_snorm8(bytes[3] as i8),
+        )
+    }
+
+    #[inline]
+    pub fn pack_unorm2x16(vector: Vector2) -> u32 {
+        to_unorm16(vector.x()) as u32 | (to_unorm16(vector.y()) as u32) << 16
+    }
+
+    #[inline]
+    pub fn unpack_unorm2x16(packed: u32) -> Vector2 {
+        Vector2::set(
+            from_unorm16(packed as u16),
+            from_unorm16((packed >> 16) as u16),
+        )
+    }
+
+    #[inline]
+    pub fn pack_snorm2x16(vector: Vector2) -> u32 {
+        to_snorm16(vector.x()) as u16 as u32 | (to_snorm16(vector.y()) as u16 as u32) << 16
+    }
+
+    #[inline]
+    pub fn unpack_snorm2x16(packed: u32) -> Vector2 {
+        Vector2::set(
+            from_snorm16(packed as u16 as i16),
+            from_snorm16((packed >> 16) as u16 as i16),
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod quantize_test {
+    use crate::quantize_math::*;
+    use crate::{Vector2, Vector4};
+
+    #[test]
+    fn endpoints_are_exact() {
+        assert_eq!(from_unorm8(to_unorm8(1.0)), 1.0);
+        assert_eq!(from_unorm8(to_unorm8(0.0)), 0.0);
+        assert_eq!(from_snorm8(to_snorm8(-1.0)), -1.0);
+        assert_eq!(from_snorm8(to_snorm8(0.0)), 0.0);
+        assert_eq!(from_snorm8(i8::MIN), -1.0);
+        assert_eq!(from_snorm16(i16::MIN), -1.0);
+        assert_eq!(from_unorm16(to_unorm16(2.0)), 1.0);
+    }
+
+    #[test]
+    fn round_trip_error() {
+        for step in 0..=1000 {
+            let unit: f32 = step as f32 / 1000.0;
+            let signed: f32 = unit * 2.0 - 1.0;
+
+            assert!((from_unorm8(to_unorm8(unit)) - unit).abs() <= 0.5 / 255.0 + f32::EPSILON);
+            assert!((from_snorm8(to_snorm8(signed)) - signed).abs() <= 0.5 / 127.0 + f32::EPSILON);
+            assert!((from_unorm16(to_unorm16(unit)) - unit).abs() <= 0.5 / 65535.0 + f32::EPSILON);
+            assert!(
+                (from_snorm16(to_snorm16(signed)) - signed).abs() <= 0.5 / 32767.0 + f32::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn packed_layout() {
+        assert_eq!(pack_unorm4x8(Vector4::set(1.0, 0.0, 0.0, 1.0)), 0xFF00_00FF);
+        assert_eq!(
+            pack_snorm4x8(Vector4::set(-1.0, 0.0, 1.0, 0.0)),
+            0x007F_0081
+        );
+        assert_eq!(pack_unorm2x16(Vector2::set(0.0, 1.0)), 0xFFFF_0000);
+
+        let color: Vector4 = Vector4::set(0.2, 0.4, 0.6, 0.8);
+        let unpacked: Vector4 = unpack_unorm4x8(pack_unorm4x8(color));
+        assert!((unpacked - color)
+            .value
+            .to_array()
+            .iter()
+            .all(|v| v.abs() <= 0.5 / 255.0));
+
+        let signed: Vector2 = Vector2::set(-0.3, 0.7);
+        let unpacked: [f32; 2] = unpack_snorm2x16(pack_snorm2x16(signed)).to_primitive();
+        assert!(
+            (unpacked[0] + 0.3).abs() <= 0.5 / 32767.0
+                && (unpacked[1] - 0.7).abs() <= 0.5 / 32767.0
+        );
+
+        let signed: Vector4 = Vector4::set(-0.3, 0.7, -1.0, 0.1);
+        let unpacked: Vector4 = unpack_snorm4x8(pack_snorm4x8(signed));
+        assert!((unpacked - signed)
+            .value
+            .to_array()
+            .iter()
+            .all(|v| v.abs() <= 0.5 / 127.0));
+    }
+}
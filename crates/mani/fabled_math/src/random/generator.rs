@@ -0,0 +1,66 @@
+use crate::{Vector2, Vector3};
+
+pub trait RandomGenerator {
+    fn next_u32(&mut self) -> u32;
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let high: u64 = self.next_u32() as u64;
+        let low: u64 = self.next_u32() as u64;
+
+        (high << 32) | low
+    }
+
+    // Uniform in [0, 1) from the upper 24 bits.
+    #[inline]
+    fn next_f32(&mut self) -> f32 {
+        const INVERSE_24_BIT: f32 = 1.0 / 16_777_216.0;
+
+        (self.next_u32() >> 8) as f32 * INVERSE_24_BIT
+    }
+
+    #[inline]
+    fn next_range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    // Unbiased integer in [0, bound) using Lemire's multiply and reject.
+    #[inline]
+    fn next_bounded(&mut self, bound: u32) -> u32 {
+        if std::intrinsics::unlikely(bound == 0) {
+            return 0;
+        }
+
+        let threshold: u32 = bound.wrapping_neg() % bound;
+
+        loop {
+            let product: u64 = self.next_u32() as u64 * bound as u64;
+
+            if std::intrinsics::likely(product as u32 >= threshold) {
+                return (product >> 32) as u32;
+            }
+        }
+    }
+
+    #[inline]
+    fn next_bool(&mut self) -> bool {
+        self.next_u32() >> 31 != 0
+    }
+
+    #[inline]
+    fn next_vec2(&mut self) -> Vector2 {
+        let x: f32 = self.next_f32();
+        let y: f32 = self.next_f32();
+
+        Vector2::set(x, y)
+    }
+
+    #[inline]
+    fn next_vec3(&mut self) -> Vector3 {
+        let x: f32 = self.next_f32();
+        let y: f32 = self.next_f32();
+        let z: f32 = self.next_f32();
+
+        Vector3::set(x, y, z)
+    }
+}
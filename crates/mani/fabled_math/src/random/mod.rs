@@ -0,0 +1,14 @@
+// Small deterministic generators and sampling. Sampling functions take
+// uniform numbers in [0, 1) rather than a generator so the same warps work
+// with both random and low discrepancy input.
+mod generator;
+mod pcg;
+mod sampling;
+mod sequence;
+mod xoshiro;
+
+pub use generator::*;
+pub use pcg::*;
+pub use sampling::*;
+pub use sequence::*;
+pub use xoshiro::*;
@@ -0,0 +1,178 @@
+use crate::RandomGenerator;
+
+use std::fmt::{Display, Formatter};
+
+// PCG-XSH-RR with 64 bit state and 32 bit output. The stream selects one of
+// 2^63 independent sequences for the same seed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pcg32 {
+    pub state: u64,
+    pub increment: u64,
+}
+
+impl Default for Pcg32 {
+    fn default() -> Self {
+        Pcg32::new(Pcg32::DEFAULT_SEED, Pcg32::DEFAULT_STREAM)
+    }
+}
+
+impl Pcg32 {
+    pub const DEFAULT_SEED: u64 = 0x853c_49e6_748f_ea9b;
+    pub const DEFAULT_STREAM: u64 = 0xda3e_39cb_94b9_5bdb;
+
+    const MULTIPLIER: u64 = 0x5851_f42d_4c95_7f2d;
+
+    #[inline]
+    pub fn new(seed: u64, stream: u64) -> Pcg32 {
+        let mut generator: Pcg32 = Pcg32 {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+
+        generator.step();
+        generator.state = generator.state.wrapping_add(seed);
+        generator.step();
+
+        generator
+    }
+
+    #[inline(always)]
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(Pcg32::MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+
+    // Jumps the generator by delta steps in O(log delta).
+    pub fn advance(&mut self, delta: u64) {
+        let mut accumulated_multiplier: u64 = 1;
+        let mut accumulated_increment: u64 = 0;
+
+        let mut current_multiplier: u64 = Pcg32::MULTIPLIER;
+        let mut current_increment: u64 = self.increment;
+
+        let mut remaining: u64 = delta;
+
+        while remaining > 0 {
+            if remaining & 1 != 0 {
+                accumulated_multiplier = accumulated_multiplier.wrapping_mul(current_multiplier);
+                accumulated_increment = accumulated_increment
+                    .wrapping_mul(current_multiplier)
+                    .wrapping_add(current_increment);
+            }
+
+            current_increment = current_multiplier
+                .wrapping_add(1)
+                .wrapping_mul(current_increment);
+            current_multiplier = current_multiplier.wrapping_mul(current_multiplier);
+
+            remaining >>= 1;
+        }
+
+        self.state = accumulated_multiplier
+            .wrapping_mul(self.state)
+            .wrapping_add(accumulated_increment);
+    }
+}
+
+impl RandomGenerator for Pcg32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let previous: u64 = self.state;
+
+        self.step();
+
+        let xor_shifted: u32 = (((previous >> 18) ^ previous) >> 27) as u32;
+        let rotation: u32 = (previous >> 59) as u32;
+
+        xor_shifted.rotate_right(rotation)
+    }
+}
+
+impl Display for Pcg32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pcg32 (state : {}, increment : {})",
+            self.state, self.increment
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod generator_test {
+    use crate::{Pcg32, RandomGenerator, Xoshiro128PlusPlus};
+
+    #[test]
+    fn pcg32_reference_output() {
+        // Output of the reference pcg32-demo for seed 42 and stream 54.
+        let mut generator: Pcg32 = Pcg32::new(42, 54);
+
+        for expected in [
+            0xa15c_02b7,
+            0x7b47_f409,
+            0xba1d_3330,
+            0x83d2_f293,
+            0xbfa4_784b,
+        ] {
+            assert_eq!(generator.next_u32(), expected);
+        }
+
+        let mut other_stream: Pcg32 = Pcg32::new(42, 55);
+        assert_ne!(other_stream.next_u32(), 0xa15c_02b7);
+    }
+
+    #[test]
+    fn pcg32_advance() {
+        let mut stepped: Pcg32 = Pcg32::new(7, 3);
+        let mut advanced: Pcg32 = stepped;
+
+        for _ in 0..1000 {
+            stepped.next_u32();
+        }
+
+        advanced.advance(1000);
+
+        assert_eq!(stepped, advanced);
+    }
+
+    #[test]
+    fn xoshiro_reference_and_streams() {
+        let mut generator: Xoshiro128PlusPlus = Xoshiro128PlusPlus {
+            state: [1, 2, 3, 4],
+        };
+
+        assert_eq!(generator.next_u32(), 641);
+        assert_eq!(generator.next_u32(), 1_573_767);
+
+        let first: Xoshiro128PlusPlus = Xoshiro128PlusPlus::from_stream(9, 0);
+        let second: Xoshiro128PlusPlus = Xoshiro128PlusPlus::from_stream(9, 1);
+
+        let mut jumped: Xoshiro128PlusPlus = first;
+        jumped.jump();
+
+        assert_eq!(jumped, second);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn float_and_bounded_ranges() {
+        let mut generator: Pcg32 = Pcg32::default();
+
+        let mut histogram: [u32; 6] = [0; 6];
+
+        for _ in 0..60_000 {
+            let value: f32 = generator.next_f32();
+            assert!((0.0..1.0).contains(&value));
+
+            histogram[generator.next_bounded(6) as usize] += 1;
+        }
+
+        for bucket in histogram {
+            assert!((9_400..10_600).contains(&bucket));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+// Warps from the unit square to common domains. Every function expects
+// uniform input in [0, 1)^2 and preserves stratification where noted.
+pub mod sample_math {
+    use crate::{Triangle, Vector2, Vector3};
+
+    use std::f32::consts::{FRAC_PI_4, PI, TAU};
+
+    // Shirley-Chiu concentric mapping, keeps strata adjacent on the disk.
+    #[inline]
+    pub fn concentric_disk(uniform: Vector2) -> Vector2 {
+        let offset_x: f32 = 2.0 * uniform.x() - 1.0;
+        let offset_y: f32 = 2.0 * uniform.y() - 1.0;
+
+        if std::intrinsics::unlikely(offset_x == 0.0 && offset_y == 0.0) {
+            return Vector2::set(0.0, 0.0);
+        }
+
+        let (radius, theta): (f32, f32) = if offset_x.abs() > offset_y.abs() {
+            (offset_x, FRAC_PI_4 * (offset_y / offset_x))
+        } else {
+            (
+                offset_y,
+                2.0 * FRAC_PI_4 - FRAC_PI_4 * (offset_x / offset_y),
+            )
+        };
+
+        let (sin, cos): (f32, f32) = theta.sin_cos();
+
+        Vector2::set(radius * cos, radius * sin)
+    }
+
+    #[inline]
+    pub fn uniform_disk(uniform: Vector2) -> Vector2 {
+        let radius: f32 = uniform.x().sqrt();
+        let (sin, cos): (f32, f32) = (TAU * uniform.y()).sin_cos();
+
+        Vector2::set(radius * cos, radius * sin)
+    }
+
+    #[inline]
+    pub fn uniform_sphere(uniform: Vector2) -> Vector3 {
+        let z: f32 = 1.0 - 2.0 * uniform.x();
+        let radius: f32 = (1.0 - z * z).max(0.0).sqrt();
+        let (sin, cos): (f32, f32) = (TAU * uniform.y()).sin_cos();
+
+        Vector3::set(radius * cos, radius * sin, z)
+    }
+
+    // Hemispheres are oriented around Vector3::FORWARD (+z).
+    #[inline]
+    pub fn uniform_hemisphere(uniform: Vector2) -> Vector3 {
+        let z: f32 = uniform.x();
+        let radius: f32 = (1.0 - z * z).max(0.0).sqrt();
+        let (sin, cos): (f32, f32) = (TAU * uniform.y()).sin_cos();
+
+        Vector3::set(radius * cos, radius * sin, z)
+    }
+
+    // Malley's method, the pdf is cos(theta) / pi.
+    #[inline]
+    pub fn cosine_hemisphere(uniform: Vector2) -> Vector3 {
+        let disk: Vector2 = concentric_disk(uniform);
+        let z: f32 = (1.0 - disk.x() * disk.x() - disk.y() * disk.y())
+            .max(0.0)
+            .sqrt();
+
+        Vector3::set(disk.x(), disk.y(), z)
+    }
+
+    #[inline]
+    pub fn cosine_hemisphere_pdf(cos_theta: f32) -> f32 {
+        cos_theta * (1.0 / PI)
+    }
+
+    // Barycentric coordinates (a, b, c) uniformly distributed over a triangle.
+    #[inline]
+    pub fn uniform_triangle(uniform: Vector2) -> Vector3 {
+        let root: f32 = uniform.x().sqrt();
+
+        let a: f32 = 1.0 - root;
+        let b: f32 = root * (1.0 - uniform.y());
+
+        Vector3::set(a, b, 1.0 - a - b)
+    }
+
+    #[inline]
+    pub fn sample_triangle(triangle: Triangle, uniform: Vector2) -> Vector3 {
+        let barycentric: Vector3 = uniform_triangle(uniform);
+
+        triangle.a * barycentric.x() + triangle.b * barycentric.y() + triangle.c * barycentric.z()
+    }
+}
+
+
+#[cfg(test)]
+mod sampling_test {
+    use crate::sample_math::*;
+    use crate::vector_math::{dot, length};
+    use crate::{Pcg32, RandomGenerator, Triangle, Vector2, Vector3};
+
+    #[test]
+    fn samples_stay_in_domain() {
+        let mut generator: Pcg32 = Pcg32::new(3, 1);
+
+        let triangle: Triangle = Triangle::new(
+            Vector3::set(0.0, 0.0, 0.0),
+            Vector3::set(2.0, 0.0, 0.0),
+            Vector3::set(0.0, 3.0, 0.0),
+        );
+
+        for _ in 0..5_000 {
+            let uniform: Vector2 = generator.next_vec2();
+
+            let disk: Vector2 = concentric_disk(uniform);
+            assert!(disk.x() * disk.x() + disk.y() * disk.y() <= 1.0 + 1e-5);
+
+            let polar_disk: Vector2 = uniform_disk(uniform);
+            assert!(
+                polar_disk.x() * polar_disk.x() + polar_disk.y() * polar_disk.y() <= 1.0 + 1e-5
+            );
+
+            assert!((length(uniform_sphere(uniform).value) - 1.0).abs() < 1e-5);
+
+            let hemisphere: Vector3 = uniform_hemisphere(uniform);
+            assert!(hemisphere.z() >= 0.0 && (length(hemisphere.value) - 1.0).abs() < 1e-5);
+
+            let cosine: Vector3 = cosine_hemisphere(uniform);
+            assert!(cosine.z() >= 0.0 && (length(cosine.value) - 1.0).abs() < 1e-4);
+
+            let barycentric: Vector3 = uniform_triangle(uniform);
+            assert!(barycentric.x() >= 0.0 && barycentric.y() >= 0.0 && barycentric.z() >= -1e-6);
+
+            let point: Vector3 = sample_triangle(triangle, uniform);
+            assert!(point.x() >= -1e-6 && point.y() >= -1e-6);
+            assert!(point.x() / 2.0 + point.y() / 3.0 <= 1.0 + 1e-5);
+        }
+    }
+
+    #[test]
+    fn cosine_weighting() {
+        // E[cos theta] is 2/3 for cosine weighted and 1/2 for uniform.
+        let mut generator: Pcg32 = Pcg32::new(11, 0);
+
+        let count: usize = 100_000;
+        let mut cosine_mean: f32 = 0.0;
+        let mut uniform_mean: f32 = 0.0;
+
+        for _ in 0..count {
+            let uniform: Vector2 = generator.next_vec2();
+
+            cosine_mean += dot(cosine_hemisphere(uniform).value, Vector3::FORWARD.value);
+            uniform_mean += dot(uniform_hemisphere(uniform).value, Vector3::FORWARD.value);
+        }
+
+        assert!((cosine_mean / count as f32 - 2.0 / 3.0).abs() < 0.01);
+        assert!((uniform_mean / count as f32 - 0.5).abs() < 0.01);
+    }
+}
@@ -0,0 +1,265 @@
+// Low discrepancy sequences in [0, 1). The scramble arguments xor randomize
+// the sequence while keeping its stratification, pass 0 for the plain one.
+pub mod sequence_math {
+    use crate::{Vector2, Vector3};
+
+    const INVERSE_32_BIT: f32 = 1.0 / 4_294_967_296.0;
+
+    // Largest f32 below one, keeps every output inside [0, 1).
+    const ONE_MINUS_EPSILON: f32 = 0.999_999_94;
+
+    const SOBOL_DIRECTION: [[u32; 32]; 3] =
+        [sobol_direction(0), sobol_direction(1), sobol_direction(2)];
+
+    // Direction numbers for x, x + 1 and x^2 + x + 1, the first three
+    // primitive polynomials of the Joe-Kuo table.
+    const fn sobol_direction(dimension: usize) -> [u32; 32] {
+        let mut direction: [u32; 32] = [0; 32];
+        let mut bit: usize = 0;
+
+        while bit < 32 {
+            direction[bit] = match dimension {
+                0 => 1 << (31 - bit),
+                1 => {
+                    if bit == 0 {
+                        1 << 31
+                    } else {
+                        direction[bit - 1] ^ (direction[bit - 1] >> 1)
+                    }
+                }
+                _ => {
+                    if bit == 0 {
+                        1 << 31
+                    } else if bit == 1 {
+                        3 << 30
+                    } else {
+                        direction[bit - 2] ^ (direction[bit - 2] >> 2) ^ direction[bit - 1]
+                    }
+                }
+            };
+
+            bit += 1;
+        }
+
+        direction
+    }
+
+    #[inline(always)]
+    fn to_unit(bits: u32) -> f32 {
+        (bits as f32 * INVERSE_32_BIT).min(ONE_MINUS_EPSILON)
+    }
+
+    // Base 0 divides by zero and base 1 never runs out of digits, so both are
+    // rejected.
+    #[inline]
+    pub fn radical_inverse(base: u32, index: u32) -> f32 {
+        assert!(
+            base >= 2,
+            "radical inverse base must be at least 2, got {}",
+            base
+        );
+
+        let inverse_base: f64 = 1.0 / base as f64;
+
+        let mut remaining: u32 = index;
+        let mut reversed: u64 = 0;
+        let mut inverse_base_power: f64 = 1.0;
+
+        while remaining > 0 {
+            let next: u32 = remaining / base;
+            let digit: u32 = remaining - next * base;
+
+            reversed = reversed * base as u64 + digit as u64;
+            inverse_base_power *= inverse_base;
+
+            remaining = next;
+        }
+
+        ((reversed as f64 * inverse_base_power) as f32).min(ONE_MINUS_EPSILON)
+    }
+
+    #[inline]
+    pub fn halton2(index: u32) -> Vector2 {
+        Vector2::set(radical_inverse(2, index), radical_inverse(3, index))
+    }
+
+    #[inline]
+    pub fn halton3(index: u32) -> Vector3 {
+        Vector3::set(
+            radical_inverse(2, index),
+            radical_inverse(3, index),
+            radical_inverse(5, index),
+        )
+    }
+
+    // Only the first three dimensions have direction numbers, higher ones are
+    // rejected rather than aliased onto a correlated dimension.
+    #[inline]
+    pub fn sobol(dimension: usize, index: u32, scramble: u32) -> f32 {
+        assert!(
+            dimension < SOBOL_DIRECTION.len(),
+            "sobol dimension must be below {}, got {}",
+            SOBOL_DIRECTION.len(),
+            dimension
+        );
+
+        let direction: &[u32; 32] = &SOBOL_DIRECTION[dimension];
+
+        let mut result: u32 = scramble;
+        let mut remaining: u32 = index;
+        let mut bit: usize = 0;
+
+        while remaining != 0 {
+            if remaining & 1 != 0 {
+                result ^= direction[bit];
+            }
+
+            remaining >>= 1;
+            bit += 1;
+        }
+
+        to_unit(result)
+    }
+
+    #[inline]
+    pub fn sobol2(index: u32, scramble: u32) -> Vector2 {
+        Vector2::set(sobol(0, index, scramble), sobol(1, index, scramble))
+    }
+
+    #[inline]
+    pub fn sobol3(index: u32, scramble: u32) -> Vector3 {
+        Vector3::set(
+            sobol(0, index, scramble),
+            sobol(1, index, scramble),
+            sobol(2, index, scramble),
+        )
+    }
+
+    // Roberts' R2 sequence from the plastic number, unbounded and extendable.
+    #[inline]
+    pub fn r2(index: u32) -> Vector2 {
+        const PLASTIC: f64 = 1.324_717_957_244_746;
+        const ALPHA_X: f64 = 1.0 / PLASTIC;
+        const ALPHA_Y: f64 = 1.0 / (PLASTIC * PLASTIC);
+
+        let x: f64 = (0.5 + ALPHA_X * index as f64).fract();
+        let y: f64 = (0.5 + ALPHA_Y * index as f64).fract();
+
+        Vector2::set(
+            (x as f32).min(ONE_MINUS_EPSILON),
+            (y as f32).min(ONE_MINUS_EPSILON),
+        )
+    }
+
+    // Sample index for a pixel such that every aligned 2^k by 2^k tile takes
+    // 4^k consecutive indices. Feeding the index to a sobol or R2 sequence
+    // spreads the error across neighbouring pixels as blue noise. The morton
+    // digits are scrambled per node of the quadtree to hide the z pattern.
+    #[inline]
+    pub fn blue_noise_index(x: u32, y: u32, levels: u32, seed: u32) -> u32 {
+        let levels: u32 = levels.min(16);
+
+        let mut index: u32 = 0;
+
+        for level in (0..levels).rev() {
+            let digit: u32 = (((y >> level) & 1) << 1) | ((x >> level) & 1);
+
+            let mut node: u32 = (index ^ seed).wrapping_mul(0x9e37_79b9) ^ level;
+            node ^= node >> 16;
+            node = node.wrapping_mul(0x85eb_ca6b);
+            node ^= node >> 13;
+
+            index = (index << 2) | (digit ^ (node & 3));
+        }
+
+        index
+    }
+}
+
+
+#[cfg(test)]
+mod sequence_test {
+    use crate::sequence_math::*;
+    use crate::Vector2;
+
+    #[test]
+    fn radical_inverse_values() {
+        assert_eq!(radical_inverse(2, 1), 0.5);
+        assert_eq!(radical_inverse(2, 3), 0.75);
+        assert!((radical_inverse(3, 5) - 7.0 / 9.0).abs() < 1e-6);
+
+        assert_eq!(halton2(1).to_primitive(), [0.5, 1.0 / 3.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn radical_inverse_rejects_unary_base() {
+        radical_inverse(1, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sobol_rejects_missing_dimension() {
+        sobol(3, 5, 0);
+    }
+
+    #[test]
+    fn sobol_is_stratified() {
+        // The first 2^k points of a 2d sobol sequence put one point in every
+        // elementary interval of area 2^-k.
+        for scramble in [0, 0x1234_5678] {
+            let points: Vec<Vector2> = (0..16).map(|index| sobol2(index, scramble)).collect();
+
+            for (columns, rows) in [(16, 1), (8, 2), (4, 4), (2, 8), (1, 16)] {
+                let mut occupied: Vec<bool> = vec![false; 16];
+
+                for point in points.iter() {
+                    let column: usize = (point.x() * columns as f32) as usize;
+                    let row: usize = (point.y() * rows as f32) as usize;
+
+                    let cell: usize = row * columns + column;
+
+                    assert!(!occupied[cell]);
+                    occupied[cell] = true;
+                }
+            }
+        }
+
+        for index in 0..64 {
+            let point = sobol3(index, 0);
+            let third: f32 = point.z();
+
+            assert!((0.0..1.0).contains(&third));
+        }
+    }
+
+    #[test]
+    fn r2_in_unit_square() {
+        for index in 0..10_000 {
+            let point: Vector2 = r2(index);
+
+            assert!((0.0..1.0).contains(&point.x()) && (0.0..1.0).contains(&point.y()));
+        }
+    }
+
+    #[test]
+    fn blue_noise_index_tiles() {
+        let levels: u32 = 3;
+        let size: u32 = 1 << levels;
+
+        let mut seen: Vec<bool> = vec![false; (size * size) as usize];
+
+        for y in 0..size {
+            for x in 0..size {
+                let index: u32 = blue_noise_index(x, y, levels, 77);
+
+                assert!(!seen[index as usize]);
+                seen[index as usize] = true;
+
+                // Aligned 2x2 tiles share the same block of four indices.
+                let tile_origin: u32 = blue_noise_index(x & !1, y & !1, levels, 77);
+                assert_eq!(index >> 2, tile_origin >> 2);
+            }
+        }
+    }
+}
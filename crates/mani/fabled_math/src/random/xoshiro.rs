@@ -0,0 +1,108 @@
+use crate::RandomGenerator;
+
+use std::fmt::{Display, Formatter};
+
+// xoshiro128++ with 128 bit state. Streams are 2^64 steps apart via jump so
+// generators for different streams never overlap.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xoshiro128PlusPlus {
+    pub state: [u32; 4],
+}
+
+impl Default for Xoshiro128PlusPlus {
+    fn default() -> Self {
+        Xoshiro128PlusPlus::new(0)
+    }
+}
+
+impl Xoshiro128PlusPlus {
+    const JUMP: [u32; 4] = [0x8764_000b, 0xf542_d2d3, 0x6fa0_35c3, 0x77f2_db5b];
+
+    // Seeds the state through splitmix64 so that similar seeds diverge and the
+    // state is never all zero.
+    #[inline]
+    pub fn new(seed: u64) -> Xoshiro128PlusPlus {
+        let mut splitmix_state: u64 = seed;
+
+        let mut splitmix = || {
+            splitmix_state = splitmix_state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+            let mut z: u64 = splitmix_state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+            z ^ (z >> 31)
+        };
+
+        let low: u64 = splitmix();
+        let high: u64 = splitmix();
+
+        Xoshiro128PlusPlus {
+            state: [
+                low as u32,
+                (low >> 32) as u32,
+                high as u32,
+                (high >> 32) as u32,
+            ],
+        }
+    }
+
+    #[inline]
+    pub fn from_stream(seed: u64, stream: u32) -> Xoshiro128PlusPlus {
+        let mut generator: Xoshiro128PlusPlus = Xoshiro128PlusPlus::new(seed);
+
+        for _ in 0..stream {
+            generator.jump();
+        }
+
+        generator
+    }
+
+    // Equivalent to 2^64 calls to next_u32.
+    pub fn jump(&mut self) {
+        let mut jumped: [u32; 4] = [0; 4];
+
+        for jump_word in Xoshiro128PlusPlus::JUMP {
+            for bit in 0..32 {
+                if jump_word & (1 << bit) != 0 {
+                    for (jumped_word, state_word) in jumped.iter_mut().zip(self.state.iter()) {
+                        *jumped_word ^= state_word;
+                    }
+                }
+
+                self.next_u32();
+            }
+        }
+
+        self.state = jumped;
+    }
+}
+
+impl RandomGenerator for Xoshiro128PlusPlus {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let [s0, s1, s2, s3]: [u32; 4] = self.state;
+
+        let result: u32 = s0.wrapping_add(s3).rotate_left(7).wrapping_add(s0);
+
+        let t: u32 = s1 << 9;
+
+        let s2: u32 = s2 ^ s0;
+        let s3: u32 = s3 ^ s1;
+        let s1: u32 = s1 ^ s2;
+        let s0: u32 = s0 ^ s3;
+        let s2: u32 = s2 ^ t;
+        let s3: u32 = s3.rotate_left(11);
+
+        self.state = [s0, s1, s2, s3];
+
+        result
+    }
+}
+
+impl Display for Xoshiro128PlusPlus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Xoshiro128PlusPlus (state : {:?})", self.state)
+    }
+}
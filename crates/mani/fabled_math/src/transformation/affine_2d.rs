@@ -0,0 +1,252 @@
+use crate::{Matrix2x2, Vector2};
+
+use std::{
+    fmt::Display,
+    ops::{Mul, MulAssign},
+};
+
+#[derive(Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Affine2 {
+    pub translation: Vector2,
+    pub matrix2: Matrix2x2,
+}
+
+impl Affine2 {
+    pub const IDENTITY: Affine2 = Affine2 {
+        translation: Vector2::ZERO,
+        matrix2: Matrix2x2::IDENTITY,
+    };
+
+    pub const ZERO: Affine2 = Affine2 {
+        translation: Vector2::ZERO,
+        matrix2: Matrix2x2::ZERO,
+    };
+}
+
+impl Affine2 {
+    #[inline(always)]
+    pub const fn set(column_x: Vector2, column_y: Vector2, column_z: Vector2) -> Affine2 {
+        Affine2 {
+            translation: column_z,
+            matrix2: Matrix2x2::set(column_x, column_y),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn new(translation: Vector2, matrix2: Matrix2x2) -> Affine2 {
+        Affine2 {
+            translation,
+            matrix2,
+        }
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f32; 6] {
+        [
+            self.matrix2.column_x.x(),
+            self.matrix2.column_x.y(),
+            self.matrix2.column_y.x(),
+            self.matrix2.column_y.y(),
+            self.translation.x(),
+            self.translation.y(),
+        ]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f32; 6]) -> Affine2 {
+        Affine2::set(
+            Vector2::set(array[0], array[1]),
+            Vector2::set(array[2], array[3]),
+            Vector2::set(array[4], array[5]),
+        )
+    }
+}
+
+impl Display for Affine2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Affine2\n[\n\t{}\n\t{}\n\t{}\n]",
+            self.matrix2.column_x, self.matrix2.column_y, self.translation
+        )
+    }
+}
+
+impl Mul for Affine2 {
+    type Output = Affine2;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Affine2 {
+            translation: self.matrix2 * rhs.translation + self.translation,
+            matrix2: self.matrix2 * rhs.matrix2,
+        }
+    }
+}
+
+impl MulAssign for Affine2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+pub mod affine2_math {
+    use crate::{Affine2, Matrix2x2, Matrix3x3, Vector2, Vector3};
+
+    use crate::matrix2x2_math::{
+        determinant_mat2, from_angle_mat2, from_mat3_mat2, from_scale_angle_mat2,
+        from_scale_mat2, inverse_mat2, to_mat3_mat2, to_scale_angle_mat2,
+    };
+
+    #[inline]
+    pub const fn from_translation_affine2(translation: Vector2) -> Affine2 {
+        Affine2 {
+            translation,
+            matrix2: Matrix2x2::IDENTITY,
+        }
+    }
+
+    #[inline]
+    pub const fn from_scale_affine2(scale: Vector2) -> Affine2 {
+        Affine2 {
+            translation: Vector2::ZERO,
+            matrix2: from_scale_mat2(scale),
+        }
+    }
+
+    #[inline]
+    pub fn from_angle_affine2(angle_radian: f32) -> Affine2 {
+        Affine2 {
+            translation: Vector2::ZERO,
+            matrix2: from_angle_mat2(angle_radian),
+        }
+    }
+
+    // Scale first, then rotate, then translate.
+    #[inline]
+    pub fn from_scale_angle_translation_affine2(
+        scale: Vector2,
+        angle_radian: f32,
+        translation: Vector2,
+    ) -> Affine2 {
+        Affine2 {
+            translation,
+            matrix2: from_scale_angle_mat2(scale, angle_radian),
+        }
+    }
+
+    // Returns (scale, angle, translation), a reflection is reported as a negative x scale.
+    #[inline]
+    pub fn to_scale_angle_translation_affine2(affine2: Affine2) -> (Vector2, f32, Vector2) {
+        let (scale, angle) = to_scale_angle_mat2(affine2.matrix2);
+
+        (scale, angle, affine2.translation)
+    }
+
+    #[inline]
+    pub fn determinant_affine2(affine2: Affine2) -> f32 {
+        determinant_mat2(affine2.matrix2)
+    }
+
+    #[inline]
+    pub fn inverse_affine2(affine2: Affine2) -> Affine2 {
+        let inverse_matrix2: Matrix2x2 = inverse_mat2(affine2.matrix2);
+
+        Affine2 {
+            translation: -(inverse_matrix2 * affine2.translation),
+            matrix2: inverse_matrix2,
+        }
+    }
+
+    #[inline]
+    pub fn transform_point2_affine2(affine2: Affine2, point2: Vector2) -> Vector2 {
+        affine2.matrix2 * point2 + affine2.translation
+    }
+
+    #[inline]
+    pub fn transform_vector2_affine2(affine2: Affine2, vector2: Vector2) -> Vector2 {
+        affine2.matrix2 * vector2
+    }
+
+    #[inline]
+    pub fn inverse_transform_point2_affine2(affine2: Affine2, point2: Vector2) -> Vector2 {
+        inverse_mat2(affine2.matrix2) * (point2 - affine2.translation)
+    }
+
+    #[inline]
+    pub fn inverse_transform_vector2_affine2(affine2: Affine2, vector2: Vector2) -> Vector2 {
+        inverse_mat2(affine2.matrix2) * vector2
+    }
+
+    #[inline]
+    pub fn from_mat3_affine2(matrix_3x3: Matrix3x3) -> Affine2 {
+        Affine2 {
+            translation: Vector2::set(matrix_3x3.column_z.x(), matrix_3x3.column_z.y()),
+            matrix2: from_mat3_mat2(matrix_3x3),
+        }
+    }
+
+    #[inline]
+    pub fn to_mat3_affine2(affine2: Affine2) -> Matrix3x3 {
+        let mut matrix_3x3: Matrix3x3 = to_mat3_mat2(affine2.matrix2);
+
+        matrix_3x3.column_z = Vector3::set(affine2.translation.x(), affine2.translation.y(), 1.0);
+
+        matrix_3x3
+    }
+}
+
+#[cfg(test)]
+mod affine2_test {
+    use crate::approx::{approx_vec2, THRESHOLD};
+    use crate::affine2_math::*;
+    use crate::{Affine2, Vector2};
+
+    #[test]
+    fn compose_decompose() {
+        let scale: Vector2 = Vector2::set(2.0, 0.5);
+        let translation: Vector2 = Vector2::set(-3.0, 4.0);
+
+        let affine2: Affine2 = from_scale_angle_translation_affine2(scale, 0.75, translation);
+
+        let (decomposed_scale, angle, decomposed_translation) =
+            to_scale_angle_translation_affine2(affine2);
+
+        assert!(approx_vec2(decomposed_scale, scale));
+        assert!((angle - 0.75).abs() <= THRESHOLD);
+        assert!(approx_vec2(decomposed_translation, translation));
+
+        let reflected: Affine2 =
+            from_scale_angle_translation_affine2(Vector2::set(-1.0, 1.0), 0.5, translation);
+
+        let (reflected_scale, reflected_angle, _) = to_scale_angle_translation_affine2(reflected);
+
+        assert!(approx_vec2(reflected_scale, Vector2::set(-1.0, 1.0)));
+        assert!((reflected_angle - 0.5).abs() <= THRESHOLD);
+    }
+
+    #[test]
+    fn transform_inverse() {
+        let affine2: Affine2 = from_scale_angle_translation_affine2(
+            Vector2::set(2.0, 3.0),
+            std::f32::consts::FRAC_PI_2,
+            Vector2::set(1.0, 1.0),
+        );
+
+        let point: Vector2 = transform_point2_affine2(affine2, Vector2::RIGHT);
+        assert!(approx_vec2(point, Vector2::set(1.0, 3.0)));
+
+        assert!(approx_vec2(
+            inverse_transform_point2_affine2(affine2, point),
+            Vector2::RIGHT
+        ));
+
+        let identity: Affine2 = affine2 * inverse_affine2(affine2);
+
+        assert!(approx_vec2(identity.translation, Vector2::ZERO));
+        assert!(approx_vec2(identity.matrix2.column_x, Vector2::RIGHT));
+        assert!(approx_vec2(identity.matrix2.column_y, Vector2::UP));
+    }
+}
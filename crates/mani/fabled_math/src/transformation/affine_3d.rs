@@ -6,6 +6,7 @@ use std::{
 };
 
 #[derive(Copy, Clone, Default, PartialEq)]
+#[repr(C)]
 pub struct Affine3 {
     pub translation: Vector3,
     pub matrix3: Matrix3x3,
@@ -73,9 +74,10 @@ impl Affine3 {
         let rotation_matrix: [f32; 9] = self.matrix3.to_primitive();
 
         [
-            rotation_matrix[0], rotation_matrix[1], rotation_matrix[2], translation[0],
-            rotation_matrix[3], rotation_matrix[4], rotation_matrix[5], translation[1],
-            rotation_matrix[6], rotation_matrix[7], rotation_matrix[8], translation[2],
+            rotation_matrix[0], rotation_matrix[1], rotation_matrix[2],
+            rotation_matrix[3], rotation_matrix[4], rotation_matrix[5],
+            rotation_matrix[6], rotation_matrix[7], rotation_matrix[8],
+            translation[0], translation[1], translation[2],
             ]
     }
 
@@ -101,7 +103,7 @@ impl Affine3 {
             column_x: Vector4{ value: self.matrix3.column_x.value },
             column_y: Vector4 { value: self.matrix3.column_y.value },
             column_z: Vector4 { value: self.matrix3.column_z.value },
-            column_w: Vector4 { value: self.translation.value },
+            column_w: Vector4::set(self.translation.x(), self.translation.y(), self.translation.z(), 1.0),
         }
     }
 
@@ -169,10 +171,11 @@ impl MulAssign for Affine3 {
 
 pub mod affine3_math {
 
-    use crate::{Affine3, Matrix3x3, Quaternion, Vector3};
+    use crate::{Affine3, DecomposedTransform, Matrix3x3, Quaternion, Vector3};
 
     use crate::matrix3x3_math::{
-        determinant_mat3, from_quat_mat3, inverse_mat3, rotate_x_mat3, rotate_y_mat3, rotate_z_mat3,
+        determinant_mat3, from_quat_mat3, inverse_basis_mat3, inverse_mat3, qr_decomposition_mat3,
+        rotate_x_mat3, rotate_y_mat3, rotate_z_mat3, transpose_mat3,
     };
     use crate::quaternion_math::{
         from_angle_axis_quat, from_euler_quat, from_rotation_matrix_quat,
@@ -372,4 +375,240 @@ pub mod affine3_math {
 
         (translation, rotation, scale)
     }
-}
\ No newline at end of file
+
+    // Shear aware counterpart of decompose_trs_affine3. The basis is factored
+    // into rotation * upper triangular through Gram-Schmidt, a negative
+    // determinant flips the x axis of both factors so the rotation stays proper.
+    #[inline]
+    pub fn decompose_affine3(affine3: Affine3) -> DecomposedTransform {
+        let (mut orthonormal, mut triangular): (Matrix3x3, Matrix3x3) =
+            qr_decomposition_mat3(affine3.matrix3);
+
+        let reflection: bool = determinant_mat3(orthonormal) < 0.0;
+
+        if std::intrinsics::unlikely(reflection) {
+            orthonormal.column_x = -orthonormal.column_x;
+
+            let flip_x: Vector3 = Vector3::set(-1.0, 1.0, 1.0);
+
+            triangular.column_x *= flip_x;
+            triangular.column_y *= flip_x;
+            triangular.column_z *= flip_x;
+        }
+
+        let scale: Vector3 = Vector3::set(
+            triangular.column_x.x(),
+            triangular.column_y.y(),
+            triangular.column_z.z(),
+        );
+
+        let safe_rcp = |value: f32| -> f32 {
+            if value != 0.0 {
+                value.recip()
+            } else {
+                0.0
+            }
+        };
+
+        let shear: Vector3 = Vector3::set(
+            triangular.column_y.x() * safe_rcp(scale.y()),
+            triangular.column_z.x() * safe_rcp(scale.z()),
+            triangular.column_z.y() * safe_rcp(scale.z()),
+        );
+
+        DecomposedTransform {
+            translation: affine3.translation,
+            rotation: from_rotation_matrix_quat(orthonormal),
+            scale,
+            shear,
+            reflection,
+        }
+    }
+
+    #[inline]
+    pub fn compose_affine3(decomposed: DecomposedTransform) -> Affine3 {
+        let shear: Vector3 = decomposed.shear;
+        let scale: Vector3 = decomposed.scale;
+
+        let shear_scale: Matrix3x3 = Matrix3x3::set(
+            Vector3::set(scale.x(), 0.0, 0.0),
+            Vector3::set(shear.x() * scale.y(), scale.y(), 0.0),
+            Vector3::set(shear.y() * scale.z(), shear.z() * scale.z(), scale.z()),
+        );
+
+        Affine3 {
+            translation: decomposed.translation,
+            matrix3: from_quat_mat3(decomposed.rotation) * shear_scale,
+        }
+    }
+
+    #[inline]
+    pub fn transform_point3_affine3(affine3: Affine3, point3: Vector3) -> Vector3 {
+        affine3.matrix3 * point3 + affine3.translation
+    }
+
+    #[inline]
+    pub fn transform_vector3_affine3(affine3: Affine3, vector3: Vector3) -> Vector3 {
+        affine3.matrix3 * vector3
+    }
+
+    // Rotation only, the direction keeps its length. The rotation is the
+    // orthonormal factor of the Gram-Schmidt decomposition, the same one
+    // decompose_affine3 reports, so scale and shear are both dropped. A
+    // reflected basis mirrors the direction.
+    #[inline]
+    pub fn transform_direction3_affine3(affine3: Affine3, direction3: Vector3) -> Vector3 {
+        let (orthonormal, _) = qr_decomposition_mat3(affine3.matrix3);
+
+        orthonormal * direction3
+    }
+
+    #[inline]
+    pub fn inverse_transform_point3_affine3(affine3: Affine3, point3: Vector3) -> Vector3 {
+        inverse_basis_mat3(affine3.matrix3) * (point3 - affine3.translation)
+    }
+
+    #[inline]
+    pub fn inverse_transform_vector3_affine3(affine3: Affine3, vector3: Vector3) -> Vector3 {
+        inverse_basis_mat3(affine3.matrix3) * vector3
+    }
+
+    #[inline]
+    pub fn inverse_transform_direction3_affine3(affine3: Affine3, direction3: Vector3) -> Vector3 {
+        let (orthonormal, _) = qr_decomposition_mat3(affine3.matrix3);
+
+        transpose_mat3(orthonormal) * direction3
+    }
+}
+
+#[cfg(test)]
+mod affine3_test {
+    use crate::approx::approx_vec3;
+    use crate::affine3_math::*;
+    use crate::matrix3x3_math::from_quat_mat3;
+    use crate::quaternion_math::from_angle_axis_quat;
+    use crate::vector_math::normalize;
+    use crate::{Affine3, DecomposedTransform, Matrix3x3, Quaternion, Vector3};
+
+    fn trs_affine3(translation: Vector3, scale: Vector3) -> Affine3 {
+        let axis: Vector3 = Vector3 {
+            value: normalize(Vector3::set(1.0, 2.0, -0.5).value),
+        };
+        let rotation: Matrix3x3 = from_quat_mat3(from_angle_axis_quat(axis, 1.2));
+
+        Affine3 {
+            translation,
+            matrix3: Matrix3x3::set(
+                rotation.column_x * scale.x(),
+                rotation.column_y * scale.y(),
+                rotation.column_z * scale.z(),
+            ),
+        }
+    }
+
+    #[test]
+    fn transform_round_trip() {
+        let point: Vector3 = Vector3::set(3.0, -1.0, 0.25);
+
+        for scale in [
+            Vector3::ONE,
+            Vector3::broadcast(2.5),
+            Vector3::set(0.5, 2.0, 3.0),
+        ] {
+            let affine3: Affine3 = trs_affine3(Vector3::set(-4.0, 1.0, 7.0), scale);
+
+            let world_point: Vector3 = transform_point3_affine3(affine3, point);
+            let world_vector: Vector3 = transform_vector3_affine3(affine3, point);
+            let world_direction: Vector3 = transform_direction3_affine3(affine3, point);
+
+            assert!(approx_vec3(
+                inverse_transform_point3_affine3(affine3, world_point),
+                point
+            ));
+            assert!(approx_vec3(
+                inverse_transform_vector3_affine3(affine3, world_vector),
+                point
+            ));
+            assert!(approx_vec3(
+                inverse_transform_direction3_affine3(affine3, world_direction),
+                point
+            ));
+        }
+    }
+
+    #[test]
+    fn transform_direction_ignores_scale() {
+        let direction: Vector3 = Vector3::set(0.0, 0.6, 0.8);
+
+        let unscaled: Affine3 = trs_affine3(Vector3::ZERO, Vector3::ONE);
+        let scaled: Affine3 = trs_affine3(Vector3::set(1.0, 2.0, 3.0), Vector3::set(4.0, 0.5, 2.0));
+
+        assert!(approx_vec3(
+            transform_direction3_affine3(scaled, direction),
+            transform_vector3_affine3(unscaled, direction)
+        ));
+    }
+
+    #[test]
+    fn transform_direction_ignores_shear() {
+        let axis: Vector3 = Vector3 {
+            value: normalize(Vector3::set(0.6, -1.0, 0.2).value),
+        };
+        let rotation: Quaternion = from_angle_axis_quat(axis, 2.1);
+
+        let sheared: Affine3 = compose_affine3(DecomposedTransform {
+            translation: Vector3::set(3.0, 0.0, -1.0),
+            rotation,
+            scale: Vector3::set(1.5, 0.5, 2.0),
+            shear: Vector3::set(0.9, -0.6, 1.3),
+            reflection: false,
+        });
+
+        let rotation_matrix: Matrix3x3 = from_quat_mat3(rotation);
+
+        for direction in [
+            Vector3::RIGHT,
+            Vector3::set(0.0, 0.6, 0.8),
+            Vector3::set(-0.48, 0.6, 0.64),
+        ] {
+            let world_direction: Vector3 = transform_direction3_affine3(sheared, direction);
+
+            assert!(approx_vec3(world_direction, rotation_matrix * direction));
+            assert!(approx_vec3(
+                inverse_transform_direction3_affine3(sheared, world_direction),
+                direction
+            ));
+        }
+    }
+
+    #[test]
+    fn decompose_shear_and_reflection() {
+        let axis: Vector3 = Vector3 {
+            value: normalize(Vector3::set(-0.3, 1.0, 0.7).value),
+        };
+
+        for scale in [Vector3::set(2.0, 0.5, 3.0), Vector3::set(-1.5, 1.0, 0.25)] {
+            let decomposed: DecomposedTransform = DecomposedTransform {
+                translation: Vector3::set(1.0, -2.0, 5.0),
+                rotation: from_angle_axis_quat(axis, 0.8),
+                scale,
+                shear: Vector3::set(0.4, -0.2, 0.7),
+                reflection: scale.x() < 0.0,
+            };
+
+            let affine3: Affine3 = compose_affine3(decomposed);
+            let recovered: DecomposedTransform = decompose_affine3(affine3);
+
+            assert_eq!(recovered.reflection, decomposed.reflection);
+            assert!(approx_vec3(recovered.scale, decomposed.scale));
+            assert!(approx_vec3(recovered.shear, decomposed.shear));
+            assert!(approx_vec3(recovered.translation, decomposed.translation));
+
+            let rebuilt: Affine3 = compose_affine3(recovered);
+
+            assert!(approx_vec3(rebuilt.matrix3.column_x, affine3.matrix3.column_x));
+            assert!(approx_vec3(rebuilt.matrix3.column_y, affine3.matrix3.column_y));
+            assert!(approx_vec3(rebuilt.matrix3.column_z, affine3.matrix3.column_z));
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{Quaternion, Vector3};
+
+use std::fmt::{Display, Formatter};
+
+// Translation, rotation, shear and scale, composed as
+// translation * rotation * shear * scale. Shear holds the xy, xz and yz
+// factors of the upper unit triangular matrix. A reflection is folded into
+// a negative x scale and flagged.
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecomposedTransform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+    pub shear: Vector3,
+    pub reflection: bool,
+}
+
+impl Default for DecomposedTransform {
+    fn default() -> Self {
+        DecomposedTransform::IDENTITY
+    }
+}
+
+impl DecomposedTransform {
+    pub const IDENTITY: DecomposedTransform = DecomposedTransform {
+        translation: Vector3::ZERO,
+        rotation: Quaternion::IDENTITY,
+        scale: Vector3::ONE,
+        shear: Vector3::ZERO,
+        reflection: false,
+    };
+}
+
+impl Display for DecomposedTransform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DecomposedTransform (translation : {}, rotation : {}, scale : {}, shear : {}, reflection : {})",
+            self.translation, self.rotation, self.scale, self.shear, self.reflection
+        )
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{DVector4, Matrix4x4};
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct DMatrix4x4 {
+    pub column_x: DVector4,
+    pub column_y: DVector4,
+    pub column_z: DVector4,
+    pub column_w: DVector4,
+}
+
+impl Default for DMatrix4x4 {
+    fn default() -> Self {
+        DMatrix4x4::IDENTITY
+    }
+}
+
+impl DMatrix4x4 {
+    pub const IDENTITY: DMatrix4x4 = DMatrix4x4 {
+        column_x: DVector4::set(1.0, 0.0, 0.0, 0.0),
+        column_y: DVector4::set(0.0, 1.0, 0.0, 0.0),
+        column_z: DVector4::set(0.0, 0.0, 1.0, 0.0),
+        column_w: DVector4::set(0.0, 0.0, 0.0, 1.0),
+    };
+
+    pub const ZERO: DMatrix4x4 = DMatrix4x4 {
+        column_x: DVector4::ZERO,
+        column_y: DVector4::ZERO,
+        column_z: DVector4::ZERO,
+        column_w: DVector4::ZERO,
+    };
+}
+
+impl DMatrix4x4 {
+    #[inline(always)]
+    pub const fn set(
+        column_x: DVector4,
+        column_y: DVector4,
+        column_z: DVector4,
+        column_w: DVector4,
+    ) -> DMatrix4x4 {
+        DMatrix4x4 {
+            column_x,
+            column_y,
+            column_z,
+            column_w,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: f64) -> DMatrix4x4 {
+        let splat_dvector4: DVector4 = DVector4::broadcast(val);
+
+        DMatrix4x4 {
+            column_x: splat_dvector4,
+            column_y: splat_dvector4,
+            column_z: splat_dvector4,
+            column_w: splat_dvector4,
+        }
+    }
+
+    #[rustfmt::skip]
+    #[inline]
+    pub const fn to_primitive(self) -> [f64; 16] {
+        let x_column: [f64; 4] = self.column_x.to_primitive();
+        let y_column: [f64; 4] = self.column_y.to_primitive();
+        let z_column: [f64; 4] = self.column_z.to_primitive();
+        let w_column: [f64; 4] = self.column_w.to_primitive();
+
+        [
+            x_column[0], x_column[1], x_column[2], x_column[3],
+            y_column[0], y_column[1], y_column[2], y_column[3],
+            z_column[0], z_column[1], z_column[2], z_column[3],
+            w_column[0], w_column[1], w_column[2], w_column[3],
+        ]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f64; 16]) -> DMatrix4x4 {
+        DMatrix4x4 {
+            column_x: DVector4::set(array[0], array[1], array[2], array[3]),
+            column_y: DVector4::set(array[4], array[5], array[6], array[7]),
+            column_z: DVector4::set(array[8], array[9], array[10], array[11]),
+            column_w: DVector4::set(array[12], array[13], array[14], array[15]),
+        }
+    }
+
+    #[inline]
+    pub fn as_mat4(self) -> Matrix4x4 {
+        Matrix4x4::set(
+            self.column_x.as_vec4(),
+            self.column_y.as_vec4(),
+            self.column_z.as_vec4(),
+            self.column_w.as_vec4(),
+        )
+    }
+}
+
+impl From<Matrix4x4> for DMatrix4x4 {
+    #[inline]
+    fn from(value: Matrix4x4) -> Self {
+        DMatrix4x4 {
+            column_x: DVector4::from(value.column_x),
+            column_y: DVector4::from(value.column_y),
+            column_z: DVector4::from(value.column_z),
+            column_w: DVector4::from(value.column_w),
+        }
+    }
+}
+
+impl Display for DMatrix4x4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DMatrix4x4\n[\n\t{}\n\t{}\n\t{}\n\t{}\n]",
+            self.column_x, self.column_y, self.column_z, self.column_w
+        )
+    }
+}
+
+impl Mul<DVector4> for DMatrix4x4 {
+    type Output = DVector4;
+
+    #[inline]
+    fn mul(self, rhs: DVector4) -> Self::Output {
+        self.column_x * rhs.x()
+            + self.column_y * rhs.y()
+            + self.column_z * rhs.z()
+            + self.column_w * rhs.w()
+    }
+}
+
+// Component-Wise
+impl Mul<f64> for DMatrix4x4 {
+    type Output = DMatrix4x4;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        DMatrix4x4 {
+            column_x: self.column_x * rhs,
+            column_y: self.column_y * rhs,
+            column_z: self.column_z * rhs,
+            column_w: self.column_w * rhs,
+        }
+    }
+}
+
+impl MulAssign<f64> for DMatrix4x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+// Matrix-Wise
+impl Mul for DMatrix4x4 {
+    type Output = DMatrix4x4;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        DMatrix4x4 {
+            column_x: self * rhs.column_x,
+            column_y: self * rhs.column_y,
+            column_z: self * rhs.column_z,
+            column_w: self * rhs.column_w,
+        }
+    }
+}
+
+impl MulAssign for DMatrix4x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Add for DMatrix4x4 {
+    type Output = DMatrix4x4;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        DMatrix4x4 {
+            column_x: self.column_x + rhs.column_x,
+            column_y: self.column_y + rhs.column_y,
+            column_z: self.column_z + rhs.column_z,
+            column_w: self.column_w + rhs.column_w,
+        }
+    }
+}
+
+impl AddAssign for DMatrix4x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for DMatrix4x4 {
+    type Output = DMatrix4x4;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        DMatrix4x4 {
+            column_x: self.column_x - rhs.column_x,
+            column_y: self.column_y - rhs.column_y,
+            column_z: self.column_z - rhs.column_z,
+            column_w: self.column_w - rhs.column_w,
+        }
+    }
+}
+
+impl SubAssign for DMatrix4x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+pub mod dmatrix4x4_math {
+    use crate::{DMatrix4x4, DQuaternion, DVector3, DVector4, Matrix4x4, Vector4};
+
+    #[inline]
+    pub const fn transpose_dmat4(matrix: DMatrix4x4) -> DMatrix4x4 {
+        let x: [f64; 4] = matrix.column_x.to_primitive();
+        let y: [f64; 4] = matrix.column_y.to_primitive();
+        let z: [f64; 4] = matrix.column_z.to_primitive();
+        let w: [f64; 4] = matrix.column_w.to_primitive();
+
+        DMatrix4x4::set(
+            DVector4::set(x[0], y[0], z[0], w[0]),
+            DVector4::set(x[1], y[1], z[1], w[1]),
+            DVector4::set(x[2], y[2], z[2], w[2]),
+            DVector4::set(x[3], y[3], z[3], w[3]),
+        )
+    }
+
+    #[inline]
+    pub const fn from_translation_dmat4(translation: DVector3) -> DMatrix4x4 {
+        DMatrix4x4::set(
+            DVector4::set(1.0, 0.0, 0.0, 0.0),
+            DVector4::set(0.0, 1.0, 0.0, 0.0),
+            DVector4::set(0.0, 0.0, 1.0, 0.0),
+            DVector4::set(translation.x(), translation.y(), translation.z(), 1.0),
+        )
+    }
+
+    pub fn from_trs_dmat4(
+        translation: DVector3,
+        rotation: DQuaternion,
+        scale: DVector3,
+    ) -> DMatrix4x4 {
+        let x2: f64 = rotation.i() + rotation.i();
+        let y2: f64 = rotation.j() + rotation.j();
+        let z2: f64 = rotation.k() + rotation.k();
+
+        let xx: f64 = rotation.i() * x2;
+        let xy: f64 = rotation.i() * y2;
+        let xz: f64 = rotation.i() * z2;
+        let yy: f64 = rotation.j() * y2;
+        let yz: f64 = rotation.j() * z2;
+        let zz: f64 = rotation.k() * z2;
+        let wx: f64 = rotation.w() * x2;
+        let wy: f64 = rotation.w() * y2;
+        let wz: f64 = rotation.w() * z2;
+
+        DMatrix4x4::set(
+            DVector4::set(1.0 - (yy + zz), xy + wz, xz - wy, 0.0) * scale.x(),
+            DVector4::set(xy - wz, 1.0 - (xx + zz), yz + wx, 0.0) * scale.y(),
+            DVector4::set(xz + wy, yz - wx, 1.0 - (xx + yy), 0.0) * scale.z(),
+            DVector4::set(translation.x(), translation.y(), translation.z(), 1.0),
+        )
+    }
+
+    pub fn inverse_dmat4(matrix: DMatrix4x4) -> DMatrix4x4 {
+        let m: [f64; 16] = matrix.to_primitive();
+
+        let s0: f64 = m[0] * m[5] - m[4] * m[1];
+        let s1: f64 = m[0] * m[6] - m[4] * m[2];
+        let s2: f64 = m[0] * m[7] - m[4] * m[3];
+        let s3: f64 = m[1] * m[6] - m[5] * m[2];
+        let s4: f64 = m[1] * m[7] - m[5] * m[3];
+        let s5: f64 = m[2] * m[7] - m[6] * m[3];
+
+        let c5: f64 = m[10] * m[15] - m[14] * m[11];
+        let c4: f64 = m[9] * m[15] - m[13] * m[11];
+        let c3: f64 = m[9] * m[14] - m[13] * m[10];
+        let c2: f64 = m[8] * m[15] - m[12] * m[11];
+        let c1: f64 = m[8] * m[14] - m[12] * m[10];
+        let c0: f64 = m[8] * m[13] - m[12] * m[9];
+
+        let rcp_determinant: f64 =
+            (s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0).recip();
+
+        DMatrix4x4::from_primitive([
+            (m[5] * c5 - m[6] * c4 + m[7] * c3) * rcp_determinant,
+            (-m[1] * c5 + m[2] * c4 - m[3] * c3) * rcp_determinant,
+            (m[13] * s5 - m[14] * s4 + m[15] * s3) * rcp_determinant,
+            (-m[9] * s5 + m[10] * s4 - m[11] * s3) * rcp_determinant,
+            (-m[4] * c5 + m[6] * c2 - m[7] * c1) * rcp_determinant,
+            (m[0] * c5 - m[2] * c2 + m[3] * c1) * rcp_determinant,
+            (-m[12] * s5 + m[14] * s2 - m[15] * s1) * rcp_determinant,
+            (m[8] * s5 - m[10] * s2 + m[11] * s1) * rcp_determinant,
+            (m[4] * c4 - m[5] * c2 + m[7] * c0) * rcp_determinant,
+            (-m[0] * c4 + m[1] * c2 - m[3] * c0) * rcp_determinant,
+            (m[12] * s4 - m[13] * s2 + m[15] * s0) * rcp_determinant,
+            (-m[8] * s4 + m[9] * s2 - m[11] * s0) * rcp_determinant,
+            (-m[4] * c3 + m[5] * c1 - m[6] * c0) * rcp_determinant,
+            (m[0] * c3 - m[1] * c1 + m[2] * c0) * rcp_determinant,
+            (-m[12] * s3 + m[13] * s1 - m[14] * s0) * rcp_determinant,
+            (m[8] * s3 - m[9] * s1 + m[10] * s0) * rcp_determinant,
+        ])
+    }
+
+    #[inline]
+    pub fn multiply_point3_dmat4(matrix: DMatrix4x4, point3: DVector3) -> DVector3 {
+        let x_axis: DVector3 = matrix.column_x.trunc_dvec3() * point3.x();
+        let y_axis: DVector3 = matrix.column_y.trunc_dvec3() * point3.y();
+        let z_axis: DVector3 = matrix.column_z.trunc_dvec3() * point3.z();
+
+        (x_axis + y_axis) + (z_axis + matrix.column_w.trunc_dvec3())
+    }
+
+    #[inline]
+    pub fn multiply_vector3_dmat4(matrix: DMatrix4x4, vector3: DVector3) -> DVector3 {
+        let x_axis: DVector3 = matrix.column_x.trunc_dvec3() * vector3.x();
+        let y_axis: DVector3 = matrix.column_y.trunc_dvec3() * vector3.y();
+        let z_axis: DVector3 = matrix.column_z.trunc_dvec3() * vector3.z();
+
+        (x_axis + y_axis) + z_axis
+    }
+
+    // Moves the translation relative to the camera in double precision before
+    // narrowing, so large world coordinates keep their precision near the camera.
+    #[inline]
+    pub fn to_camera_relative_mat4(matrix: DMatrix4x4, camera_position: DVector3) -> Matrix4x4 {
+        let relative_translation: DVector3 = matrix.column_w.trunc_dvec3() - camera_position;
+
+        Matrix4x4::set(
+            matrix.column_x.as_vec4(),
+            matrix.column_y.as_vec4(),
+            matrix.column_z.as_vec4(),
+            Vector4::set(
+                relative_translation.x() as f32,
+                relative_translation.y() as f32,
+                relative_translation.z() as f32,
+                matrix.column_w.w() as f32,
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod dmatrix4x4_test {
+    use crate::dmatrix4x4_math::*;
+    use crate::dquaternion_math::from_angle_axis_dquat;
+    use crate::{DMatrix4x4, DVector3, Vector3};
+
+    #[test]
+    fn inverse() {
+        let matrix: DMatrix4x4 = from_trs_dmat4(
+            DVector3::set(12.0, -3.5, 7.25),
+            from_angle_axis_dquat(DVector3::UP, 0.8),
+            DVector3::set(2.0, 0.5, 3.0),
+        );
+
+        let identity: [f64; 16] = (matrix * inverse_dmat4(matrix)).to_primitive();
+
+        for (value, expected) in identity.iter().zip(DMatrix4x4::IDENTITY.to_primitive()) {
+            assert!((value - expected).abs() <= 1e-12);
+        }
+    }
+
+    #[test]
+    fn camera_relative() {
+        let far_position: DVector3 = DVector3::set(40_000_000.25, 12.5, -90_000_000.75);
+        let camera_position: DVector3 = DVector3::set(40_000_000.0, 10.0, -90_000_000.0);
+
+        let relative =
+            to_camera_relative_mat4(from_translation_dmat4(far_position), camera_position);
+
+        let translation: Vector3 = relative.column_w.trunc_vec3();
+
+        assert_eq!(translation.to_primitive(), [0.25, 2.5, -0.75]);
+        assert_eq!(relative.column_w.w(), 1.0);
+    }
+}
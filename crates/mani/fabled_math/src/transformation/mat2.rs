@@ -0,0 +1,245 @@
+use crate::Vector2;
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Matrix2x2 {
+    pub column_x: Vector2,
+    pub column_y: Vector2,
+}
+
+impl Default for Matrix2x2 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Matrix2x2 {
+    pub const IDENTITY: Matrix2x2 = Matrix2x2 {
+        column_x: Vector2::RIGHT,
+        column_y: Vector2::UP,
+    };
+
+    pub const ZERO: Matrix2x2 = Matrix2x2 {
+        column_x: Vector2::ZERO,
+        column_y: Vector2::ZERO,
+    };
+}
+
+impl Matrix2x2 {
+    #[inline(always)]
+    pub const fn set(column_x: Vector2, column_y: Vector2) -> Matrix2x2 {
+        Matrix2x2 { column_x, column_y }
+    }
+
+    #[inline(always)]
+    pub const fn broadcast(val: f32) -> Matrix2x2 {
+        let splat_vector2: Vector2 = Vector2::broadcast(val);
+
+        Matrix2x2 {
+            column_x: splat_vector2,
+            column_y: splat_vector2,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn to_diagonal(self) -> Vector2 {
+        Vector2::set(self.column_x.x(), self.column_y.y())
+    }
+
+    #[inline]
+    pub const fn to_primitive(self) -> [f32; 4] {
+        [
+            self.column_x.x(),
+            self.column_x.y(),
+            self.column_y.x(),
+            self.column_y.y(),
+        ]
+    }
+
+    #[inline]
+    pub const fn from_primitive(array: [f32; 4]) -> Matrix2x2 {
+        Matrix2x2 {
+            column_x: Vector2::set(array[0], array[1]),
+            column_y: Vector2::set(array[2], array[3]),
+        }
+    }
+}
+
+impl Display for Matrix2x2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Matrix2x2\n[\n\t{}\n\t{}\n]",
+            self.column_x, self.column_y,
+        )
+    }
+}
+
+// Component-Wise
+impl Mul<f32> for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Matrix2x2 {
+            column_x: self.column_x * rhs,
+            column_y: self.column_y * rhs,
+        }
+    }
+}
+
+impl MulAssign<f32> for Matrix2x2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        self.column_x *= rhs;
+        self.column_y *= rhs;
+    }
+}
+
+// Matrix-Wise
+impl Mul for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Matrix2x2 {
+            column_x: self * rhs.column_x,
+            column_y: self * rhs.column_y,
+        }
+    }
+}
+
+impl MulAssign for Matrix2x2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Add for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Matrix2x2 {
+            column_x: self.column_x + rhs.column_x,
+            column_y: self.column_y + rhs.column_y,
+        }
+    }
+}
+
+impl AddAssign for Matrix2x2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.column_x += rhs.column_x;
+        self.column_y += rhs.column_y;
+    }
+}
+
+impl Sub for Matrix2x2 {
+    type Output = Matrix2x2;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Matrix2x2 {
+            column_x: self.column_x - rhs.column_x,
+            column_y: self.column_y - rhs.column_y,
+        }
+    }
+}
+
+impl SubAssign for Matrix2x2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.column_x -= rhs.column_x;
+        self.column_y -= rhs.column_y;
+    }
+}
+
+pub mod matrix2x2_math {
+    use crate::{Matrix2x2, Matrix3x3, Vector2, Vector3};
+
+    use crate::vector2_math::{cross, length};
+
+    #[inline]
+    pub const fn transpose_mat2(matrix_2x2: Matrix2x2) -> Matrix2x2 {
+        Matrix2x2 {
+            column_x: Vector2::set(matrix_2x2.column_x.x(), matrix_2x2.column_y.x()),
+            column_y: Vector2::set(matrix_2x2.column_x.y(), matrix_2x2.column_y.y()),
+        }
+    }
+
+    #[inline]
+    pub fn determinant_mat2(matrix_2x2: Matrix2x2) -> f32 {
+        cross(matrix_2x2.column_x, matrix_2x2.column_y)
+    }
+
+    #[inline]
+    pub fn inverse_mat2(matrix_2x2: Matrix2x2) -> Matrix2x2 {
+        let rcp_determinant: f32 = determinant_mat2(matrix_2x2).recip();
+
+        Matrix2x2 {
+            column_x: Vector2::set(matrix_2x2.column_y.y(), -matrix_2x2.column_x.y()),
+            column_y: Vector2::set(-matrix_2x2.column_y.x(), matrix_2x2.column_x.x()),
+        } * rcp_determinant
+    }
+
+    #[inline]
+    pub const fn from_scale_mat2(scale: Vector2) -> Matrix2x2 {
+        Matrix2x2::set(Vector2::set(scale.x(), 0.0), Vector2::set(0.0, scale.y()))
+    }
+
+    #[inline]
+    pub fn from_angle_mat2(angle_radian: f32) -> Matrix2x2 {
+        let (sin_angle, cos_angle) = angle_radian.sin_cos();
+
+        Matrix2x2::set(
+            Vector2::set(cos_angle, sin_angle),
+            Vector2::set(-sin_angle, cos_angle),
+        )
+    }
+
+    #[inline]
+    pub fn from_scale_angle_mat2(scale: Vector2, angle_radian: f32) -> Matrix2x2 {
+        let rotation: Matrix2x2 = from_angle_mat2(angle_radian);
+
+        Matrix2x2::set(rotation.column_x * scale.x(), rotation.column_y * scale.y())
+    }
+
+    // Returns (scale, angle), a reflection is reported as a negative x scale.
+    #[inline]
+    pub fn to_scale_angle_mat2(matrix_2x2: Matrix2x2) -> (Vector2, f32) {
+        let mut scale_x: f32 = length(matrix_2x2.column_x);
+        let scale_y: f32 = length(matrix_2x2.column_y);
+
+        if std::intrinsics::unlikely(determinant_mat2(matrix_2x2) < 0.0) {
+            scale_x = -scale_x;
+        }
+
+        let angle: f32 = (matrix_2x2.column_x.y() * scale_x.signum())
+            .atan2(matrix_2x2.column_x.x() * scale_x.signum());
+
+        (Vector2::set(scale_x, scale_y), angle)
+    }
+
+    #[inline]
+    pub const fn from_mat3_mat2(matrix_3x3: Matrix3x3) -> Matrix2x2 {
+        Matrix2x2::set(
+            Vector2::set(matrix_3x3.column_x.x(), matrix_3x3.column_x.y()),
+            Vector2::set(matrix_3x3.column_y.x(), matrix_3x3.column_y.y()),
+        )
+    }
+
+    #[inline]
+    pub const fn to_mat3_mat2(matrix_2x2: Matrix2x2) -> Matrix3x3 {
+        Matrix3x3::set(
+            Vector3::set(matrix_2x2.column_x.x(), matrix_2x2.column_x.y(), 0.0),
+            Vector3::set(matrix_2x2.column_y.x(), matrix_2x2.column_y.y(), 0.0),
+            Vector3::FORWARD,
+        )
+    }
+}
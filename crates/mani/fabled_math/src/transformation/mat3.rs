@@ -1,12 +1,11 @@
 use crate::{Matrix4x4, Vector3};
 
-use crate::matrix3x3_math::transpose_mat3;
-
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
 pub struct Matrix3x3 {
     pub column_x: Vector3,
     pub column_y: Vector3,
@@ -247,12 +246,10 @@ impl Mul for Matrix3x3 {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        let transposed_matrix: Matrix3x3 = transpose_mat3(rhs);
-
         Matrix3x3 {
-            column_x: self.column_x * transposed_matrix.column_x,
-            column_y: self.column_y * transposed_matrix.column_y,
-            column_z: self.column_z * transposed_matrix.column_z,
+            column_x: self * rhs.column_x,
+            column_y: self * rhs.column_y,
+            column_z: self * rhs.column_z,
         }
     }
 }
@@ -260,10 +257,7 @@ impl Mul for Matrix3x3 {
 impl MulAssign for Matrix3x3 {
     #[inline]
     fn mul_assign(&mut self, rhs: Self) {
-        let transposed_matrix: Matrix3x3 = transpose_mat3(rhs);
-        self.column_x *= transposed_matrix.column_x;
-        self.column_y *= transposed_matrix.column_y;
-        self.column_z *= transposed_matrix.column_z;
+        *self = *self * rhs;
     }
 }
 
@@ -271,7 +265,7 @@ pub mod matrix3x3_math {
     use crate::{EulerOrder, Matrix3x3, Quaternion, Vector2, Vector3, Vector4};
 
     use crate::quaternion_math::from_euler_quat;
-    use crate::vector_math::{cross, dot};
+    use crate::vector_math::{cross, dot, length, length_squared, normalize, orthogonal};
 
 
     use crate::math_trait::{QuaternionSwizzles, Swizzles3};
@@ -467,4 +461,397 @@ pub mod matrix3x3_math {
 
         adjudicate_matrix * inverse_determinant
     }
+
+    #[inline]
+    pub fn is_orthogonal_mat3(matrix: Matrix3x3, epsilon: f32) -> bool {
+        let x_sqr: f32 = length_squared(matrix.column_x.value);
+        let y_sqr: f32 = length_squared(matrix.column_y.value);
+        let z_sqr: f32 = length_squared(matrix.column_z.value);
+
+        let xy: f32 = dot(matrix.column_x.value, matrix.column_y.value);
+        let yz: f32 = dot(matrix.column_y.value, matrix.column_z.value);
+        let zx: f32 = dot(matrix.column_z.value, matrix.column_x.value);
+
+        let epsilon_sqr: f32 = epsilon * epsilon;
+
+        (xy * xy <= epsilon_sqr * x_sqr * y_sqr)
+            & (yz * yz <= epsilon_sqr * y_sqr * z_sqr)
+            & (zx * zx <= epsilon_sqr * z_sqr * x_sqr)
+    }
+
+    // Inverse of a rotation and scale basis. A basis with orthogonal columns
+    // (rigid, uniform or non-uniform scale without shear) is inverted by
+    // scaling its transpose, anything else falls back to the cofactor inverse.
+    #[inline]
+    pub fn inverse_basis_mat3(matrix: Matrix3x3) -> Matrix3x3 {
+        const ORTHOGONAL_EPSILON: f32 = 0.0001;
+
+        if std::intrinsics::likely(is_orthogonal_mat3(matrix, ORTHOGONAL_EPSILON)) {
+            let rcp_x_sqr: f32 = length_squared(matrix.column_x.value).recip();
+            let rcp_y_sqr: f32 = length_squared(matrix.column_y.value).recip();
+            let rcp_z_sqr: f32 = length_squared(matrix.column_z.value).recip();
+
+            transpose_mat3(Matrix3x3::set(
+                matrix.column_x * rcp_x_sqr,
+                matrix.column_y * rcp_y_sqr,
+                matrix.column_z * rcp_z_sqr,
+            ))
+        } else {
+            inverse_mat3(matrix)
+        }
+    }
+
+    #[inline]
+    pub fn normalize_basis_mat3(matrix: Matrix3x3) -> Matrix3x3 {
+        Matrix3x3::set(
+            matrix.column_x * length(matrix.column_x.value).recip(),
+            matrix.column_y * length(matrix.column_y.value).recip(),
+            matrix.column_z * length(matrix.column_z.value).recip(),
+        )
+    }
+
+    // Higham's iteration, averaging the matrix with its inverse transpose
+    // converges quadratically on the closest orthogonal matrix. The returned
+    // orthogonal factor keeps the sign of the determinant, so a reflected
+    // matrix gives a reflection rather than a rotation. matrix = orthogonal * stretch.
+    #[inline]
+    pub fn polar_decomposition_mat3(matrix: Matrix3x3) -> (Matrix3x3, Matrix3x3) {
+        const MAX_ITERATION: usize = 32;
+        const EPSILON: f32 = 1.0e-6;
+        const SINGULAR_EPSILON: f32 = 1.0e-6;
+
+        // A singular matrix has no inverse to iterate with. Its factors come
+        // from the svd instead, u * transpose(v) is then a rotation and the
+        // stretch carries the zero and any negative singular value.
+        let volume: f32 = length(matrix.column_x.value)
+            * length(matrix.column_y.value)
+            * length(matrix.column_z.value);
+
+        if std::intrinsics::unlikely(determinant_mat3(matrix).abs() <= volume * SINGULAR_EPSILON) {
+            let (u, sigma, v): (Matrix3x3, Vector3, Matrix3x3) = svd_mat3(matrix);
+
+            let stretch_basis: Matrix3x3 = Matrix3x3::set(
+                v.column_x * sigma.x(),
+                v.column_y * sigma.y(),
+                v.column_z * sigma.z(),
+            );
+
+            return (u * transpose_mat3(v), stretch_basis * transpose_mat3(v));
+        }
+
+        let mut orthogonal: Matrix3x3 = matrix;
+
+        for _ in 0..MAX_ITERATION {
+            let next: Matrix3x3 =
+                (orthogonal + transpose_mat3(inverse_mat3(orthogonal))) * 0.5;
+
+            let difference: Matrix3x3 = next - orthogonal;
+            orthogonal = next;
+
+            let error: f32 = length_squared(difference.column_x.value)
+                + length_squared(difference.column_y.value)
+                + length_squared(difference.column_z.value);
+
+            if error < EPSILON * EPSILON {
+                break;
+            }
+        }
+
+        let stretch: Matrix3x3 = transpose_mat3(orthogonal) * matrix;
+
+        (orthogonal, stretch)
+    }
+
+    // Modified Gram-Schmidt, matrix = q * r with r upper triangular. Degenerate
+    // columns get an arbitrary orthonormal direction and a zero diagonal in r,
+    // q is orthonormal but may be a reflection.
+    #[inline]
+    pub fn qr_decomposition_mat3(matrix: Matrix3x3) -> (Matrix3x3, Matrix3x3) {
+        const EPSILON: f32 = 1.0e-8;
+
+        let column_x: Vector3 = matrix.column_x;
+        let column_y: Vector3 = matrix.column_y;
+        let column_z: Vector3 = matrix.column_z;
+
+        let r_xx: f32 = length(column_x.value);
+
+        let q_x: Vector3 = if std::intrinsics::likely(r_xx > EPSILON) {
+            column_x * r_xx.recip()
+        } else {
+            Vector3::RIGHT
+        };
+
+        let r_xy: f32 = dot(q_x.value, column_y.value);
+        let residual_y: Vector3 = column_y - q_x * r_xy;
+        let r_yy: f32 = length(residual_y.value);
+
+        let q_y: Vector3 = if std::intrinsics::likely(r_yy > EPSILON) {
+            residual_y * r_yy.recip()
+        } else {
+            Vector3 {
+                value: normalize(orthogonal(q_x.value)),
+            }
+        };
+
+        let r_xz: f32 = dot(q_x.value, column_z.value);
+        let r_yz: f32 = dot(q_y.value, column_z.value);
+        let residual_z: Vector3 = column_z - q_x * r_xz - q_y * r_yz;
+        let r_zz: f32 = length(residual_z.value);
+
+        let q_z: Vector3 = if std::intrinsics::likely(r_zz > EPSILON) {
+            residual_z * r_zz.recip()
+        } else {
+            Vector3 {
+                value: cross(q_x.value, q_y.value),
+            }
+        };
+
+        (
+            Matrix3x3::set(q_x, q_y, q_z),
+            Matrix3x3::set(
+                Vector3::set(r_xx, 0.0, 0.0),
+                Vector3::set(r_xy, r_yy, 0.0),
+                Vector3::set(r_xz, r_yz, r_zz),
+            ),
+        )
+    }
+
+    // Cyclic Jacobi for a symmetric matrix. Eigenvalues are sorted from largest
+    // to smallest, the eigenvectors are the matching columns and form a rotation.
+    #[inline]
+    pub fn symmetric_eigen_mat3(matrix: Matrix3x3) -> (Vector3, Matrix3x3) {
+        const MAX_SWEEP: usize = 32;
+        const EPSILON: f32 = 1.0e-12;
+
+        // Column major, element (row, column) is at column * 3 + row.
+        let mut a: [f32; 9] = matrix.to_primitive();
+        let mut v: [f32; 9] = Matrix3x3::IDENTITY.to_primitive();
+
+        let norm: f32 = a.iter().map(|element| element * element).sum::<f32>();
+
+        for _ in 0..MAX_SWEEP {
+            let off_diagonal: f32 = a[3] * a[3] + a[6] * a[6] + a[7] * a[7];
+
+            if off_diagonal <= EPSILON * norm {
+                break;
+            }
+
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                let a_pq: f32 = a[q * 3 + p];
+
+                if a_pq == 0.0 {
+                    continue;
+                }
+
+                let theta: f32 = (a[q * 3 + q] - a[p * 3 + p]) / (2.0 * a_pq);
+                let t: f32 = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c: f32 = (t * t + 1.0).sqrt().recip();
+                let s: f32 = t * c;
+
+                for k in 0..3 {
+                    let a_kp: f32 = a[p * 3 + k];
+                    let a_kq: f32 = a[q * 3 + k];
+
+                    a[p * 3 + k] = c * a_kp - s * a_kq;
+                    a[q * 3 + k] = s * a_kp + c * a_kq;
+                }
+
+                for k in 0..3 {
+                    let a_pk: f32 = a[k * 3 + p];
+                    let a_qk: f32 = a[k * 3 + q];
+
+                    a[k * 3 + p] = c * a_pk - s * a_qk;
+                    a[k * 3 + q] = s * a_pk + c * a_qk;
+                }
+
+                for k in 0..3 {
+                    let v_kp: f32 = v[p * 3 + k];
+                    let v_kq: f32 = v[q * 3 + k];
+
+                    v[p * 3 + k] = c * v_kp - s * v_kq;
+                    v[q * 3 + k] = s * v_kp + c * v_kq;
+                }
+            }
+        }
+
+        let mut order: [usize; 3] = [0, 1, 2];
+        order.sort_unstable_by(|&lhs, &rhs| a[rhs * 4].total_cmp(&a[lhs * 4]));
+
+        let column = |index: usize| -> Vector3 {
+            Vector3::set(v[index * 3], v[index * 3 + 1], v[index * 3 + 2])
+        };
+
+        let eigenvalues: Vector3 =
+            Vector3::set(a[order[0] * 4], a[order[1] * 4], a[order[2] * 4]);
+
+        let mut eigenvectors: Matrix3x3 =
+            Matrix3x3::set(column(order[0]), column(order[1]), column(order[2]));
+
+        if determinant_mat3(eigenvectors) < 0.0 {
+            eigenvectors.column_z = -eigenvectors.column_z;
+        }
+
+        (eigenvalues, eigenvectors)
+    }
+
+    // Signed singular value decomposition, matrix = u * diag(sigma) * transpose(v).
+    // Both u and v are rotations, a reflection is carried by a negative last
+    // singular value instead.
+    #[inline]
+    pub fn svd_mat3(matrix: Matrix3x3) -> (Matrix3x3, Vector3, Matrix3x3) {
+        const EPSILON: f32 = 1.0e-6;
+
+        let (_, v): (Vector3, Matrix3x3) =
+            symmetric_eigen_mat3(transpose_mat3(matrix) * matrix);
+
+        let image_x: Vector3 = matrix * v.column_x;
+        let image_y: Vector3 = matrix * v.column_y;
+        let image_z: Vector3 = matrix * v.column_z;
+
+        let sigma_x: f32 = length(image_x.value);
+
+        let u_x: Vector3 = if std::intrinsics::likely(sigma_x > EPSILON) {
+            image_x * sigma_x.recip()
+        } else {
+            v.column_x
+        };
+
+        let residual_y: Vector3 = image_y - u_x * dot(u_x.value, image_y.value);
+        let residual_length: f32 = length(residual_y.value);
+
+        let u_y: Vector3 = if std::intrinsics::likely(residual_length > EPSILON) {
+            residual_y * residual_length.recip()
+        } else {
+            Vector3 {
+                value: normalize(orthogonal(u_x.value)),
+            }
+        };
+
+        let u_z: Vector3 = Vector3 {
+            value: cross(u_x.value, u_y.value),
+        };
+
+        let sigma: Vector3 = Vector3::set(
+            sigma_x,
+            dot(u_y.value, image_y.value),
+            dot(u_z.value, image_z.value),
+        );
+
+        (Matrix3x3::set(u_x, u_y, u_z), sigma, v)
+    }
+}
+
+
+#[cfg(test)]
+mod matrix3x3_test {
+    use crate::approx::{approx_mat3, DECOMPOSITION_THRESHOLD};
+    use crate::matrix3x3_math::*;
+    use crate::vector_math::normalize;
+    use crate::{Matrix3x3, Vector3};
+
+    fn diagonal(diagonal: Vector3) -> Matrix3x3 {
+        Matrix3x3::set(
+            Vector3::set(diagonal.x(), 0.0, 0.0),
+            Vector3::set(0.0, diagonal.y(), 0.0),
+            Vector3::set(0.0, 0.0, diagonal.z()),
+        )
+    }
+
+    fn sample_matrix() -> Matrix3x3 {
+        let axis: Vector3 = Vector3 {
+            value: normalize(Vector3::set(1.0, -2.0, 0.5).value),
+        };
+
+        from_angle_axis_mat3(axis, 0.9)
+            * Matrix3x3::set(
+                Vector3::set(2.0, 0.0, 0.0),
+                Vector3::set(0.6, 0.5, 0.0),
+                Vector3::set(-0.3, 0.2, 3.0),
+            )
+    }
+
+    #[test]
+    fn product() {
+        let lhs: Matrix3x3 = Matrix3x3::from_primitive([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0]);
+        let rhs: Matrix3x3 = Matrix3x3::from_primitive([2.0, 0.0, 1.0, 0.0, 1.0, 0.0, 3.0, 0.0, -1.0]);
+
+        let expected: Matrix3x3 =
+            Matrix3x3::from_primitive([9.0, 12.0, 16.0, 4.0, 5.0, 6.0, -4.0, -2.0, -1.0]);
+
+        assert!(approx_mat3(lhs * rhs, expected));
+        assert!(approx_mat3(lhs * inverse_mat3(lhs), Matrix3x3::IDENTITY));
+    }
+
+    #[test]
+    fn polar_and_qr() {
+        for matrix in [sample_matrix(), sample_matrix() * diagonal(Vector3::set(-1.0, 1.0, 1.0))] {
+            let (orthogonal, stretch): (Matrix3x3, Matrix3x3) = polar_decomposition_mat3(matrix);
+
+            assert!(approx_mat3(orthogonal * stretch, matrix));
+            assert!(approx_mat3(stretch, transpose_mat3(stretch)));
+            assert!(approx_mat3(orthogonal * transpose_mat3(orthogonal), Matrix3x3::IDENTITY));
+
+            let (q, r): (Matrix3x3, Matrix3x3) = qr_decomposition_mat3(matrix);
+
+            assert!(approx_mat3(q * r, matrix));
+            assert!(approx_mat3(q * transpose_mat3(q), Matrix3x3::IDENTITY));
+            assert!(
+                r.column_x.y().abs() + r.column_x.z().abs() + r.column_y.z().abs()
+                    <= DECOMPOSITION_THRESHOLD
+            );
+        }
+
+        for singular in [
+            Matrix3x3::ZERO,
+            sample_matrix() * diagonal(Vector3::set(1.0, 0.0, 2.0)),
+            Matrix3x3::set(Vector3::RIGHT, Vector3::RIGHT * 2.0, Vector3::UP),
+        ] {
+            let (orthogonal, stretch): (Matrix3x3, Matrix3x3) = polar_decomposition_mat3(singular);
+
+            assert!(orthogonal
+                .to_primitive()
+                .iter()
+                .all(|element| element.is_finite()));
+            assert!(approx_mat3(orthogonal * stretch, singular));
+            assert!(approx_mat3(stretch, transpose_mat3(stretch)));
+            assert!(approx_mat3(
+                orthogonal * transpose_mat3(orthogonal),
+                Matrix3x3::IDENTITY
+            ));
+        }
+    }
+
+    #[test]
+    fn symmetric_eigen() {
+        let matrix: Matrix3x3 = sample_matrix();
+        let symmetric: Matrix3x3 = transpose_mat3(matrix) * matrix;
+
+        let (eigenvalues, eigenvectors): (Vector3, Matrix3x3) = symmetric_eigen_mat3(symmetric);
+
+        assert!(eigenvalues.x() >= eigenvalues.y() && eigenvalues.y() >= eigenvalues.z());
+        assert!((determinant_mat3(eigenvectors) - 1.0).abs() <= DECOMPOSITION_THRESHOLD);
+        assert!(approx_mat3(
+            symmetric * eigenvectors,
+            eigenvectors * diagonal(eigenvalues)
+        ));
+    }
+
+    #[test]
+    fn singular_value_decomposition() {
+        for matrix in [
+            sample_matrix(),
+            sample_matrix() * diagonal(Vector3::set(1.0, -1.0, 1.0)),
+            diagonal(Vector3::set(2.0, 0.0, 1.0)),
+        ] {
+            let (u, sigma, v): (Matrix3x3, Vector3, Matrix3x3) = svd_mat3(matrix);
+
+            assert!((determinant_mat3(u) - 1.0).abs() <= DECOMPOSITION_THRESHOLD);
+            assert!((determinant_mat3(v) - 1.0).abs() <= DECOMPOSITION_THRESHOLD);
+            assert!(approx_mat3(u * diagonal(sigma) * transpose_mat3(v), matrix));
+            assert_eq!(
+                sigma.z() < 0.0,
+                determinant_mat3(matrix) < -DECOMPOSITION_THRESHOLD
+            );
+        }
+    }
 }
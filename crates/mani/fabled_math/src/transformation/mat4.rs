@@ -1,12 +1,11 @@
 use crate::{Affine3, Matrix3x3, Vector4};
 
-use crate::matrix4x4_math::transpose_mat4;
-
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
 use std::fmt::Display;
 
 #[derive(Copy, Clone, PartialEq)]
+#[repr(C)]
 pub struct Matrix4x4 {
     pub column_x: Vector4,
     pub column_y: Vector4,
@@ -87,7 +86,7 @@ impl Matrix4x4 {
     pub const fn to_primitive(self) -> [f32; 16] {
         let x_column = self.column_x;
         let y_column = self.column_y;
-        let z_column = self.column_y;
+        let z_column = self.column_z;
         let w_column = self.column_w;
 
         [
@@ -291,13 +290,11 @@ impl Mul for Matrix4x4 {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        let transposed_matrix: Matrix4x4 = transpose_mat4(rhs);
-
         Matrix4x4 {
-            column_x: self.column_x * transposed_matrix.column_x,
-            column_y: self.column_y * transposed_matrix.column_y,
-            column_z: self.column_z * transposed_matrix.column_z,
-            column_w: self.column_w * transposed_matrix.column_w,
+            column_x: self * rhs.column_x,
+            column_y: self * rhs.column_y,
+            column_z: self * rhs.column_z,
+            column_w: self * rhs.column_w,
         }
     }
 }
@@ -305,12 +302,7 @@ impl Mul for Matrix4x4 {
 impl MulAssign for Matrix4x4 {
     #[inline]
     fn mul_assign(&mut self, rhs: Self) {
-        let transposed_matrix: Matrix4x4 = transpose_mat4(rhs);
-
-        self.column_x *= transposed_matrix.column_x;
-        self.column_y *= transposed_matrix.column_y;
-        self.column_z *= transposed_matrix.column_z;
-        self.column_w *= transposed_matrix.column_w;
+        *self = *self * rhs;
     }
 }
 
@@ -320,27 +312,7 @@ impl Mul<Affine3> for Matrix4x4 {
 
     #[inline]
     fn mul(self, rhs: Affine3) -> Self::Output {
-        const VEC3_MASK: std::simd::f32x4 = std::simd::f32x4::from_array([1.0, 1.0, 1.0, 0.0]);
-
-        let column_x_extended = Vector4 {
-            value: rhs.matrix3.column_x.value * VEC3_MASK,
-        };
-        let column_y_extended = Vector4 {
-            value: rhs.matrix3.column_y.value * VEC3_MASK,
-        };
-        let column_z_extended = Vector4 {
-            value: rhs.matrix3.column_z.value * VEC3_MASK,
-        };
-        let column_w_extended = Vector4 {
-            value: rhs.translation.value,
-        };
-
-        Matrix4x4 {
-            column_x: column_x_extended,
-            column_y: column_y_extended,
-            column_z: column_z_extended,
-            column_w: column_w_extended,
-        }
+        self * rhs.to_mat4()
     }
 }
 
@@ -353,8 +325,12 @@ impl MulAssign<Affine3> for Matrix4x4 {
 
 pub mod matrix4x4_math {
 
-    use crate::{EulerOrder, Matrix4x4, Quaternion, Vector3, Vector4};
+    use crate::{Affine3, DecomposedTransform, EulerOrder, Matrix4x4, Quaternion, Vector3, Vector4};
 
+    use crate::affine3_math::{
+        compose_affine3, decompose_affine3, inverse_transform_direction3_affine3, inverse_transform_point3_affine3,
+        inverse_transform_vector3_affine3, transform_direction3_affine3,
+    };
     use crate::quaternion_math::{from_euler_quat, from_rotation_matrix_quat};
     use crate::vector_math::{dot, length, rcp};
 
@@ -538,17 +514,26 @@ pub mod matrix4x4_math {
     #[inline]
     pub fn compose_trs_mat4(translation : Vector3, rotation : Quaternion, scale : Vector3) -> Matrix4x4{
         let translation_vector = Vector4{ value: translation.value } + Vector4::W;
-        let scale_vector = Vector4::set(scale.x(), scale.y(), scale.z(), 0.0);
         let rotation_matrix = from_quat_mat4(rotation);
 
         Matrix4x4{
-            column_x: rotation_matrix.column_x * scale_vector,
-            column_y: rotation_matrix.column_y * scale_vector,
-            column_z: rotation_matrix.column_z * scale_vector,
+            column_x: rotation_matrix.column_x * scale.x(),
+            column_y: rotation_matrix.column_y * scale.y(),
+            column_z: rotation_matrix.column_z * scale.z(),
             column_w: translation_vector,
         }
     }
 
+    #[inline]
+    pub fn decompose_mat4(matrix: Matrix4x4) -> DecomposedTransform {
+        decompose_affine3(Affine3::from_mat4(matrix))
+    }
+
+    #[inline]
+    pub fn compose_mat4(decomposed: DecomposedTransform) -> Matrix4x4 {
+        compose_affine3(decomposed).to_mat4()
+    }
+
     #[inline]
     pub fn decompose_trs_mat4(matrix: Matrix4x4) -> (Vector3, Quaternion, Vector3) {
         let determinant = determinant_mat4(matrix);
@@ -789,7 +774,7 @@ pub mod matrix4x4_math {
         let y_axis = matrix.column_y.trunc_vec3() * point3.y();
         let z_axis = matrix.column_z.trunc_vec3() * point3.z();
 
-        let w_axis = matrix.column_w.trunc_vec3() + Vector3::RIGHT;
+        let w_axis = matrix.column_w.trunc_vec3();
 
         (x_axis + y_axis) + (z_axis + w_axis)
     }
@@ -816,5 +801,92 @@ pub mod matrix4x4_math {
         (result * Vector4::broadcast(result.w().recip())).trunc_vec3()
     }
 
+    #[inline]
+    pub fn is_affine_mat4(matrix: Matrix4x4) -> bool {
+        (matrix.column_x.w() == 0.0)
+            & (matrix.column_y.w() == 0.0)
+            & (matrix.column_z.w() == 0.0)
+            & (matrix.column_w.w() == 1.0)
+    }
+
+    #[inline]
+    pub fn transform_point3_mat4(matrix: Matrix4x4, point3: Vector3) -> Vector3 {
+        multiply_point3_mat4(matrix, point3)
+    }
+
+    #[inline]
+    pub fn transform_vector3_mat4(matrix: Matrix4x4, vector3: Vector3) -> Vector3 {
+        multiply_vector3_mat4(matrix, vector3)
+    }
+
+    #[inline]
+    pub fn transform_direction3_mat4(matrix: Matrix4x4, direction3: Vector3) -> Vector3 {
+        transform_direction3_affine3(Affine3::from_mat4(matrix), direction3)
+    }
+
+    #[inline]
+    pub fn inverse_transform_point3_mat4(matrix: Matrix4x4, point3: Vector3) -> Vector3 {
+        if std::intrinsics::unlikely(!is_affine_mat4(matrix)) {
+            return project_point3_mat4(inverse_mat4(matrix), point3);
+        }
+
+        inverse_transform_point3_affine3(Affine3::from_mat4(matrix), point3)
+    }
+
+    #[inline]
+    pub fn inverse_transform_vector3_mat4(matrix: Matrix4x4, vector3: Vector3) -> Vector3 {
+        inverse_transform_vector3_affine3(Affine3::from_mat4(matrix), vector3)
+    }
+
+    #[inline]
+    pub fn inverse_transform_direction3_mat4(matrix: Matrix4x4, direction3: Vector3) -> Vector3 {
+        inverse_transform_direction3_affine3(Affine3::from_mat4(matrix), direction3)
+    }
 }
 
+#[cfg(test)]
+mod matrix4x4_test {
+    use crate::{Affine3, Matrix3x3, Matrix4x4, Vector3, Vector4};
+
+    fn sequence() -> Matrix4x4 {
+        Matrix4x4::set(
+            Vector4::set(1.0, 2.0, 3.0, 4.0),
+            Vector4::set(5.0, 6.0, 7.0, 8.0),
+            Vector4::set(9.0, 10.0, 11.0, 12.0),
+            Vector4::set(13.0, 14.0, 15.0, 16.0),
+        )
+    }
+
+    #[test]
+    fn product() {
+        let rhs = Matrix4x4::set(
+            Vector4::set(2.0, 0.0, 1.0, 0.0),
+            Vector4::set(0.0, 1.0, 0.0, 3.0),
+            Vector4::set(1.0, 1.0, 1.0, 1.0),
+            Vector4::set(4.0, 0.0, 2.0, 1.0),
+        );
+
+        let expected = [
+            11.0, 14.0, 17.0, 20.0, 44.0, 48.0, 52.0, 56.0, 28.0, 32.0, 36.0, 40.0, 35.0, 42.0,
+            49.0, 56.0,
+        ];
+
+        assert_eq!((sequence() * rhs).to_primitive(), expected);
+
+        let mut assigned = sequence();
+        assigned *= rhs;
+
+        assert_eq!(assigned.to_primitive(), expected);
+
+        // The affine operand is widened with a homogeneous w of one.
+        let translation = Affine3 {
+            translation: Vector3::set(1.0, 2.0, 3.0),
+            matrix3: Matrix3x3::IDENTITY,
+        };
+
+        assert_eq!(
+            (sequence() * translation).column_w.to_primitive(),
+            [51.0, 58.0, 65.0, 72.0]
+        );
+    }
+}
@@ -1,8 +1,16 @@
+mod affine_2d;
 mod affine_3d;
+mod decomposed;
+mod dmat4;
+mod mat2;
 mod mat3;
 mod mat4;
 
+pub use affine_2d::*;
 pub use affine_3d::*;
+pub use decomposed::*;
+pub use dmat4::*;
+pub use mat2::*;
 pub use mat3::*;
 pub use mat4::*;
 
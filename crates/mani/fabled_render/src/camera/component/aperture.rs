@@ -7,6 +7,7 @@ use crate::camera::{inch_to_millimeter, meter_to_millimeter, MeasurementType};
 
 #[non_exhaustive]
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Aperture {
     pub aperture_x_mm: f32,
     pub aperture_y_mm: f32,
@@ -5,6 +5,7 @@ use std::fmt::{Display, Formatter};
 // Optional add component if camera is Perspective.
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AspectRatio {
     pub horizontal: f32,
     pub vertical: f32,
@@ -2,6 +2,7 @@ use fabled_component::{Component, Modification};
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClippingPlane {
     pub near: f32,
     pub far: f32,
@@ -15,6 +15,7 @@ use std::fmt::{Display, Formatter};
 
 #[non_exhaustive]
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FStop {
     pub f_stop: f32,
     pub step: i32,
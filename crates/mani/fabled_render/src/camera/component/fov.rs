@@ -6,6 +6,7 @@ use std::fmt::{Display, Formatter};
 // Optional add component if camera is Perspective.
 
 #[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FovAxis {
     Horizontal = 0,
     Vertical = 1,
@@ -29,6 +30,7 @@ impl Display for FovAxis {
 }
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fov {
     pub radian: f32,
     pub axis: FovAxis,
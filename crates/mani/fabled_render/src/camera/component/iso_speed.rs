@@ -10,6 +10,7 @@ use std::fmt::{Display, Formatter};
 // While having a low film speed number will have lower noise to the image.
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ISOSpeed {
     pub arithmetic_speed: f32,
 }
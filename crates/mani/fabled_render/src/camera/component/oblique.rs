@@ -8,6 +8,7 @@ use std::fmt::{Display, Formatter};
 // Optional add component if camera is Orthographic.
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Oblique {
     // angle_rad, vertical_position, depth_offset.
     pub value: Vector3,
@@ -11,6 +11,7 @@ use std::fmt::{Display, Formatter};
 // exposure on sensor depending on how slow the shutter speed is.
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shutter {
     pub speed_second: f32,
 }
@@ -4,6 +4,7 @@ use fabled_math::Vector4;
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ViewPort {
     pub rect: Vector4,
 }
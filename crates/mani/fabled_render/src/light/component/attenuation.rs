@@ -2,6 +2,7 @@ use fabled_component::{Component, Modification};
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FallOffAlgorithm {
     // Similar to unreal engine 4 falloff function
     // 1.0 / (x * x + 1.0)
@@ -4,10 +4,12 @@ use std::fmt::{Display, Formatter};
 
 // mesh object cast shadow if this is added
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShadowCaster;
 
 // mesh object will receive shadow if this is added.
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShadowReceiver;
 
 
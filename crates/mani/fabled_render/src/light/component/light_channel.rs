@@ -2,6 +2,7 @@ use fabled_component::{All, Component};
 use std::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Channel {
     Channel0,
     Channel1,
@@ -22,6 +23,7 @@ impl Display for Channel {
 // Mesh with channel0 will only get illuminated by light source that are in
 // channel0 and so on.
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightChannel {
     value: Channel,
 }
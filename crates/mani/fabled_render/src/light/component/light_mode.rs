@@ -9,6 +9,7 @@ use std::fmt::{Display, Formatter};
 // calculate attenuation dynamically in the shader.
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Mode {
     Baked,
     Stationary,
@@ -23,6 +24,7 @@ impl Display for Mode {
 
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightMode {
     pub mode: Mode,
 }
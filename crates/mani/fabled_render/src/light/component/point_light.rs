@@ -26,6 +26,7 @@ use crate::light::{
 // We will need to pass it as Luminance Intensity to the shader to calculate the
 // target illuminance.
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PointLight {
     pub intensity: f32,
     pub radius: f32,
@@ -15,6 +15,7 @@ use crate::light::{
 // We will need to pass it as Luminance Intensity to the shader to calculate the
 // target illuminance.
 #[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpotLight {
     pub value: Vector4,
 }
@@ -11,6 +11,7 @@ use std::fmt::{write, Display, Formatter};
 // Optional Parameters: Color (treated as tint), Temperature, Shadow Parameters.
 
 #[derive(Copy, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SunLight {
     pub illuminance: f32,
     pub angle_rad: f32,
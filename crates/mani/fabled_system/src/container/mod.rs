@@ -1,7 +1,9 @@
 mod camera;
 mod lighting;
+mod time;
 mod world_flag;
 
 pub use camera::*;
 pub use lighting::*;
+pub use time::*;
 pub use world_flag::*;
@@ -0,0 +1,42 @@
+use fabled_component::{Modification, Unique};
+
+// Written by the Scheduler at the start of every frame. delta and elapsed are
+// scaled and stop while paused, the unscaled values keep running.
+#[derive(Copy, Clone)]
+pub struct Time {
+    pub delta: f32,
+    pub unscaled_delta: f32,
+    pub fixed_delta: f32,
+    pub scale: f32,
+    pub paused: bool,
+    pub elapsed: f64,
+    pub unscaled_elapsed: f64,
+    // How far the frame is between the last two fixed steps, in [0, 1).
+    pub alpha: f32,
+    // Scaled time not yet consumed by a fixed step.
+    pub accumulator: f32,
+    pub frame: u64,
+    pub fixed_step: u64,
+}
+
+impl Default for Time {
+    fn default() -> Self {
+        Self {
+            delta: 0.0,
+            unscaled_delta: 0.0,
+            fixed_delta: 1.0 / 60.0,
+            scale: 1.0,
+            paused: false,
+            elapsed: 0.0,
+            unscaled_elapsed: 0.0,
+            alpha: 0.0,
+            accumulator: 0.0,
+            frame: 0,
+            fixed_step: 0,
+        }
+    }
+}
+
+impl Unique for Time {
+    type Tracking = Modification;
+}
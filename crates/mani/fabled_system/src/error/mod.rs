@@ -0,0 +1,11 @@
+mod prefab_error;
+mod scene_error;
+mod schedule_error;
+mod script_error;
+mod world_error;
+
+pub use prefab_error::*;
+pub use scene_error::*;
+pub use schedule_error::*;
+pub use script_error::*;
+pub use world_error::*;
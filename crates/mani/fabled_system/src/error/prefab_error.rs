@@ -0,0 +1,41 @@
+use crate::SceneError;
+
+use fabled_transform::HierarchyError;
+
+use thiserror::*;
+
+#[derive(Debug, Error)]
+pub enum PrefabError {
+    #[error("Prefab {0:?} is not in the library.")]
+    MissingPrefab(String),
+
+    #[error("Prefab {0:?} ends up nesting itself.")]
+    Cycle(String),
+
+    #[error("Prefab {prefab:?} has {count:?} roots, exactly one is expected.")]
+    Root { prefab: String, count: usize },
+
+    #[error("Entity {entity:?} of prefab {prefab:?} has a parent that is not in the prefab.")]
+    MissingParent { prefab: String, entity: u64 },
+
+    #[error("Entity {entity:?} of prefab {prefab:?} is used more than once.")]
+    DuplicateEntity { prefab: String, entity: u64 },
+
+    #[error("Entity {entity:?} of prefab {prefab:?} ends up parenting itself.")]
+    ParentCycle { prefab: String, entity: u64 },
+
+    #[error("Entity {0:?} is not alive.")]
+    MissingEntity(u64),
+
+    #[error("Entity {0:?} is not the root of a prefab instance.")]
+    MissingInstance(u64),
+
+    #[error("Could not read or write the prefab: {0}")]
+    Ron(#[from] ron::Error),
+
+    #[error(transparent)]
+    Scene(#[from] SceneError),
+
+    #[error(transparent)]
+    Hierarchy(#[from] HierarchyError),
+}
@@ -0,0 +1,25 @@
+use thiserror::*;
+
+#[derive(Debug, Error)]
+pub enum SceneError {
+    #[error("Could not read or write the json scene: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Could not read or write the ron scene: {0}")]
+    Ron(#[from] ron::Error),
+
+    #[error("Could not read or write the binary scene: {0}")]
+    Bincode(#[from] bincode::Error),
+
+    #[error("Scene version {found:?} is newer than the supported version {supported:?}.")]
+    Version { found: u32, supported: u32 },
+
+    #[error("Component {0:?} is not registered for serialization.")]
+    UnknownComponent(String),
+
+    #[error("Entity id {0:?} in the scene is not a valid entity id.")]
+    InvalidEntity(u64),
+
+    #[error("Component {0} holds a NaN or infinite float, which only binary scenes can store.")]
+    NonFinite(&'static str),
+}
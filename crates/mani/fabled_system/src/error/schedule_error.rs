@@ -0,0 +1,28 @@
+use crate::Stage;
+
+use thiserror::*;
+
+#[derive(Debug, Error)]
+pub enum ScheduleError {
+    #[error("System {system:?} is ordered against {other:?}, which is not in the {stage} stage.")]
+    UnknownSystem {
+        stage: Stage,
+        system: &'static str,
+        other: &'static str,
+    },
+
+    #[error("The ordering constraints of the {0} stage form a cycle.")]
+    Cycle(Stage),
+
+    #[error("System {0:?} is registered more than once.")]
+    Duplicate(&'static str),
+
+    #[error("The scheduler has not been built for this world.")]
+    NotBuilt,
+
+    #[error("Could not add {name:?} to the world: {message}")]
+    AddWorkload { name: &'static str, message: String },
+
+    #[error("Could not run {name:?}: {message}")]
+    RunWorkload { name: &'static str, message: String },
+}
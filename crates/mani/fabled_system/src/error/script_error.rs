@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use thiserror::*;
+
+#[derive(Debug, Error)]
+pub enum ScriptError {
+    #[error("Could not read script {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Script {path:?} failed: {source}")]
+    Script { path: PathBuf, source: mlua::Error },
+
+    #[error(transparent)]
+    Lua(#[from] mlua::Error),
+}
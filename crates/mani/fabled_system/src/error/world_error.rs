@@ -0,0 +1,16 @@
+use thiserror::*;
+
+#[derive(Debug, Error)]
+pub enum WorldError {
+    #[error("World {0:?} is not in the registry.")]
+    MissingWorld(usize),
+
+    #[error("Entity {0:?} is not alive in the source world.")]
+    MissingEntity(u64),
+
+    #[error("Could not add the workload to world {world:?}: {message}")]
+    AddWorkload { world: usize, message: String },
+
+    #[error("Could not run the workload on world {world:?}: {message}")]
+    RunWorkload { world: usize, message: String },
+}
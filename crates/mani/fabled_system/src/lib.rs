@@ -1,9 +1,15 @@
 mod container;
+mod error;
+mod scene;
+mod schedule;
 mod startup;
 mod subsystem;
 mod system;
 
 pub use container::*;
+pub use error::*;
+pub use scene::*;
+pub use schedule::*;
 pub use startup::*;
 pub use subsystem::*;
 pub use system::*;
@@ -0,0 +1,297 @@
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+use std::fmt::Display;
+
+// serde_json writes NaN and infinity as null, which then fails to load back
+// into the float. Walks the value the way a serializer would and stops at the
+// first float that is not finite, every other value is accepted.
+pub(crate) fn is_finite<T: Serialize + ?Sized>(value: &T) -> bool {
+    value.serialize(FiniteCheck).is_ok()
+}
+
+#[derive(Debug)]
+struct NonFinite;
+
+impl Display for NonFinite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NaN or infinite float")
+    }
+}
+
+impl std::error::Error for NonFinite {}
+
+// Errors raised by the value's own Serialize impl are not this check's
+// concern, they surface again when the value is actually encoded.
+impl ser::Error for NonFinite {
+    fn custom<M: Display>(_: M) -> Self {
+        NonFinite
+    }
+}
+
+struct FiniteCheck;
+
+impl Serializer for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, _: bool) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<(), NonFinite> {
+        if value.is_finite() {
+            Ok(())
+        } else {
+            Err(NonFinite)
+        }
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<(), NonFinite> {
+        if value.is_finite() {
+            Ok(())
+        } else {
+            Err(NonFinite)
+        }
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_str(self, _: &str) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), NonFinite> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), NonFinite> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), NonFinite> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), NonFinite> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+}
+
+impl SerializeSeq for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), NonFinite> {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl SerializeTuple for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), NonFinite> {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleStruct for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), NonFinite> {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl SerializeTupleVariant for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), NonFinite> {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl SerializeMap for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), NonFinite> {
+        key.serialize(FiniteCheck)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), NonFinite> {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl SerializeStruct for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), NonFinite> {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl SerializeStructVariant for FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), NonFinite> {
+        value.serialize(FiniteCheck)
+    }
+
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
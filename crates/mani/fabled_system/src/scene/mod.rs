@@ -0,0 +1,10 @@
+mod finite_check;
+mod prefab_format;
+mod prefab_library;
+mod scene_format;
+mod scene_serializer;
+
+pub use prefab_format::*;
+pub use prefab_library::*;
+pub use scene_format::*;
+pub use scene_serializer::*;
@@ -0,0 +1,92 @@
+use fabled_component::{All, Component};
+
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+pub const PREFAB_VERSION: u32 = 1;
+
+// Ids are local to the prefab file. The hierarchy is stored as parent ids
+// instead of Parent and Children components, exactly one entity has none and
+// becomes the root of every instance.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PrefabEntity {
+    pub id: u64,
+    pub parent: Option<u64>,
+    pub components: BTreeMap<String, serde_json::Value>,
+}
+
+// Another prefab instanced under one of the entities of this prefab. Its
+// entities are addressed by a key starting with id, so nested ids never
+// collide with the ids of the outer prefab.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NestedPrefab {
+    pub id: u64,
+    pub prefab: String,
+    pub parent: u64,
+    pub overrides: Vec<PrefabOverride>,
+}
+
+// Replaces, or adds, one component of one entity of an instance. The key is
+// the local id of the entity, prefixed by the nested prefab ids leading to it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PrefabOverride {
+    pub key: Vec<u64>,
+    pub component: String,
+    pub value: serde_json::Value,
+}
+
+// The on disk prefab asset, always written as ron.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Prefab {
+    pub version: u32,
+    pub entities: Vec<PrefabEntity>,
+    pub nested: Vec<NestedPrefab>,
+}
+
+impl Default for Prefab {
+    fn default() -> Self {
+        Self {
+            version: PREFAB_VERSION,
+            entities: Vec::new(),
+            nested: Vec::new(),
+        }
+    }
+}
+
+// Added to the root of a spawned prefab. Saved with scenes so a loaded
+// instance can still diff against and apply back to its prefab.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrefabInstance {
+    pub prefab: String,
+}
+
+impl Display for PrefabInstance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PrefabInstance({})", self.prefab)
+    }
+}
+
+impl Component for PrefabInstance {
+    type Tracking = All;
+}
+
+// Added to every spawned entity, nested ones included, linking it back to the
+// root of its outermost instance and its key inside that prefab. The instance
+// is remapped like Parent when entities are loaded or copied.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrefabLink {
+    pub instance: u64,
+    pub key: Vec<u64>,
+}
+
+impl Display for PrefabLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PrefabLink({}, {:?})", self.instance, self.key)
+    }
+}
+
+impl Component for PrefabLink {
+    type Tracking = All;
+}
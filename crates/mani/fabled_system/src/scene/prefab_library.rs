@@ -0,0 +1,977 @@
+use crate::{
+    collect_scene, delete_entities, insert_components, ComponentRegistry, Prefab, PrefabEntity,
+    PrefabError, PrefabInstance, PrefabLink, PrefabOverride, SceneError, PREFAB_VERSION,
+};
+
+use fabled_transform::{
+    Children, Hierarchy, HierarchyViewMut, LocalToWorld, Parent, Rotation, Scale, Translation,
+};
+
+use ron::ser::PrettyConfig;
+
+use shipyard::{EntitiesView, EntitiesViewMut, EntityId, Get, IntoIter, IntoWithId, View, ViewMut};
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+// Part of the prefab layout, or added by spawn, rather than of its components.
+const LAYOUT_COMPONENTS: [&str; 4] = ["Parent", "Children", "PrefabInstance", "PrefabLink"];
+
+const TRANSFORM_COMPONENTS: [&str; 3] = ["Translation", "Rotation", "Scale"];
+
+// Recomputed on every update, an instance always differs from its prefab there.
+const DERIVED_COMPONENTS: [&str; 1] = ["LocalToWorld"];
+
+// A prefab with its nested prefabs expanded, root first.
+struct FlatEntity {
+    key: Vec<u64>,
+    parent: Option<Vec<u64>>,
+    components: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Default)]
+pub struct PrefabLibrary {
+    prefabs: HashMap<String, Prefab>,
+}
+
+impl PrefabLibrary {
+    pub fn insert<S: Into<String>>(&mut self, name: S, prefab: Prefab) -> Option<Prefab> {
+        self.prefabs.insert(name.into(), prefab)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Prefab> {
+        self.prefabs.get(name)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Prefab> {
+        self.prefabs.remove(name)
+    }
+
+    pub fn load<S: Into<String>>(&mut self, name: S, bytes: &[u8]) -> Result<(), PrefabError> {
+        let prefab: Prefab = ron::de::from_bytes(bytes)?;
+
+        if prefab.version > PREFAB_VERSION {
+            return Err(SceneError::Version {
+                found: prefab.version,
+                supported: PREFAB_VERSION,
+            }
+            .into());
+        }
+
+        self.prefabs.insert(name.into(), prefab);
+
+        Ok(())
+    }
+
+    pub fn save(&self, name: &str) -> Result<Vec<u8>, PrefabError> {
+        let prefab = self.get_prefab(name)?;
+
+        Ok(ron::ser::to_string_pretty(prefab, PrettyConfig::default())?.into_bytes())
+    }
+
+    // Captures root and its descendants as a new prefab. Prefab instances
+    // found under root are captured as plain entities.
+    pub fn create<S: Into<String>>(
+        &mut self,
+        name: S,
+        world: &shipyard::World,
+        components: &ComponentRegistry,
+        root: u64,
+    ) -> Result<(), PrefabError> {
+        let root_id = alive_entity(world, root)?;
+
+        // Breadth first, the local ids follow that order. Entities already
+        // visited are skipped so a corrupt hierarchy can not loop forever.
+        let order: Vec<EntityId> = world.run(|children_storage: View<Children>| {
+            let mut order = vec![root_id];
+            let mut visited: HashSet<u64> = std::iter::once(root).collect();
+            let mut index = 0;
+
+            while index < order.len() {
+                if let Ok(children) = children_storage.get(order[index]) {
+                    order.extend(
+                        children
+                            .value
+                            .iter()
+                            .filter(|child| visited.insert(**child))
+                            .filter_map(|child| EntityId::from_inner(*child)),
+                    );
+                }
+
+                index += 1;
+            }
+
+            order
+        });
+
+        let local: HashMap<u64, u64> = order
+            .iter()
+            .enumerate()
+            .map(|(index, entity_id)| (entity_id.inner(), index as u64))
+            .collect();
+
+        let mut live: HashMap<u64, BTreeMap<String, serde_json::Value>> =
+            collect_scene(world, components, |serde| serde.save_text)?
+                .entities
+                .into_iter()
+                .map(|entity| (entity.id, entity.components))
+                .collect();
+
+        let entities: Vec<PrefabEntity> = world.run(|parent_storage: View<Parent>| {
+            order
+                .iter()
+                .enumerate()
+                .map(|(index, entity_id)| PrefabEntity {
+                    id: index as u64,
+                    parent: match index {
+                        0 => None,
+                        _ => parent_storage
+                            .get(*entity_id)
+                            .ok()
+                            .and_then(|parent| local.get(&parent.value).copied()),
+                    },
+                    components: live
+                        .remove(&entity_id.inner())
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|(component, _)| {
+                            !LAYOUT_COMPONENTS.contains(&component.as_str())
+                                && !DERIVED_COMPONENTS.contains(&component.as_str())
+                        })
+                        .collect(),
+                })
+                .collect()
+        });
+
+        self.prefabs.insert(
+            name.into(),
+            Prefab {
+                entities,
+                ..Prefab::default()
+            },
+        );
+
+        Ok(())
+    }
+
+    // Spawns the prefab with the instance overrides applied on top of the
+    // nested ones and returns the root, attached under parent when given.
+    pub fn spawn(
+        &self,
+        world: &shipyard::World,
+        components: &ComponentRegistry,
+        name: &str,
+        parent: Option<u64>,
+        overrides: &[PrefabOverride],
+    ) -> Result<u64, PrefabError> {
+        let parent_id = match parent {
+            Some(parent) => Some(alive_entity(world, parent)?),
+            None => None,
+        };
+
+        let mut flat = self.resolve(components, name, overrides)?;
+
+        let spawned: Vec<EntityId> = world.run(|mut entities_view: EntitiesViewMut| {
+            flat.iter()
+                .map(|_| entities_view.add_entity((), ()))
+                .collect()
+        });
+
+        let keyed: HashMap<Vec<u64>, EntityId> = flat
+            .iter()
+            .zip(&spawned)
+            .map(|(entity, entity_id)| (entity.key.clone(), *entity_id))
+            .collect();
+
+        let inserted = insert_components(
+            world,
+            components,
+            spawned
+                .iter()
+                .copied()
+                .zip(
+                    flat.iter_mut()
+                        .map(|entity| std::mem::take(&mut entity.components)),
+                )
+                .collect(),
+            |serde| serde.load_text,
+        );
+
+        if let Err(error) = inserted {
+            delete_entities(world, spawned);
+
+            return Err(error.into());
+        }
+
+        add_local_to_world(world, &spawned);
+
+        let root = spawned[0];
+
+        let attached = world.run(
+            |mut hierarchy: HierarchyViewMut| -> Result<(), PrefabError> {
+                for (entity, entity_id) in flat.iter().zip(&spawned) {
+                    if let Some(parent) = &entity.parent {
+                        hierarchy.attach(*entity_id, keyed[parent])?;
+                    }
+                }
+
+                if let Some(parent_id) = parent_id {
+                    hierarchy.attach(root, parent_id)?;
+                }
+
+                Ok(())
+            },
+        );
+
+        if let Err(error) = attached {
+            delete_entities(world, spawned);
+
+            return Err(error);
+        }
+
+        world.run(
+            |mut instance_storage: ViewMut<PrefabInstance>,
+             mut link_storage: ViewMut<PrefabLink>| {
+                instance_storage.add_component_unchecked(
+                    root,
+                    PrefabInstance {
+                        prefab: name.to_string(),
+                    },
+                );
+
+                for (entity, entity_id) in flat.iter().zip(&spawned) {
+                    link_storage.add_component_unchecked(
+                        *entity_id,
+                        PrefabLink {
+                            instance: root.inner(),
+                            key: entity.key.clone(),
+                        },
+                    );
+                }
+            },
+        );
+
+        Ok(root.inner())
+    }
+
+    // Components of the instance that differ from its prefab, in prefab
+    // order. Entities deleted from or added to the instance and removed
+    // components are not tracked.
+    pub fn overrides(
+        &self,
+        world: &shipyard::World,
+        components: &ComponentRegistry,
+        root: u64,
+    ) -> Result<Vec<PrefabOverride>, PrefabError> {
+        let name = instance_prefab(world, root)?;
+        let flat = self.resolve(components, &name, &[])?;
+
+        let linked: HashMap<Vec<u64>, u64> = world.run(|link_storage: View<PrefabLink>| {
+            link_storage
+                .iter()
+                .with_id()
+                .filter(|(_, link)| link.instance == root)
+                .map(|(entity_id, link)| (link.key.clone(), entity_id.inner()))
+                .collect()
+        });
+
+        let mut live: HashMap<u64, BTreeMap<String, serde_json::Value>> =
+            collect_scene(world, components, |serde| serde.save_text)?
+                .entities
+                .into_iter()
+                .map(|entity| (entity.id, entity.components))
+                .collect();
+
+        let mut overrides = Vec::new();
+
+        for entity in flat {
+            let entity_components = match linked.get(&entity.key).and_then(|id| live.remove(id)) {
+                Some(entity_components) => entity_components,
+                None => continue,
+            };
+
+            for (component, value) in entity_components {
+                if LAYOUT_COMPONENTS.contains(&component.as_str())
+                    || DERIVED_COMPONENTS.contains(&component.as_str())
+                {
+                    continue;
+                }
+
+                if entity.components.get(&component) != Some(&value) {
+                    overrides.push(PrefabOverride {
+                        key: entity.key.clone(),
+                        component,
+                        value,
+                    });
+                }
+            }
+        }
+
+        Ok(overrides)
+    }
+
+    // Writes the overrides of the instance back into its prefab. Overrides of
+    // nested entities land on the nested entry, the nested prefab itself is
+    // left alone. Other live instances pick the change up when spawned again.
+    pub fn apply_overrides(
+        &mut self,
+        world: &shipyard::World,
+        components: &ComponentRegistry,
+        root: u64,
+    ) -> Result<(), PrefabError> {
+        let name = instance_prefab(world, root)?;
+        let overrides = self.overrides(world, components, root)?;
+
+        let prefab = self
+            .prefabs
+            .get_mut(&name)
+            .ok_or(PrefabError::MissingPrefab(name))?;
+
+        for prefab_override in overrides {
+            match prefab_override.key.as_slice() {
+                [id] => {
+                    if let Some(entity) = prefab.entities.iter_mut().find(|entity| entity.id == *id)
+                    {
+                        entity
+                            .components
+                            .insert(prefab_override.component, prefab_override.value);
+                    }
+                }
+                [id, nested_key @ ..] => {
+                    if let Some(nested) = prefab.nested.iter_mut().find(|nested| nested.id == *id) {
+                        let nested_override = PrefabOverride {
+                            key: nested_key.to_vec(),
+                            component: prefab_override.component,
+                            value: prefab_override.value,
+                        };
+
+                        match nested.overrides.iter_mut().find(|existing| {
+                            existing.key == nested_override.key
+                                && existing.component == nested_override.component
+                        }) {
+                            Some(existing) => *existing = nested_override,
+                            None => nested.overrides.push(nested_override),
+                        }
+                    }
+                }
+                [] => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_prefab(&self, name: &str) -> Result<&Prefab, PrefabError> {
+        self.prefabs
+            .get(name)
+            .ok_or_else(|| PrefabError::MissingPrefab(name.to_string()))
+    }
+
+    // Flattens the prefab, applies the instance overrides, then normalizes
+    // every component through its registered type.
+    fn resolve(
+        &self,
+        components: &ComponentRegistry,
+        name: &str,
+        overrides: &[PrefabOverride],
+    ) -> Result<Vec<FlatEntity>, PrefabError> {
+        let mut flat = self.flatten(name, &mut Vec::new())?;
+
+        override_entities(&mut flat, overrides);
+
+        for entity in &mut flat {
+            for component in LAYOUT_COMPONENTS {
+                entity.components.remove(component);
+            }
+
+            complete_transform(&mut entity.components)?;
+
+            for (component, value) in entity.components.iter_mut() {
+                let serde = components
+                    .find(component)
+                    .and_then(|entry| entry.serde.as_ref())
+                    .ok_or_else(|| SceneError::UnknownComponent(component.clone()))?;
+
+                *value = (serde.normalize_text)(value)?;
+            }
+        }
+
+        Ok(flat)
+    }
+
+    fn flatten(&self, name: &str, stack: &mut Vec<String>) -> Result<Vec<FlatEntity>, PrefabError> {
+        if stack.iter().any(|visited| visited == name) {
+            return Err(PrefabError::Cycle(name.to_string()));
+        }
+
+        let prefab = self.get_prefab(name)?;
+
+        let roots = prefab
+            .entities
+            .iter()
+            .filter(|entity| entity.parent.is_none())
+            .count();
+
+        if roots != 1 {
+            return Err(PrefabError::Root {
+                prefab: name.to_string(),
+                count: roots,
+            });
+        }
+
+        let mut ids = HashSet::new();
+
+        let duplicate = prefab
+            .entities
+            .iter()
+            .map(|entity| entity.id)
+            .chain(prefab.nested.iter().map(|nested| nested.id))
+            .find(|id| !ids.insert(*id));
+
+        if let Some(entity) = duplicate {
+            return Err(PrefabError::DuplicateEntity {
+                prefab: name.to_string(),
+                entity,
+            });
+        }
+
+        let contains = |id: u64| prefab.entities.iter().any(|entity| entity.id == id);
+
+        let orphan = prefab
+            .entities
+            .iter()
+            .filter_map(|entity| entity.parent.map(|parent| (entity.id, parent)))
+            .chain(
+                prefab
+                    .nested
+                    .iter()
+                    .map(|nested| (nested.id, nested.parent)),
+            )
+            .find(|(_, parent)| !contains(*parent));
+
+        if let Some((entity, _)) = orphan {
+            return Err(PrefabError::MissingParent {
+                prefab: name.to_string(),
+                entity,
+            });
+        }
+
+        // With a single root and every parent present, an entity is only cut
+        // off from the root by a parent cycle.
+        let parents: HashMap<u64, u64> = prefab
+            .entities
+            .iter()
+            .filter_map(|entity| entity.parent.map(|parent| (entity.id, parent)))
+            .collect();
+
+        let cyclic = prefab.entities.iter().find(|entity| {
+            let mut current = entity.id;
+
+            for _ in 0..prefab.entities.len() {
+                match parents.get(&current) {
+                    Some(parent) => current = *parent,
+                    None => return false,
+                }
+            }
+
+            true
+        });
+
+        if let Some(entity) = cyclic {
+            return Err(PrefabError::ParentCycle {
+                prefab: name.to_string(),
+                entity: entity.id,
+            });
+        }
+
+        let mut flat: Vec<FlatEntity> = prefab
+            .entities
+            .iter()
+            .filter(|entity| entity.parent.is_none())
+            .chain(
+                prefab
+                    .entities
+                    .iter()
+                    .filter(|entity| entity.parent.is_some()),
+            )
+            .map(|entity| FlatEntity {
+                key: vec![entity.id],
+                parent: entity.parent.map(|parent| vec![parent]),
+                components: entity.components.clone(),
+            })
+            .collect();
+
+        stack.push(name.to_string());
+
+        for nested in &prefab.nested {
+            let mut nested_flat = self.flatten(&nested.prefab, stack)?;
+
+            override_entities(&mut nested_flat, &nested.overrides);
+
+            for entity in nested_flat {
+                let parent = match entity.parent {
+                    Some(parent) => prefixed(nested.id, &parent),
+                    None => vec![nested.parent],
+                };
+
+                flat.push(FlatEntity {
+                    key: prefixed(nested.id, &entity.key),
+                    parent: Some(parent),
+                    components: entity.components,
+                });
+            }
+        }
+
+        stack.pop();
+
+        Ok(flat)
+    }
+}
+
+// Overrides whose entity is no longer in the prefab are ignored.
+fn override_entities(flat: &mut [FlatEntity], overrides: &[PrefabOverride]) {
+    for prefab_override in overrides {
+        if let Some(entity) = flat
+            .iter_mut()
+            .find(|entity| entity.key == prefab_override.key)
+        {
+            entity.components.insert(
+                prefab_override.component.clone(),
+                prefab_override.value.clone(),
+            );
+        }
+    }
+}
+
+// Hand written prefabs may store only part of the transform. The transform
+// systems only visit entities holding all of it, so the missing parts are
+// resolved as defaults and count as part of the prefab.
+fn complete_transform(
+    components: &mut BTreeMap<String, serde_json::Value>,
+) -> Result<(), SceneError> {
+    if !TRANSFORM_COMPONENTS
+        .iter()
+        .any(|component| components.contains_key(*component))
+    {
+        return Ok(());
+    }
+
+    let defaults = [
+        serde_json::to_value(Translation::default())?,
+        serde_json::to_value(Rotation::default())?,
+        serde_json::to_value(Scale::default())?,
+    ];
+
+    for (component, value) in TRANSFORM_COMPONENTS.iter().zip(defaults) {
+        components.entry(component.to_string()).or_insert(value);
+    }
+
+    Ok(())
+}
+
+// LocalToWorld is never stored in a prefab, it is recomputed from the rest.
+fn add_local_to_world(world: &shipyard::World, entities: &[EntityId]) {
+    world.run(
+        |translation_storage: View<Translation>,
+         mut local_to_world_storage: ViewMut<LocalToWorld>| {
+            for entity_id in entities.iter().copied() {
+                if translation_storage.contains(entity_id)
+                    && !local_to_world_storage.contains(entity_id)
+                {
+                    local_to_world_storage
+                        .add_component_unchecked(entity_id, LocalToWorld::default());
+                }
+            }
+        },
+    );
+}
+
+#[inline]
+fn prefixed(id: u64, key: &[u64]) -> Vec<u64> {
+    std::iter::once(id).chain(key.iter().copied()).collect()
+}
+
+fn alive_entity(world: &shipyard::World, entity: u64) -> Result<EntityId, PrefabError> {
+    world.run(|entities_view: EntitiesView| {
+        EntityId::from_inner(entity)
+            .filter(|entity_id| entities_view.is_alive(*entity_id))
+            .ok_or(PrefabError::MissingEntity(entity))
+    })
+}
+
+fn instance_prefab(world: &shipyard::World, root: u64) -> Result<String, PrefabError> {
+    world.run(|instance_storage: View<PrefabInstance>| {
+        EntityId::from_inner(root)
+            .and_then(|entity_id| instance_storage.get(entity_id).ok())
+            .map(|instance| instance.prefab.clone())
+            .ok_or(PrefabError::MissingInstance(root))
+    })
+}
+
+#[cfg(test)]
+mod prefab_library_test {
+    use crate::{
+        calculate_local_world_parent_system, calculate_local_world_system,
+        invalidate_unfrozen_system, load_scene, save_scene, ComponentRegistry, NestedPrefab,
+        Prefab, PrefabEntity, PrefabError, PrefabLibrary, PrefabLink, PrefabOverride, SceneFormat,
+    };
+    use fabled_math::Vector3;
+    use fabled_transform::{Children, LocalToWorld, Parent, Translation};
+    use shipyard::{EntityId, Get, IntoIter, IntoWithId, View, ViewMut, World};
+    use std::collections::HashMap;
+
+    fn translation(x: f32, y: f32, z: f32) -> serde_json::Value {
+        serde_json::to_value(Translation {
+            value: Vector3::set(x, y, z),
+        })
+        .unwrap()
+    }
+
+    fn entity(id: u64, parent: Option<u64>, value: serde_json::Value) -> PrefabEntity {
+        PrefabEntity {
+            id,
+            parent,
+            components: std::iter::once((String::from("Translation"), value)).collect(),
+        }
+    }
+
+    fn lamp() -> Prefab {
+        Prefab {
+            entities: vec![
+                entity(0, None, translation(0.0, 0.0, 0.0)),
+                entity(1, Some(0), translation(0.0, 1.0, 0.0)),
+            ],
+            ..Prefab::default()
+        }
+    }
+
+    fn scene_components() -> ComponentRegistry {
+        let mut components = ComponentRegistry::default();
+        components.register_scene_components();
+
+        components
+    }
+
+    #[test]
+    fn nested_prefab_with_overrides() {
+        let components = scene_components();
+
+        let mut library = PrefabLibrary::default();
+        library.insert("lamp", lamp());
+        library.insert(
+            "room",
+            Prefab {
+                entities: vec![entity(0, None, translation(5.0, 0.0, 0.0))],
+                nested: vec![NestedPrefab {
+                    id: 1,
+                    prefab: String::from("lamp"),
+                    parent: 0,
+                    overrides: vec![PrefabOverride {
+                        key: vec![1],
+                        component: String::from("Translation"),
+                        value: translation(0.0, 2.0, 0.0),
+                    }],
+                }],
+                ..Prefab::default()
+            },
+        );
+
+        let mut world = World::new();
+        let anchor = world.add_entity(()).inner();
+
+        let room = library
+            .spawn(
+                &world,
+                &components,
+                "room",
+                Some(anchor),
+                &[PrefabOverride {
+                    key: vec![0],
+                    component: String::from("Translation"),
+                    value: translation(7.0, 0.0, 0.0),
+                }],
+            )
+            .unwrap();
+
+        world.run(
+            |parent_storage: View<Parent>,
+             children_storage: View<Children>,
+             translation_storage: View<Translation>,
+             link_storage: View<PrefabLink>| {
+                let room_id = EntityId::from_inner(room).unwrap();
+
+                let linked: HashMap<Vec<u64>, EntityId> = link_storage
+                    .iter()
+                    .with_id()
+                    .map(|(entity_id, link)| (link.key.clone(), entity_id))
+                    .collect();
+
+                assert_eq!(linked.len(), 3);
+
+                let lamp = linked[&vec![1, 0]];
+                let bulb = linked[&vec![1, 1]];
+
+                assert_eq!(parent_storage.get(room_id).unwrap().value, anchor);
+                assert_eq!(parent_storage.get(lamp).unwrap().value, room);
+                assert_eq!(parent_storage.get(bulb).unwrap().value, lamp.inner());
+                assert_eq!(
+                    children_storage.get(room_id).unwrap().value,
+                    vec![lamp.inner()]
+                );
+
+                assert_eq!(
+                    translation_storage
+                        .get(room_id)
+                        .unwrap()
+                        .value
+                        .to_primitive(),
+                    [7.0, 0.0, 0.0]
+                );
+                assert_eq!(
+                    translation_storage.get(bulb).unwrap().value.to_primitive(),
+                    [0.0, 2.0, 0.0]
+                );
+            },
+        );
+
+        library.insert(
+            "loop",
+            Prefab {
+                entities: vec![entity(0, None, translation(0.0, 0.0, 0.0))],
+                nested: vec![NestedPrefab {
+                    id: 1,
+                    prefab: String::from("loop"),
+                    parent: 0,
+                    overrides: Vec::new(),
+                }],
+                ..Prefab::default()
+            },
+        );
+
+        assert!(matches!(
+            library.spawn(&world, &components, "loop", None, &[]),
+            Err(PrefabError::Cycle(_))
+        ));
+    }
+
+    #[test]
+    fn instances_survive_scene_round_trip() {
+        let components = scene_components();
+
+        let mut library = PrefabLibrary::default();
+        library.insert("lamp", lamp());
+
+        let source = World::new();
+        library
+            .spawn(
+                &source,
+                &components,
+                "lamp",
+                None,
+                &[PrefabOverride {
+                    key: vec![1],
+                    component: String::from("Translation"),
+                    value: translation(0.0, 3.0, 0.0),
+                }],
+            )
+            .unwrap();
+
+        let bytes = save_scene(&source, &components, SceneFormat::Ron).unwrap();
+
+        // Offsets the ids so the instance has to be remapped.
+        let mut target = World::new();
+        target.add_entity(());
+        target.add_entity(());
+
+        let loaded = load_scene(&target, &components, SceneFormat::Ron, &bytes).unwrap();
+        let lamp = loaded[0];
+
+        target.run(|link_storage: View<PrefabLink>| {
+            assert_eq!(link_storage.iter().count(), 2);
+            assert!(link_storage.iter().all(|link| link.instance == lamp));
+        });
+
+        let overrides = library.overrides(&target, &components, lamp).unwrap();
+
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].key, vec![1]);
+    }
+
+    #[test]
+    fn instances_join_the_transform_pipeline() {
+        let components = scene_components();
+
+        let mut library = PrefabLibrary::default();
+        library.insert("lamp", lamp());
+
+        let world = World::new();
+
+        let lamp = library
+            .spawn(
+                &world,
+                &components,
+                "lamp",
+                None,
+                &[PrefabOverride {
+                    key: vec![0],
+                    component: String::from("Translation"),
+                    value: translation(2.0, 0.0, 0.0),
+                }],
+            )
+            .unwrap();
+
+        shipyard::Workload::builder("transform_test")
+            .with_system(&invalidate_unfrozen_system)
+            .with_system(&calculate_local_world_system)
+            .with_system(&calculate_local_world_parent_system)
+            .add_to_world(&world)
+            .unwrap();
+
+        world.run_workload("transform_test").unwrap();
+
+        world.run(
+            |link_storage: View<PrefabLink>, local_to_world_storage: View<LocalToWorld>| {
+                let bulb = link_storage
+                    .iter()
+                    .with_id()
+                    .find(|(_, link)| link.instance == lamp && link.key == vec![1])
+                    .map(|(entity_id, _)| entity_id)
+                    .unwrap();
+
+                assert_eq!(
+                    local_to_world_storage
+                        .get(bulb)
+                        .unwrap()
+                        .value
+                        .to_primitive()[12..15],
+                    [2.0, 1.0, 0.0]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn malformed_prefabs_are_rejected() {
+        let components = scene_components();
+
+        let mut library = PrefabLibrary::default();
+        library.insert("lamp", lamp());
+        library.insert(
+            "duplicate",
+            Prefab {
+                entities: vec![
+                    entity(0, None, translation(0.0, 0.0, 0.0)),
+                    entity(1, Some(0), translation(0.0, 1.0, 0.0)),
+                ],
+                nested: vec![NestedPrefab {
+                    id: 1,
+                    prefab: String::from("lamp"),
+                    parent: 0,
+                    overrides: Vec::new(),
+                }],
+                ..Prefab::default()
+            },
+        );
+        library.insert(
+            "cyclic",
+            Prefab {
+                entities: vec![
+                    entity(0, None, translation(0.0, 0.0, 0.0)),
+                    entity(1, Some(2), translation(0.0, 1.0, 0.0)),
+                    entity(2, Some(1), translation(0.0, 2.0, 0.0)),
+                ],
+                ..Prefab::default()
+            },
+        );
+
+        let world = World::new();
+
+        assert!(matches!(
+            library.spawn(&world, &components, "duplicate", None, &[]),
+            Err(PrefabError::DuplicateEntity { entity: 1, .. })
+        ));
+        assert!(matches!(
+            library.spawn(&world, &components, "cyclic", None, &[]),
+            Err(PrefabError::ParentCycle { .. })
+        ));
+
+        world.run(|translation_storage: View<Translation>| {
+            assert_eq!(translation_storage.iter().count(), 0);
+        });
+    }
+
+    #[test]
+    fn overrides_apply_back() {
+        let components = scene_components();
+
+        let mut library = PrefabLibrary::default();
+        library.insert("lamp", lamp());
+
+        let world = World::new();
+
+        let lamp = library
+            .spawn(&world, &components, "lamp", None, &[])
+            .unwrap();
+
+        assert!(library
+            .overrides(&world, &components, lamp)
+            .unwrap()
+            .is_empty());
+
+        world.run(|mut translation_storage: ViewMut<Translation>| {
+            (&mut translation_storage)
+                .get(EntityId::from_inner(lamp).unwrap())
+                .unwrap()
+                .value = Vector3::set(0.1, 0.0, 0.0);
+        });
+
+        let overrides = library.overrides(&world, &components, lamp).unwrap();
+
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].key, vec![0]);
+
+        library.apply_overrides(&world, &components, lamp).unwrap();
+
+        assert!(library
+            .overrides(&world, &components, lamp)
+            .unwrap()
+            .is_empty());
+
+        // The applied prefab survives its ron round trip.
+        let mut reloaded = PrefabLibrary::default();
+        reloaded
+            .load("lamp", &library.save("lamp").unwrap())
+            .unwrap();
+
+        assert!(reloaded
+            .overrides(&world, &components, lamp)
+            .unwrap()
+            .is_empty());
+
+        // A child listing the root again must not loop the capture.
+        world.run(
+            |link_storage: View<PrefabLink>,
+             mut children_storage: ViewMut<Children>,
+             mut local_to_world_storage: ViewMut<LocalToWorld>| {
+                let bulb = link_storage
+                    .iter()
+                    .with_id()
+                    .find(|(_, link)| link.key == vec![1])
+                    .map(|(entity_id, _)| entity_id)
+                    .unwrap();
+
+                children_storage.add_component_unchecked(bulb, Children { value: vec![lamp] });
+                local_to_world_storage.add_component_unchecked(bulb, LocalToWorld::default());
+            },
+        );
+
+        library
+            .create("captured", &world, &components, lamp)
+            .unwrap();
+
+        let captured = library.get("captured").unwrap();
+
+        assert_eq!(captured.entities.len(), 2);
+        assert_eq!(captured.entities[1].parent, Some(0));
+        assert!(!captured.entities[1].components.contains_key("LocalToWorld"));
+    }
+}
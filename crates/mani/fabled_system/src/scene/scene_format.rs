@@ -0,0 +1,91 @@
+use crate::scene::finite_check::is_finite;
+use crate::SceneError;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+// Bumped whenever the layout below changes, older scenes are still read.
+pub const SCENE_VERSION: u32 = 1;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SceneFormat {
+    // Readable text that diffs well under version control.
+    Ron,
+    Json,
+    // Compact bincode for shipping, not meant to be edited.
+    Binary,
+}
+
+impl SceneFormat {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<SceneFormat> {
+        match path.as_ref().extension()?.to_str()? {
+            "ron" => Some(SceneFormat::Ron),
+            "json" => Some(SceneFormat::Json),
+            "scene" | "bin" => Some(SceneFormat::Binary),
+            _ => None,
+        }
+    }
+}
+
+// Entity ids are the ids of the saved world, they only link the entities of
+// the file together and are remapped on load. Components are keyed by their
+// registered name, the BTreeMap keeps the text output in a stable order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SceneEntity<P> {
+    pub id: u64,
+    pub components: BTreeMap<String, P>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Scene<P> {
+    pub version: u32,
+    pub entities: Vec<SceneEntity<P>>,
+}
+
+impl<P> Default for Scene<P> {
+    fn default() -> Self {
+        Self {
+            version: SCENE_VERSION,
+            entities: Vec::new(),
+        }
+    }
+}
+
+// Bincode is not self describing, so binary components are encoded on their
+// own and stored as bytes inside the scene.
+pub(crate) trait ScenePayload: Sized {
+    fn encode<T: Serialize>(component: &T) -> Result<Self, SceneError>;
+
+    fn decode<T: DeserializeOwned>(&self) -> Result<T, SceneError>;
+}
+
+// The text formats have no NaN or infinity, serde_json would quietly save them
+// as null and the scene would then fail to load.
+impl ScenePayload for serde_json::Value {
+    fn encode<T: Serialize>(component: &T) -> Result<Self, SceneError> {
+        let value: serde_json::Value = serde_json::to_value(component)?;
+
+        if !is_finite(component) {
+            return Err(SceneError::NonFinite(std::any::type_name::<T>()));
+        }
+
+        Ok(value)
+    }
+
+    fn decode<T: DeserializeOwned>(&self) -> Result<T, SceneError> {
+        Ok(T::deserialize(self)?)
+    }
+}
+
+impl ScenePayload for Vec<u8> {
+    fn encode<T: Serialize>(component: &T) -> Result<Self, SceneError> {
+        Ok(bincode::serialize(component)?)
+    }
+
+    fn decode<T: DeserializeOwned>(&self) -> Result<T, SceneError> {
+        Ok(bincode::deserialize(self)?)
+    }
+}
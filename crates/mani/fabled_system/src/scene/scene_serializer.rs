@@ -0,0 +1,344 @@
+use crate::{
+    remap_entity_references, ComponentRegistry, LoadFunction, SaveFunction, Scene, SceneEntity,
+    SceneError, SceneFormat, ScenePayload, SerdeFunction, SCENE_VERSION,
+};
+
+use ron::ser::PrettyConfig;
+
+use shipyard::{AllStoragesViewMut, EntitiesViewMut, EntityId};
+
+use std::collections::BTreeMap;
+
+// Saves every entity owning at least one component registered with serde,
+// components registered without it are left out.
+pub fn save_scene(
+    world: &shipyard::World,
+    components: &ComponentRegistry,
+    format: SceneFormat,
+) -> Result<Vec<u8>, SceneError> {
+    match format {
+        SceneFormat::Ron => {
+            let scene = collect_scene(world, components, |serde| serde.save_text)?;
+
+            Ok(ron::ser::to_string_pretty(&scene, PrettyConfig::default())?.into_bytes())
+        }
+        SceneFormat::Json => {
+            let scene = collect_scene(world, components, |serde| serde.save_text)?;
+
+            Ok(serde_json::to_vec_pretty(&scene)?)
+        }
+        SceneFormat::Binary => {
+            let scene = collect_scene(world, components, |serde| serde.save_binary)?;
+
+            Ok(bincode::serialize(&scene)?)
+        }
+    }
+}
+
+// Spawns the scene as new entities and returns their ids in file order.
+// Parent and Children are remapped to the spawned entities, a parent missing
+// from the file is dropped and its child becomes a root.
+pub fn load_scene(
+    world: &shipyard::World,
+    components: &ComponentRegistry,
+    format: SceneFormat,
+    bytes: &[u8],
+) -> Result<Vec<u64>, SceneError> {
+    match format {
+        SceneFormat::Ron => {
+            let scene: Scene<serde_json::Value> = ron::de::from_bytes(bytes)?;
+
+            spawn_scene(world, components, scene, |serde| serde.load_text)
+        }
+        SceneFormat::Json => {
+            let scene: Scene<serde_json::Value> = serde_json::from_slice(bytes)?;
+
+            spawn_scene(world, components, scene, |serde| serde.load_text)
+        }
+        SceneFormat::Binary => {
+            let scene: Scene<Vec<u8>> = bincode::deserialize(bytes)?;
+
+            spawn_scene(world, components, scene, |serde| serde.load_binary)
+        }
+    }
+}
+
+pub(crate) fn collect_scene<P: ScenePayload, F: Fn(&SerdeFunction) -> SaveFunction<P>>(
+    world: &shipyard::World,
+    components: &ComponentRegistry,
+    select: F,
+) -> Result<Scene<P>, SceneError> {
+    let mut entities: BTreeMap<u64, BTreeMap<String, P>> = BTreeMap::new();
+
+    for entry in components.entries() {
+        if let Some(serde) = &entry.serde {
+            for (entity_id, payload) in select(serde)(world)? {
+                entities
+                    .entry(entity_id.inner())
+                    .or_default()
+                    .insert(entry.name.to_string(), payload);
+            }
+        }
+    }
+
+    Ok(Scene {
+        version: SCENE_VERSION,
+        entities: entities
+            .into_iter()
+            .map(|(id, components)| SceneEntity { id, components })
+            .collect(),
+    })
+}
+
+pub(crate) fn spawn_scene<P: ScenePayload, F: Fn(&SerdeFunction) -> LoadFunction<P>>(
+    world: &shipyard::World,
+    components: &ComponentRegistry,
+    scene: Scene<P>,
+    select: F,
+) -> Result<Vec<u64>, SceneError> {
+    if scene.version > SCENE_VERSION {
+        return Err(SceneError::Version {
+            found: scene.version,
+            supported: SCENE_VERSION,
+        });
+    }
+
+    let sources: Vec<EntityId> = scene
+        .entities
+        .iter()
+        .map(|entity| EntityId::from_inner(entity.id).ok_or(SceneError::InvalidEntity(entity.id)))
+        .collect::<Result<Vec<EntityId>, SceneError>>()?;
+
+    let pairs: Vec<(EntityId, EntityId)> = world.run(|mut entities_view: EntitiesViewMut| {
+        sources
+            .iter()
+            .map(|source| (*source, entities_view.add_entity((), ())))
+            .collect()
+    });
+
+    let spawned: Vec<(EntityId, BTreeMap<String, P>)> = pairs
+        .iter()
+        .zip(scene.entities)
+        .map(|((_, spawned), entity)| (*spawned, entity.components))
+        .collect();
+
+    // A malformed or unknown component takes the whole scene down with it.
+    if let Err(error) = insert_components(world, components, spawned, select) {
+        delete_entities(world, pairs.iter().map(|(_, spawned)| *spawned));
+
+        return Err(error);
+    }
+
+    remap_entity_references(world, &pairs, false);
+
+    Ok(pairs.iter().map(|(_, spawned)| spawned.inner()).collect())
+}
+
+// Adds the named components to existing entities. Every name is resolved
+// before the first storage is written.
+pub(crate) fn insert_components<P: ScenePayload, F: Fn(&SerdeFunction) -> LoadFunction<P>>(
+    world: &shipyard::World,
+    components: &ComponentRegistry,
+    entities: Vec<(EntityId, BTreeMap<String, P>)>,
+    select: F,
+) -> Result<(), SceneError> {
+    let mut grouped: BTreeMap<String, Vec<(EntityId, P)>> = BTreeMap::new();
+
+    for (entity_id, entity_components) in entities {
+        for (name, payload) in entity_components {
+            grouped.entry(name).or_default().push((entity_id, payload));
+        }
+    }
+
+    let mut pending: Vec<(LoadFunction<P>, Vec<(EntityId, P)>)> = Vec::with_capacity(grouped.len());
+
+    for (name, payloads) in grouped {
+        match components
+            .find(&name)
+            .and_then(|entry| entry.serde.as_ref())
+        {
+            Some(serde) => pending.push((select(serde), payloads)),
+            None => return Err(SceneError::UnknownComponent(name)),
+        }
+    }
+
+    for (load, payloads) in pending {
+        load(world, payloads)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn delete_entities<I: IntoIterator<Item = EntityId>>(
+    world: &shipyard::World,
+    entities: I,
+) {
+    world.run(|mut all_storages: AllStoragesViewMut| {
+        for entity_id in entities {
+            all_storages.delete_entity(entity_id);
+        }
+    });
+}
+
+#[cfg(test)]
+mod scene_serializer_test {
+    use crate::{load_scene, save_scene, ComponentRegistry, SceneError, SceneFormat};
+    use fabled_math::Vector3;
+    use fabled_render::camera::FStop;
+    use fabled_render::light::{FallOffAlgorithm, ShadowCaster};
+    use fabled_transform::{Children, Hierarchy, HierarchyViewMut, Parent, Translation};
+    use shipyard::{EntityId, Get, View, World};
+
+    fn hierarchy_world() -> World {
+        let mut world = World::new();
+
+        let root = world.add_entity((Translation {
+            value: Vector3::set(1.0, 2.0, 3.0),
+        },));
+        let child = world.add_entity((Translation {
+            value: Vector3::set(0.0, 1.0, 0.0),
+        },));
+
+        world.run(|mut hierarchy: HierarchyViewMut| {
+            hierarchy.attach(child, root).unwrap();
+        });
+
+        world
+    }
+
+    #[test]
+    fn scene_round_trip() {
+        let mut components = ComponentRegistry::default();
+        components.register_scene_components();
+
+        let source = hierarchy_world();
+
+        for format in [SceneFormat::Ron, SceneFormat::Json, SceneFormat::Binary] {
+            let bytes = save_scene(&source, &components, format).unwrap();
+
+            // Offsets the ids so the remap has something to do.
+            let mut target = World::new();
+            target.add_entity(());
+            target.add_entity(());
+            target.add_entity(());
+
+            let loaded = load_scene(&target, &components, format, &bytes).unwrap();
+
+            assert_eq!(loaded.len(), 2);
+
+            target.run(
+                |translation_storage: View<Translation>,
+                 parent_storage: View<Parent>,
+                 children_storage: View<Children>| {
+                    let root = EntityId::from_inner(loaded[0]).unwrap();
+                    let child = EntityId::from_inner(loaded[1]).unwrap();
+
+                    assert_eq!(
+                        translation_storage.get(root).unwrap().value.to_primitive(),
+                        [1.0, 2.0, 3.0]
+                    );
+                    assert_eq!(parent_storage.get(child).unwrap().value, loaded[0]);
+                    assert_eq!(children_storage.get(root).unwrap().value, vec![loaded[1]]);
+                    assert!(parent_storage.get(root).is_err());
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn camera_and_light_round_trip() {
+        let mut components = ComponentRegistry::default();
+        components.register_scene_components();
+
+        let mut source = World::new();
+        source.add_entity((
+            FStop {
+                f_stop: 2.8,
+                step: 3,
+            },
+            FallOffAlgorithm::CustomBakeryFallOff { light_size: 0.5 },
+            ShadowCaster,
+        ));
+
+        for format in [SceneFormat::Ron, SceneFormat::Json, SceneFormat::Binary] {
+            let bytes = save_scene(&source, &components, format).unwrap();
+
+            let target = World::new();
+            let loaded = load_scene(&target, &components, format, &bytes).unwrap();
+
+            assert_eq!(loaded.len(), 1);
+
+            target.run(
+                |f_stop_storage: View<FStop>,
+                 fall_off_storage: View<FallOffAlgorithm>,
+                 shadow_caster_storage: View<ShadowCaster>| {
+                    let entity = EntityId::from_inner(loaded[0]).unwrap();
+
+                    assert!(
+                        f_stop_storage.get(entity).unwrap()
+                            == &FStop {
+                                f_stop: 2.8,
+                                step: 3
+                            }
+                    );
+                    assert!(matches!(
+                        fall_off_storage.get(entity).unwrap(),
+                        FallOffAlgorithm::CustomBakeryFallOff { light_size } if *light_size == 0.5
+                    ));
+                    assert!(shadow_caster_storage.get(entity).is_ok());
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn non_finite_floats_fail_to_save_as_text() {
+        let mut components = ComponentRegistry::default();
+        components.register_scene_components();
+
+        let mut world = World::new();
+        world.add_entity((Translation {
+            value: Vector3::set(f32::NAN, 0.0, f32::INFINITY),
+        },));
+
+        for format in [SceneFormat::Ron, SceneFormat::Json] {
+            assert!(matches!(
+                save_scene(&world, &components, format),
+                Err(SceneError::NonFinite(_))
+            ));
+        }
+
+        let bytes = save_scene(&world, &components, SceneFormat::Binary).unwrap();
+
+        let target = World::new();
+        let loaded = load_scene(&target, &components, SceneFormat::Binary, &bytes).unwrap();
+
+        target.run(|translation_storage: View<Translation>| {
+            let translation = translation_storage
+                .get(EntityId::from_inner(loaded[0]).unwrap())
+                .unwrap()
+                .value;
+
+            assert!(translation.x().is_nan());
+            assert_eq!(translation.z(), f32::INFINITY);
+        });
+    }
+
+    #[test]
+    fn unknown_component_is_rejected() {
+        let components = ComponentRegistry::default();
+
+        let mut world = World::new();
+        let entity = world.add_entity(()).inner();
+
+        let scene = format!(
+            r#"(version: 1, entities: [(id: {}, components: {{"Unknown": ()}})])"#,
+            entity
+        );
+
+        assert!(matches!(
+            load_scene(&world, &components, SceneFormat::Ron, scene.as_bytes()),
+            Err(SceneError::UnknownComponent(_))
+        ));
+    }
+}
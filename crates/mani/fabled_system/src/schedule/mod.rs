@@ -0,0 +1,5 @@
+mod scheduler;
+mod stage;
+
+pub use scheduler::*;
+pub use stage::*;
@@ -0,0 +1,591 @@
+use crate::{
+    calculate_local_world_parent_system, calculate_local_world_system, calculate_motion_system,
+    invalidate_unfrozen_system, removed_deleted_transform_system,
+    store_previous_local_world_system, validate_transform_system, ScheduleError, Stage, Time,
+    TransformDiagnostics,
+};
+
+use fabled_component::{Unique, Untracked};
+
+use shipyard::{IntoWorkloadSystem, UniqueView, UniqueViewMut, Workload};
+
+use std::collections::HashSet;
+
+// Kept after build so the same scheduler can be built for several worlds.
+type RegisterFunction = Box<dyn Fn(&shipyard::World) -> Result<(), String>>;
+type UniqueFunction = fn(&shipyard::World) -> Result<(), ScheduleError>;
+
+struct ScheduledSystem {
+    name: &'static str,
+    stage: Stage,
+    before: Vec<&'static str>,
+    after: Vec<&'static str>,
+    register: RegisterFunction,
+}
+
+// Names of the systems a world already has as workloads, so building the
+// same world again only adds the new ones.
+#[derive(Default)]
+struct BuiltSystems {
+    names: HashSet<&'static str>,
+}
+
+impl Unique for BuiltSystems {
+    type Tracking = Untracked;
+}
+
+// Returned when adding a system to order it against others of the same stage.
+pub struct SystemOrder<'a> {
+    system: &'a mut ScheduledSystem,
+}
+
+impl SystemOrder<'_> {
+    pub fn before(self, name: &'static str) -> Self {
+        self.system.before.push(name);
+        self
+    }
+
+    pub fn after(self, name: &'static str) -> Self {
+        self.system.after.push(name);
+        self
+    }
+}
+
+// Runs the stages of a frame in Stage::ALL order, FixedUpdate as many times as
+// the accumulated time allows. Every system is its own named workload so
+// systems are ordered by their before and after constraints and otherwise by
+// insertion. The frame delta is always passed in, nothing reads the clock, so
+// a headless loop is fully deterministic. The fixed step accumulator lives in
+// the Time of each world, one scheduler can drive several worlds.
+pub struct Scheduler {
+    systems: Vec<ScheduledSystem>,
+    order: [Vec<&'static str>; 5],
+    // Caps the fixed steps of a single frame so a long stall does not spiral.
+    pub max_fixed_steps: u32,
+    // Uniques the scheduled systems borrow, added by build when missing.
+    uniques: Vec<UniqueFunction>,
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self {
+            systems: Vec::new(),
+            order: Default::default(),
+            max_fixed_steps: 8,
+            uniques: vec![
+                add_default_unique::<Time>,
+                add_default_unique::<BuiltSystems>,
+            ],
+        }
+    }
+}
+
+impl Scheduler {
+    pub fn add_system<B, R, S: IntoWorkloadSystem<B, R> + Clone + 'static>(
+        &mut self,
+        stage: Stage,
+        name: &'static str,
+        system: S,
+    ) -> SystemOrder<'_> {
+        self.push(
+            stage,
+            name,
+            Box::new(move |world: &shipyard::World| {
+                Workload::builder(name)
+                    .with_system(system.clone())
+                    .add_to_world(world)
+                    .map(|_| ())
+                    .map_err(|error| format!("{:?}", error))
+            }),
+        )
+    }
+
+    // build receives the name the workload has to be created with.
+    pub fn add_workload<F: Fn(&'static str) -> Workload + 'static>(
+        &mut self,
+        stage: Stage,
+        name: &'static str,
+        build: F,
+    ) -> SystemOrder<'_> {
+        self.push(
+            stage,
+            name,
+            Box::new(move |world: &shipyard::World| {
+                build(name)
+                    .add_to_world(world)
+                    .map(|_| ())
+                    .map_err(|error| format!("{:?}", error))
+            }),
+        )
+    }
+
+    // Everything fabled_transform needs each frame, build adds the
+    // TransformDiagnostics unique of the validation pass.
+    pub fn add_transform_systems(&mut self) {
+        self.uniques
+            .push(add_default_unique::<TransformDiagnostics>);
+
+        self.add_system(
+            Stage::PreUpdate,
+            "store_previous_local_world_system",
+            &store_previous_local_world_system,
+        );
+
+        self.add_system(
+            Stage::PostUpdate,
+            "removed_deleted_transform_system",
+            &removed_deleted_transform_system,
+        );
+        self.add_system(
+            Stage::PostUpdate,
+            "validate_transform_system",
+            &validate_transform_system,
+        )
+        .after("removed_deleted_transform_system");
+        self.add_system(
+            Stage::PostUpdate,
+            "invalidate_unfrozen_system",
+            &invalidate_unfrozen_system,
+        )
+        .after("validate_transform_system");
+        self.add_system(
+            Stage::PostUpdate,
+            "calculate_local_world_system",
+            &calculate_local_world_system,
+        )
+        .after("invalidate_unfrozen_system");
+        self.add_system(
+            Stage::PostUpdate,
+            "calculate_local_world_parent_system",
+            &calculate_local_world_parent_system,
+        )
+        .after("calculate_local_world_system");
+        self.add_system(
+            Stage::PostUpdate,
+            "calculate_motion_system",
+            &calculate_motion_system,
+        )
+        .after("calculate_local_world_parent_system");
+    }
+
+    fn push(
+        &mut self,
+        stage: Stage,
+        name: &'static str,
+        register: RegisterFunction,
+    ) -> SystemOrder<'_> {
+        self.systems.push(ScheduledSystem {
+            name,
+            stage,
+            before: Vec::new(),
+            after: Vec::new(),
+            register,
+        });
+
+        SystemOrder {
+            system: self.systems.last_mut().unwrap(),
+        }
+    }
+
+    // Resolves the ordering and adds every system the world does not have yet,
+    // along with a default Time, and the uniques the added systems need, when
+    // the world has none. Each world the scheduler runs on has to be built,
+    // systems added afterwards need another build of every world.
+    pub fn build(&mut self, world: &shipyard::World) -> Result<(), ScheduleError> {
+        for (index, system) in self.systems.iter().enumerate() {
+            if self.systems[..index]
+                .iter()
+                .any(|other| other.name == system.name)
+            {
+                return Err(ScheduleError::Duplicate(system.name));
+            }
+        }
+
+        for stage in Stage::ALL {
+            self.order[stage.index()] = self.sort_stage(stage)?;
+        }
+
+        for add_unique in &self.uniques {
+            add_unique(world)?;
+        }
+
+        for system in &self.systems {
+            let exists = world
+                .borrow::<UniqueView<BuiltSystems>>()
+                .map(|built| built.names.contains(system.name))
+                .map_err(|_| ScheduleError::NotBuilt)?;
+
+            if exists {
+                continue;
+            }
+
+            (system.register)(world).map_err(|message| ScheduleError::AddWorkload {
+                name: system.name,
+                message,
+            })?;
+
+            world
+                .borrow::<UniqueViewMut<BuiltSystems>>()
+                .map_err(|_| ScheduleError::NotBuilt)?
+                .names
+                .insert(system.name);
+        }
+
+        Ok(())
+    }
+
+    // Kahn's algorithm, ties are broken by insertion order so the result is
+    // stable between runs.
+    fn sort_stage(&self, stage: Stage) -> Result<Vec<&'static str>, ScheduleError> {
+        let systems: Vec<&ScheduledSystem> = self
+            .systems
+            .iter()
+            .filter(|system| system.stage == stage)
+            .collect();
+
+        let position = |system: &'static str, other: &'static str| {
+            systems
+                .iter()
+                .position(|candidate| candidate.name == other)
+                .ok_or(ScheduleError::UnknownSystem {
+                    stage,
+                    system,
+                    other,
+                })
+        };
+
+        // edges[a] holds every system that has to run after a.
+        let mut edges: Vec<Vec<usize>> = vec![Vec::new(); systems.len()];
+        let mut incoming: Vec<usize> = vec![0; systems.len()];
+
+        for (index, system) in systems.iter().enumerate() {
+            for before in system.before.iter().copied() {
+                let other = position(system.name, before)?;
+                edges[index].push(other);
+                incoming[other] += 1;
+            }
+
+            for after in system.after.iter().copied() {
+                let other = position(system.name, after)?;
+                edges[other].push(index);
+                incoming[index] += 1;
+            }
+        }
+
+        let mut order: Vec<&'static str> = Vec::with_capacity(systems.len());
+        let mut done: Vec<bool> = vec![false; systems.len()];
+
+        while order.len() < systems.len() {
+            let next = (0..systems.len())
+                .find(|index| !done[*index] && incoming[*index] == 0)
+                .ok_or(ScheduleError::Cycle(stage))?;
+
+            done[next] = true;
+            order.push(systems[next].name);
+
+            for other in &edges[next] {
+                incoming[*other] -= 1;
+            }
+        }
+
+        Ok(order)
+    }
+
+    pub fn stage_order(&self, stage: Stage) -> &[&'static str] {
+        &self.order[stage.index()]
+    }
+
+    pub fn run_stage(&self, world: &shipyard::World, stage: Stage) -> Result<(), ScheduleError> {
+        self.check_built(world)?;
+
+        for name in self.order[stage.index()].iter().copied() {
+            world
+                .run_workload(name)
+                .map_err(|error| ScheduleError::RunWorkload {
+                    name,
+                    message: format!("{:?}", error),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    // Advances Time by delta seconds of real time and runs one frame.
+    pub fn update(&self, world: &shipyard::World, delta: f32) -> Result<(), ScheduleError> {
+        self.check_built(world)?;
+
+        let fixed_steps = {
+            let mut time = world
+                .borrow::<UniqueViewMut<Time>>()
+                .map_err(|_| ScheduleError::NotBuilt)?;
+
+            let scaled_delta = if time.paused {
+                0.0
+            } else {
+                delta.max(0.0) * time.scale
+            };
+
+            time.unscaled_delta = delta.max(0.0);
+            time.unscaled_elapsed += time.unscaled_delta as f64;
+            time.delta = scaled_delta;
+            time.elapsed += scaled_delta as f64;
+            time.frame += 1;
+
+            let fixed_delta = time.fixed_delta.max(f32::EPSILON);
+
+            time.accumulator =
+                (time.accumulator + scaled_delta).min(fixed_delta * self.max_fixed_steps as f32);
+
+            let fixed_steps = (time.accumulator / fixed_delta) as u32;
+
+            time.accumulator -= fixed_steps as f32 * fixed_delta;
+            time.alpha = time.accumulator / fixed_delta;
+
+            fixed_steps
+        };
+
+        self.run_stage(world, Stage::PreUpdate)?;
+
+        for _ in 0..fixed_steps {
+            self.run_stage(world, Stage::FixedUpdate)?;
+
+            if let Ok(mut time) = world.borrow::<UniqueViewMut<Time>>() {
+                time.fixed_step += 1;
+            }
+        }
+
+        self.run_stage(world, Stage::Update)?;
+        self.run_stage(world, Stage::PostUpdate)?;
+        self.run_stage(world, Stage::RenderPrep)
+    }
+
+    // Every scheduled system has to be a workload of the world.
+    fn check_built(&self, world: &shipyard::World) -> Result<(), ScheduleError> {
+        let built = world
+            .borrow::<UniqueView<BuiltSystems>>()
+            .map_err(|_| ScheduleError::NotBuilt)?;
+
+        if self
+            .systems
+            .iter()
+            .all(|system| built.names.contains(system.name))
+        {
+            Ok(())
+        } else {
+            Err(ScheduleError::NotBuilt)
+        }
+    }
+}
+
+fn add_default_unique<T: Unique + Default + Send + Sync>(
+    world: &shipyard::World,
+) -> Result<(), ScheduleError> {
+    if world.borrow::<UniqueView<T>>().is_ok() {
+        return Ok(());
+    }
+
+    world
+        .add_unique(T::default())
+        .map_err(|error| ScheduleError::AddWorkload {
+            name: std::any::type_name::<T>(),
+            message: format!("{:?}", error),
+        })
+}
+
+#[cfg(test)]
+mod scheduler_test {
+    use crate::{ScheduleError, Scheduler, Stage, Time, TransformDiagnostics};
+    use fabled_component::{Modification, Unique};
+    use shipyard::{UniqueView, UniqueViewMut, World};
+
+    #[derive(Default)]
+    struct Log {
+        value: Vec<&'static str>,
+    }
+
+    impl Unique for Log {
+        type Tracking = Modification;
+    }
+
+    fn first_system(mut log: UniqueViewMut<Log>) {
+        log.value.push("first");
+    }
+
+    fn second_system(mut log: UniqueViewMut<Log>) {
+        log.value.push("second");
+    }
+
+    fn third_system(mut log: UniqueViewMut<Log>) {
+        log.value.push("third");
+    }
+
+    fn fixed_system(mut log: UniqueViewMut<Log>) {
+        log.value.push("fixed");
+    }
+
+    fn log(world: &World) -> Vec<&'static str> {
+        world.borrow::<UniqueView<Log>>().unwrap().value.clone()
+    }
+
+    fn clear(world: &World) {
+        world.borrow::<UniqueViewMut<Log>>().unwrap().value.clear();
+    }
+
+    #[test]
+    fn stages_and_ordering() {
+        let world = World::new();
+        world.add_unique(Log::default()).unwrap();
+
+        let mut scheduler = Scheduler::default();
+
+        scheduler
+            .add_system(Stage::Update, "second_system", &second_system)
+            .after("first_system");
+        scheduler.add_system(Stage::Update, "first_system", &first_system);
+        scheduler
+            .add_system(Stage::Update, "third_system", &third_system)
+            .before("first_system");
+        scheduler.add_system(Stage::PreUpdate, "fixed_system", &fixed_system);
+
+        scheduler.build(&world).unwrap();
+        scheduler.update(&world, 0.0).unwrap();
+
+        assert_eq!(log(&world), vec!["fixed", "third", "first", "second"]);
+    }
+
+    #[test]
+    fn build_for_several_worlds() {
+        let mut scheduler = Scheduler::default();
+        scheduler.add_transform_systems();
+        scheduler.add_system(Stage::Update, "first_system", &first_system);
+
+        for _ in 0..2 {
+            let world = World::new();
+            world.add_unique(Log::default()).unwrap();
+
+            scheduler.build(&world).unwrap();
+            scheduler.update(&world, 0.0).unwrap();
+
+            assert_eq!(log(&world), vec!["first"]);
+            assert!(world.borrow::<UniqueView<TransformDiagnostics>>().is_ok());
+        }
+    }
+
+    #[test]
+    fn rebuild_adds_new_systems_only() {
+        let world = World::new();
+        world.add_unique(Log::default()).unwrap();
+
+        let mut scheduler = Scheduler::default();
+        scheduler.add_system(Stage::Update, "first_system", &first_system);
+        scheduler.build(&world).unwrap();
+
+        scheduler
+            .add_system(Stage::Update, "second_system", &second_system)
+            .after("first_system");
+
+        // The world lacks the new system until it is built again.
+        assert!(matches!(
+            scheduler.update(&world, 0.0),
+            Err(ScheduleError::NotBuilt)
+        ));
+        assert!(matches!(
+            scheduler.update(&World::new(), 0.0),
+            Err(ScheduleError::NotBuilt)
+        ));
+
+        scheduler.build(&world).unwrap();
+        scheduler.update(&world, 0.0).unwrap();
+
+        assert_eq!(log(&world), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn worlds_accumulate_separately() {
+        let mut scheduler = Scheduler::default();
+        scheduler.add_system(Stage::FixedUpdate, "fixed_system", &fixed_system);
+
+        let worlds = [World::new(), World::new()];
+
+        for world in &worlds {
+            world.add_unique(Log::default()).unwrap();
+            world
+                .add_unique(Time {
+                    fixed_delta: 0.125,
+                    ..Time::default()
+                })
+                .unwrap();
+
+            scheduler.build(world).unwrap();
+        }
+
+        scheduler.update(&worlds[0], 0.0625).unwrap();
+        scheduler.update(&worlds[1], 0.0625).unwrap();
+
+        assert!(log(&worlds[0]).is_empty());
+        assert!(log(&worlds[1]).is_empty());
+
+        scheduler.update(&worlds[0], 0.0625).unwrap();
+
+        assert_eq!(log(&worlds[0]).len(), 1);
+        assert!(log(&worlds[1]).is_empty());
+    }
+
+    #[test]
+    fn ordering_cycle_is_rejected() {
+        let world = World::new();
+
+        let mut scheduler = Scheduler::default();
+
+        scheduler
+            .add_system(Stage::Update, "first_system", &first_system)
+            .after("second_system");
+        scheduler
+            .add_system(Stage::Update, "second_system", &second_system)
+            .after("first_system");
+
+        assert!(matches!(
+            scheduler.build(&world),
+            Err(ScheduleError::Cycle(Stage::Update))
+        ));
+    }
+
+    #[test]
+    fn fixed_step_accumulates() {
+        let world = World::new();
+        world.add_unique(Log::default()).unwrap();
+        world
+            .add_unique(Time {
+                fixed_delta: 0.125,
+                ..Time::default()
+            })
+            .unwrap();
+
+        let mut scheduler = Scheduler::default();
+        scheduler.add_system(Stage::FixedUpdate, "fixed_system", &fixed_system);
+        scheduler.build(&world).unwrap();
+
+        scheduler.update(&world, 0.3125).unwrap();
+
+        assert_eq!(log(&world).len(), 2);
+        assert_eq!(world.borrow::<UniqueView<Time>>().unwrap().alpha, 0.5);
+
+        clear(&world);
+        scheduler.update(&world, 0.0625).unwrap();
+
+        assert_eq!(log(&world).len(), 1);
+        assert_eq!(world.borrow::<UniqueView<Time>>().unwrap().alpha, 0.0);
+
+        // Paused time does not advance the simulation.
+        clear(&world);
+        world.borrow::<UniqueViewMut<Time>>().unwrap().paused = true;
+        scheduler.update(&world, 1.0).unwrap();
+
+        let time = *world.borrow::<UniqueView<Time>>().unwrap();
+
+        assert!(log(&world).is_empty());
+        assert_eq!(time.delta, 0.0);
+        assert_eq!(time.unscaled_delta, 1.0);
+        assert_eq!(time.fixed_step, 3);
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    PreUpdate,
+    // Runs zero or more times per frame, once per elapsed Time::fixed_delta.
+    FixedUpdate,
+    Update,
+    PostUpdate,
+    RenderPrep,
+}
+
+impl Stage {
+    // Execution order within a frame.
+    pub const ALL: [Stage; 5] = [
+        Stage::PreUpdate,
+        Stage::FixedUpdate,
+        Stage::Update,
+        Stage::PostUpdate,
+        Stage::RenderPrep,
+    ];
+
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Stage::PreUpdate => "PreUpdate",
+            Stage::FixedUpdate => "FixedUpdate",
+            Stage::Update => "Update",
+            Stage::PostUpdate => "PostUpdate",
+            Stage::RenderPrep => "RenderPrep",
+        };
+
+        f.write_str(name)
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{PrefabInstance, PrefabLink, SceneError, ScenePayload};
+
+use fabled_component::Component;
+use fabled_render::camera::{
+    Aperture, AspectRatio, ClippingPlane, FStop, Fov, ISOSpeed, Oblique, Shutter, ViewPort,
+};
+use fabled_render::light::{
+    FallOffAlgorithm, LightChannel, LightMode, PointLight, ShadowCaster, ShadowReceiver, SpotLight,
+    SunLight,
+};
+use fabled_transform::{
+    AngularVelocity, BakedLocalToWorld, Children, Frozen, LocalToWorld, Parent,
+    PreviousLocalToWorld, Rotation, Scale, Translation, Velocity,
+};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use shipyard::{EntityId, Get, IntoIter, IntoWithId, View, ViewMut};
+
+use std::any::TypeId;
+
+type CopyFunction = fn(&shipyard::World, &shipyard::World, &[(EntityId, EntityId)]);
+type CollectFunction = fn(&shipyard::World) -> Vec<EntityId>;
+
+pub(crate) type SaveFunction<P> = fn(&shipyard::World) -> Result<Vec<(EntityId, P)>, SceneError>;
+pub(crate) type LoadFunction<P> =
+    fn(&shipyard::World, Vec<(EntityId, P)>) -> Result<(), SceneError>;
+pub(crate) type NormalizeFunction<P> = fn(&P) -> Result<P, SceneError>;
+
+// Save and load per payload, json values back the text formats and bincode bytes
+// the binary one.
+#[derive(Copy, Clone)]
+pub(crate) struct SerdeFunction {
+    pub save_text: SaveFunction<serde_json::Value>,
+    pub load_text: LoadFunction<serde_json::Value>,
+    pub save_binary: SaveFunction<Vec<u8>>,
+    pub load_binary: LoadFunction<Vec<u8>>,
+    pub normalize_text: NormalizeFunction<serde_json::Value>,
+}
+
+pub struct ComponentEntry {
+    pub name: &'static str,
+    pub type_id: TypeId,
+    copy: CopyFunction,
+    collect: CollectFunction,
+    pub(crate) serde: Option<SerdeFunction>,
+}
+
+// Shipyard storages are only reachable through their concrete type, so every
+// component that has to cross worlds is registered here once.
+#[derive(Default)]
+pub struct ComponentRegistry {
+    entries: Vec<ComponentEntry>,
+}
+
+impl ComponentRegistry {
+    pub fn register<T: Component + Clone + Send + Sync>(&mut self, name: &'static str) {
+        let type_id = TypeId::of::<T>();
+
+        if self.entries.iter().any(|entry| entry.type_id == type_id) {
+            return;
+        }
+
+        self.entries.push(ComponentEntry {
+            name,
+            type_id,
+            copy: copy_component::<T>,
+            collect: collect_component::<T>,
+            serde: None,
+        });
+    }
+
+    // Registers the component if needed and lets scenes save and load it
+    // under the given name. The name is what ends up on disk, renaming it
+    // breaks existing scenes.
+    pub fn register_serde<T: Component + Clone + Send + Sync + Serialize + DeserializeOwned>(
+        &mut self,
+        name: &'static str,
+    ) {
+        self.register::<T>(name);
+
+        let type_id = TypeId::of::<T>();
+
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.type_id == type_id)
+        {
+            entry.serde = Some(SerdeFunction {
+                save_text: save_component::<T, serde_json::Value>,
+                load_text: load_component::<T, serde_json::Value>,
+                save_binary: save_component::<T, Vec<u8>>,
+                load_binary: load_component::<T, Vec<u8>>,
+                normalize_text: normalize_component::<T, serde_json::Value>,
+            });
+        }
+    }
+
+    pub fn register_transform_components(&mut self) {
+        self.register::<Translation>("Translation");
+        self.register::<Rotation>("Rotation");
+        self.register::<Scale>("Scale");
+        self.register::<LocalToWorld>("LocalToWorld");
+        self.register::<PreviousLocalToWorld>("PreviousLocalToWorld");
+        self.register::<BakedLocalToWorld>("BakedLocalToWorld");
+        self.register::<Velocity>("Velocity");
+        self.register::<AngularVelocity>("AngularVelocity");
+        self.register::<Parent>("Parent");
+        self.register::<Children>("Children");
+        self.register::<Frozen>("Frozen");
+    }
+
+    // Everything a scene file stores. World matrices are saved so entities keep
+    // their storages, they are recomputed on the next update anyway.
+    pub fn register_scene_components(&mut self) {
+        self.register_serde::<Translation>("Translation");
+        self.register_serde::<Rotation>("Rotation");
+        self.register_serde::<Scale>("Scale");
+        self.register_serde::<LocalToWorld>("LocalToWorld");
+        self.register_serde::<Parent>("Parent");
+        self.register_serde::<Children>("Children");
+        self.register_serde::<Frozen>("Frozen");
+        self.register_serde::<PointLight>("PointLight");
+        self.register_serde::<SpotLight>("SpotLight");
+        self.register_serde::<SunLight>("SunLight");
+        self.register_serde::<LightChannel>("LightChannel");
+        self.register_serde::<LightMode>("LightMode");
+        self.register_serde::<FallOffAlgorithm>("FallOffAlgorithm");
+        self.register_serde::<ShadowCaster>("ShadowCaster");
+        self.register_serde::<ShadowReceiver>("ShadowReceiver");
+        self.register_serde::<ClippingPlane>("ClippingPlane");
+        self.register_serde::<AspectRatio>("AspectRatio");
+        self.register_serde::<ViewPort>("ViewPort");
+        self.register_serde::<Fov>("Fov");
+        self.register_serde::<Aperture>("Aperture");
+        self.register_serde::<FStop>("FStop");
+        self.register_serde::<ISOSpeed>("ISOSpeed");
+        self.register_serde::<Shutter>("Shutter");
+        self.register_serde::<Oblique>("Oblique");
+        self.register_prefab_components();
+    }
+
+    pub fn register_prefab_components(&mut self) {
+        self.register_serde::<PrefabInstance>("PrefabInstance");
+        self.register_serde::<PrefabLink>("PrefabLink");
+    }
+
+    pub fn entries(&self) -> &[ComponentEntry] {
+        &self.entries
+    }
+
+    pub fn find(&self, name: &str) -> Option<&ComponentEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    // Clones every registered component from the source entity of each pair
+    // onto the target entity. Entity references inside components are left as
+    // they are.
+    pub fn copy(
+        &self,
+        source: &shipyard::World,
+        target: &shipyard::World,
+        pairs: &[(EntityId, EntityId)],
+    ) {
+        for entry in &self.entries {
+            (entry.copy)(source, target, pairs);
+        }
+    }
+
+    // Every entity owning at least one registered component.
+    pub fn collect_entities(&self, world: &shipyard::World) -> Vec<EntityId> {
+        let mut entities: Vec<EntityId> = self
+            .entries
+            .iter()
+            .flat_map(|entry| (entry.collect)(world))
+            .collect();
+
+        entities.sort_unstable_by_key(|entity_id| entity_id.inner());
+        entities.dedup();
+
+        entities
+    }
+}
+
+fn copy_component<T: Component + Clone + Send + Sync>(
+    source: &shipyard::World,
+    target: &shipyard::World,
+    pairs: &[(EntityId, EntityId)],
+) {
+    // Cloned out first, source and target may be the same world.
+    let components: Vec<(EntityId, T)> = match source.borrow::<View<T>>() {
+        Ok(storage) => pairs
+            .iter()
+            .filter_map(|(from, to)| {
+                storage
+                    .get(*from)
+                    .ok()
+                    .map(|component| (*to, component.clone()))
+            })
+            .collect(),
+        Err(_) => return,
+    };
+
+    if components.is_empty() {
+        return;
+    }
+
+    if let Ok(mut storage) = target.borrow::<ViewMut<T>>() {
+        for (entity_id, component) in components {
+            storage.add_component_unchecked(entity_id, component);
+        }
+    }
+}
+
+fn collect_component<T: Component + Send + Sync>(world: &shipyard::World) -> Vec<EntityId> {
+    match world.borrow::<View<T>>() {
+        Ok(storage) => storage
+            .iter()
+            .with_id()
+            .map(|(entity_id, _)| entity_id)
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn save_component<T: Component + Send + Sync + Serialize, P: ScenePayload>(
+    world: &shipyard::World,
+) -> Result<Vec<(EntityId, P)>, SceneError> {
+    match world.borrow::<View<T>>() {
+        Ok(storage) => storage
+            .iter()
+            .with_id()
+            .map(|(entity_id, component)| Ok((entity_id, P::encode(component)?)))
+            .collect(),
+        Err(_) => Ok(Vec::new()),
+    }
+}
+
+fn load_component<T: Component + Send + Sync + DeserializeOwned, P: ScenePayload>(
+    world: &shipyard::World,
+    components: Vec<(EntityId, P)>,
+) -> Result<(), SceneError> {
+    let components: Vec<(EntityId, T)> = components
+        .iter()
+        .map(|(entity_id, payload)| Ok((*entity_id, payload.decode::<T>()?)))
+        .collect::<Result<Vec<(EntityId, T)>, SceneError>>()?;
+
+    if let Ok(mut storage) = world.borrow::<ViewMut<T>>() {
+        for (entity_id, component) in components {
+            storage.add_component_unchecked(entity_id, component);
+        }
+    }
+
+    Ok(())
+}
+
+// Round trips the payload through the component so hand written values, 0.1
+// read as f64 for example, compare equal to what the component saves.
+fn normalize_component<T: Serialize + DeserializeOwned, P: ScenePayload>(
+    payload: &P,
+) -> Result<P, SceneError> {
+    P::encode(&payload.decode::<T>()?)
+}
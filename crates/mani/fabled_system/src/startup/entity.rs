@@ -1,8 +1,6 @@
-use crate::{World};
+use crate::World;
 
-pub fn create_entity(world: Option<World>) -> u64 {
-    let mut world = world.unwrap_or_default();
+pub fn create_entity(world: &mut World) -> u64 {
     let entity_id = world.value.add_entity(());
     entity_id.inner()
-    // we need a world
 }
@@ -1,9 +1,13 @@
+mod component_registry;
 mod entity;
 mod lighting;
 mod world;
+mod world_registry;
 mod camera;
 
+pub use component_registry::*;
 pub use entity::*;
 pub use lighting::*;
 pub use camera::*;
 pub use world::*;
+pub use world_registry::*;
@@ -0,0 +1,442 @@
+use crate::{create_entity, ComponentRegistry, PrefabLink, World, WorldError, WorldFlag};
+
+use fabled_transform::{Children, Hierarchy, HierarchyViewMut, Parent};
+
+use shipyard::{
+    AllStoragesViewMut, EntitiesView, EntitiesViewMut, EntityId, Get, ViewMut, Workload,
+};
+
+use std::collections::{HashMap, HashSet};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WorldId {
+    pub value: usize,
+}
+
+// Owns every world of the application, an editor world next to its play mode
+// snapshot for example. Workloads are added and run per WorldFlag so a world
+// only runs what its flags ask for.
+pub struct WorldRegistry {
+    worlds: Vec<Option<World>>,
+    pub components: ComponentRegistry,
+}
+
+impl Default for WorldRegistry {
+    fn default() -> Self {
+        let mut components = ComponentRegistry::default();
+        components.register_transform_components();
+        components.register_prefab_components();
+
+        Self {
+            worlds: Vec::new(),
+            components,
+        }
+    }
+}
+
+impl WorldRegistry {
+    pub fn insert(&mut self, world: World) -> WorldId {
+        self.worlds.push(Some(world));
+
+        WorldId {
+            value: self.worlds.len() - 1,
+        }
+    }
+
+    pub fn create_world(&mut self, flags: WorldFlag) -> WorldId {
+        self.insert(World {
+            value: Default::default(),
+            flags,
+        })
+    }
+
+    pub fn remove(&mut self, world_id: WorldId) -> Option<World> {
+        self.worlds.get_mut(world_id.value)?.take()
+    }
+
+    pub fn get(&self, world_id: WorldId) -> Result<&World, WorldError> {
+        self.worlds
+            .get(world_id.value)
+            .and_then(Option::as_ref)
+            .ok_or(WorldError::MissingWorld(world_id.value))
+    }
+
+    pub fn get_mut(&mut self, world_id: WorldId) -> Result<&mut World, WorldError> {
+        self.worlds
+            .get_mut(world_id.value)
+            .and_then(Option::as_mut)
+            .ok_or(WorldError::MissingWorld(world_id.value))
+    }
+
+    // Worlds sharing at least one flag with the given ones.
+    pub fn iter_matching(&self, flags: WorldFlag) -> impl Iterator<Item = (WorldId, &World)> {
+        self.worlds
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, world)| match world {
+                Some(world) if world.flags.intersects(flags) => {
+                    Some((WorldId { value: index }, world))
+                }
+                _ => None,
+            })
+    }
+
+    // A workload can not be shared between worlds, build is called once per
+    // matching world.
+    pub fn add_workload<F: Fn() -> Workload>(
+        &self,
+        flags: WorldFlag,
+        build: F,
+    ) -> Result<(), WorldError> {
+        for (world_id, world) in self.iter_matching(flags) {
+            build()
+                .add_to_world(&world.value)
+                .map_err(|error| WorldError::AddWorkload {
+                    world: world_id.value,
+                    message: format!("{:?}", error),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    pub fn run_workload(&self, flags: WorldFlag, name: &'static str) -> Result<(), WorldError> {
+        for (world_id, world) in self.iter_matching(flags) {
+            world
+                .value
+                .run_workload(name)
+                .map_err(|error| WorldError::RunWorkload {
+                    world: world_id.value,
+                    message: format!("{:?}", error),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    pub fn create_entity(&mut self, world_id: WorldId) -> Result<u64, WorldError> {
+        Ok(create_entity(self.get_mut(world_id)?))
+    }
+
+    // Copies the entities with their registered components and returns the new
+    // ids in the same order. Parent and Children are remapped inside the copied
+    // set. A parent outside of it is kept when copying within one world and
+    // dropped otherwise, the copy becomes a root. Prefab links follow their
+    // instance root and are dropped when it is not copied along.
+    pub fn copy_entities(
+        &self,
+        from: WorldId,
+        to: WorldId,
+        entities: &[u64],
+    ) -> Result<Vec<u64>, WorldError> {
+        let source = self.get(from)?;
+        let target = self.get(to)?;
+
+        let source_entities: Vec<EntityId> = source.value.run(|entities_view: EntitiesView| {
+            entities
+                .iter()
+                .map(|entity| {
+                    EntityId::from_inner(*entity)
+                        .filter(|entity_id| entities_view.is_alive(*entity_id))
+                        .ok_or(WorldError::MissingEntity(*entity))
+                })
+                .collect::<Result<Vec<EntityId>, WorldError>>()
+        })?;
+
+        let pairs: Vec<(EntityId, EntityId)> =
+            target.value.run(|mut entities_view: EntitiesViewMut| {
+                source_entities
+                    .iter()
+                    .map(|entity_id| (*entity_id, entities_view.add_entity((), ())))
+                    .collect()
+            });
+
+        self.components.copy(&source.value, &target.value, &pairs);
+
+        remap_entity_references(&target.value, &pairs, from == to);
+
+        Ok(pairs.iter().map(|(_, copied)| copied.inner()).collect())
+    }
+
+    // Copies then deletes the originals. Links to entities that stay behind
+    // are cut first, on both sides.
+    pub fn move_entities(
+        &self,
+        from: WorldId,
+        to: WorldId,
+        entities: &[u64],
+    ) -> Result<Vec<u64>, WorldError> {
+        let moved = self.copy_entities(from, to, entities)?;
+
+        let moved_set: HashSet<u64> = entities.iter().copied().collect();
+
+        self.get(from)?
+            .value
+            .run(|mut hierarchy: HierarchyViewMut| {
+                for entity in entities {
+                    let entity_id = EntityId::from_inner(*entity).unwrap_or_else(EntityId::dead);
+
+                    let parent_stays = hierarchy
+                        .0
+                        .get(entity_id)
+                        .map(|parent| !moved_set.contains(&parent.value))
+                        .unwrap_or(false);
+
+                    if parent_stays {
+                        hierarchy.detach(entity_id);
+                    }
+
+                    let staying_children: Vec<u64> = hierarchy
+                        .1
+                        .get(entity_id)
+                        .map(|children| {
+                            children
+                                .value
+                                .iter()
+                                .copied()
+                                .filter(|child| !moved_set.contains(child))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+
+                    for child in staying_children {
+                        hierarchy
+                            .detach(EntityId::from_inner(child).unwrap_or_else(EntityId::dead));
+                    }
+                }
+            });
+
+        self.get(from)?
+            .value
+            .run(|mut all_storages: AllStoragesViewMut| {
+                for entity in entities {
+                    if let Some(entity_id) = EntityId::from_inner(*entity) {
+                        all_storages.delete_entity(entity_id);
+                    }
+                }
+            });
+
+        Ok(moved)
+    }
+
+    // Copies every entity with a registered component into a new world, the
+    // play mode snapshot of an editor world.
+    pub fn snapshot(&mut self, from: WorldId, flags: WorldFlag) -> Result<WorldId, WorldError> {
+        let entities: Vec<u64> = self
+            .components
+            .collect_entities(&self.get(from)?.value)
+            .iter()
+            .map(|entity_id| entity_id.inner())
+            .collect();
+
+        let snapshot = self.create_world(flags);
+
+        self.copy_entities(from, snapshot, &entities)?;
+
+        Ok(snapshot)
+    }
+}
+
+pub(crate) fn remap_entity_references(
+    target: &shipyard::World,
+    pairs: &[(EntityId, EntityId)],
+    same_world: bool,
+) {
+    let remap: HashMap<u64, u64> = pairs
+        .iter()
+        .map(|(source, copied)| (source.inner(), copied.inner()))
+        .collect();
+
+    target.run(
+        |mut parent_storage: ViewMut<Parent>, mut children_storage: ViewMut<Children>| {
+            for (_, copied) in pairs {
+                let outside_parent = match (&mut parent_storage).get(*copied) {
+                    Ok(mut parent) => match remap.get(&parent.value) {
+                        Some(remapped) => {
+                            parent.value = *remapped;
+                            None
+                        }
+                        None => Some(parent.value),
+                    },
+                    Err(_) => None,
+                };
+
+                if let Some(parent) = outside_parent {
+                    if same_world {
+                        let parent_id = EntityId::from_inner(parent).unwrap_or_else(EntityId::dead);
+
+                        if let Ok(mut children) = (&mut children_storage).get(parent_id) {
+                            children.value.push(copied.inner());
+                        }
+                    } else {
+                        parent_storage.remove(*copied);
+                    }
+                }
+
+                if let Ok(mut children) = (&mut children_storage).get(*copied) {
+                    children.value = children
+                        .value
+                        .iter()
+                        .filter_map(|child| remap.get(child).copied())
+                        .collect();
+                }
+            }
+        },
+    );
+
+    // Part of an instance copied without its root is a plain entity, it would
+    // otherwise pass for a second entity under the same key.
+    target.run(|mut link_storage: ViewMut<PrefabLink>| {
+        for (_, copied) in pairs {
+            let remapped = match (&mut link_storage).get(*copied) {
+                Ok(mut link) => match remap.get(&link.instance) {
+                    Some(remapped) => {
+                        link.instance = *remapped;
+                        true
+                    }
+                    None => false,
+                },
+                Err(_) => true,
+            };
+
+            if !remapped {
+                link_storage.remove(*copied);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod world_registry_test {
+    use crate::{WorldFlag, WorldRegistry};
+    use fabled_component::{Modification, Unique};
+    use fabled_math::Vector3;
+    use fabled_transform::{Children, Hierarchy, HierarchyViewMut, Parent, Translation};
+    use shipyard::{
+        EntitiesView, EntityId, Get, IntoIter, IntoWithId, UniqueViewMut, View, Workload,
+    };
+
+    #[derive(Default)]
+    struct Counter {
+        value: u32,
+    }
+
+    impl Unique for Counter {
+        type Tracking = Modification;
+    }
+
+    fn count_system(mut counter: UniqueViewMut<Counter>) {
+        counter.value += 1;
+    }
+
+    fn counter(registry: &WorldRegistry, world_id: crate::WorldId) -> u32 {
+        registry
+            .get(world_id)
+            .unwrap()
+            .value
+            .borrow::<shipyard::UniqueView<Counter>>()
+            .unwrap()
+            .value
+    }
+
+    #[test]
+    fn workloads_follow_flags() {
+        let mut registry = WorldRegistry::default();
+
+        let editor = registry.create_world(WorldFlag::EDITOR);
+        let game = registry.create_world(WorldFlag::GAME | WorldFlag::LIVE);
+
+        for world_id in [editor, game] {
+            registry
+                .get(world_id)
+                .unwrap()
+                .value
+                .add_unique(Counter::default())
+                .unwrap();
+        }
+
+        registry
+            .add_workload(WorldFlag::GAME, || {
+                Workload::builder("count").with_system(&count_system)
+            })
+            .unwrap();
+
+        registry.run_workload(WorldFlag::GAME, "count").unwrap();
+        registry.run_workload(WorldFlag::LIVE, "count").unwrap();
+
+        assert_eq!(counter(&registry, editor), 0);
+        assert_eq!(counter(&registry, game), 2);
+    }
+
+    #[test]
+    fn copy_and_move_between_worlds() {
+        let mut registry = WorldRegistry::default();
+
+        let editor = registry.create_world(WorldFlag::EDITOR);
+        let root = registry.create_entity(editor).unwrap();
+        let child = registry.create_entity(editor).unwrap();
+
+        registry.get(editor).unwrap().value.run(
+            |mut hierarchy: HierarchyViewMut,
+             mut translation_storage: shipyard::ViewMut<Translation>| {
+                let root_id = EntityId::from_inner(root).unwrap();
+                let child_id = EntityId::from_inner(child).unwrap();
+
+                hierarchy.attach(child_id, root_id).unwrap();
+                translation_storage.add_component_unchecked(
+                    child_id,
+                    Translation {
+                        value: Vector3::set(1.0, 2.0, 3.0),
+                    },
+                );
+            },
+        );
+
+        let game = registry.snapshot(editor, WorldFlag::GAME).unwrap();
+
+        let copied = registry
+            .components
+            .collect_entities(&registry.get(game).unwrap().value);
+
+        assert_eq!(copied.len(), 2);
+
+        registry.get(game).unwrap().value.run(
+            |parent_storage: View<Parent>,
+             children_storage: View<Children>,
+             translation_storage: View<Translation>| {
+                let (copied_child, _) = (&parent_storage, &translation_storage)
+                    .iter()
+                    .with_id()
+                    .next()
+                    .unwrap()
+                    .1;
+
+                let copied_root = EntityId::from_inner(copied_child.value).unwrap();
+
+                assert_eq!(children_storage.get(copied_root).unwrap().value.len(), 1);
+            },
+        );
+
+        // Moving the child alone detaches it in the editor, it arrives as a root.
+        let moved = registry.move_entities(editor, game, &[child]).unwrap();
+
+        registry.get(editor).unwrap().value.run(
+            |entities: EntitiesView, children_storage: View<Children>| {
+                assert!(!entities.is_alive(EntityId::from_inner(child).unwrap()));
+                assert!(children_storage
+                    .get(EntityId::from_inner(root).unwrap())
+                    .is_err());
+            },
+        );
+
+        registry
+            .get(game)
+            .unwrap()
+            .value
+            .run(|parent_storage: View<Parent>| {
+                assert!(parent_storage
+                    .get(EntityId::from_inner(moved[0]).unwrap())
+                    .is_err());
+            });
+    }
+}
@@ -0,0 +1,901 @@
+use crate::{ScriptError, Time};
+
+use fabled_component::{All, Component, Modification, Unique};
+use fabled_math::matrix4x4_math::{compose_trs_mat4, transform_vector3_mat4};
+use fabled_math::quaternion_math::{
+    forward_vec3, from_angle_axis_quat, inverse_quat, right_vec3, up_vec3,
+};
+use fabled_math::vector_math::{cross, dot, length, normalize};
+use fabled_math::{Quaternion, Vector3};
+use fabled_transform::{LocalToWorld, Rotation, Scale, Translation};
+
+use mlua::{
+    Function, HookTriggers, Lua, LuaOptions, MetaMethod, MultiValue, RegistryKey, StdLib, Table,
+    UserData, UserDataFields, UserDataMethods, Value,
+};
+
+use shipyard::*;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+// Base library functions that reach the file system, load arbitrary code or
+// get around the read only tables shared by every script.
+const UNSAFE_GLOBALS: [&str; 11] = [
+    "dofile",
+    "loadfile",
+    "load",
+    "collectgarbage",
+    "rawget",
+    "rawset",
+    "rawequal",
+    "rawlen",
+    "getmetatable",
+    "setmetatable",
+    "_G",
+];
+
+// Library tables every script reads from, replaced by read only views.
+const SHARED_LIBRARIES: [&str; 5] = ["coroutine", "table", "string", "utf8", "math"];
+
+// The instruction budget is checked every HOOK_INTERVAL instructions.
+const HOOK_INTERVAL: u32 = 1000;
+
+pub const DEFAULT_INSTRUCTION_LIMIT: u64 = 10_000_000;
+pub const DEFAULT_MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+// Runs the script at path for this entity. The script may define
+// on_start(self), on_update(self, delta) and on_reload(self), self being a
+// table that lives as long as the component and holds self.entity.
+#[derive(Clone)]
+pub struct Script {
+    pub path: PathBuf,
+}
+
+impl Display for Script {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Script({})", self.path.display())
+    }
+}
+
+impl Component for Script {
+    type Tracking = All;
+}
+
+#[derive(Copy, Clone)]
+pub struct LuaVector3(pub Vector3);
+
+impl UserData for LuaVector3 {
+    fn add_fields<'lua, F: UserDataFields<'lua, Self>>(fields: &mut F) {
+        fields.add_field_method_get("x", |_, this| Ok(this.0.x()));
+        fields.add_field_method_get("y", |_, this| Ok(this.0.y()));
+        fields.add_field_method_get("z", |_, this| Ok(this.0.z()));
+    }
+
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("length", |_, this, ()| Ok(length(this.0.value)));
+        methods.add_method("normalize", |_, this, ()| {
+            Ok(LuaVector3(Vector3 {
+                value: normalize(this.0.value),
+            }))
+        });
+        methods.add_method("dot", |_, this, other: LuaVector3| {
+            Ok(dot(this.0.value, other.0.value))
+        });
+        methods.add_method("cross", |_, this, other: LuaVector3| {
+            Ok(LuaVector3(Vector3 {
+                value: cross(this.0.value, other.0.value),
+            }))
+        });
+
+        methods.add_meta_method(MetaMethod::Add, |_, this, other: LuaVector3| {
+            Ok(LuaVector3(this.0 + other.0))
+        });
+        methods.add_meta_method(MetaMethod::Sub, |_, this, other: LuaVector3| {
+            Ok(LuaVector3(this.0 - other.0))
+        });
+        methods.add_meta_method(MetaMethod::Mul, |_, this, scalar: f32| {
+            Ok(LuaVector3(this.0 * scalar))
+        });
+        methods.add_meta_method(MetaMethod::Unm, |_, this, ()| Ok(LuaVector3(this.0 * -1.0)));
+        methods.add_meta_method(MetaMethod::ToString, |_, this, ()| {
+            Ok(format!("{}", this.0))
+        });
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct LuaQuaternion(pub Quaternion);
+
+impl UserData for LuaQuaternion {
+    fn add_fields<'lua, F: UserDataFields<'lua, Self>>(fields: &mut F) {
+        fields.add_field_method_get("x", |_, this| Ok(this.0.i()));
+        fields.add_field_method_get("y", |_, this| Ok(this.0.j()));
+        fields.add_field_method_get("z", |_, this| Ok(this.0.k()));
+        fields.add_field_method_get("w", |_, this| Ok(this.0.w()));
+    }
+
+    fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+        methods.add_method("normalize", |_, this, ()| {
+            Ok(LuaQuaternion(Quaternion {
+                value: normalize(this.0.value),
+            }))
+        });
+        methods.add_method("inverse", |_, this, ()| {
+            Ok(LuaQuaternion(inverse_quat(this.0)))
+        });
+        methods.add_method("forward", |_, this, ()| {
+            Ok(LuaVector3(forward_vec3(this.0)))
+        });
+        methods.add_method("right", |_, this, ()| Ok(LuaVector3(right_vec3(this.0))));
+        methods.add_method("up", |_, this, ()| Ok(LuaVector3(up_vec3(this.0))));
+        methods.add_method("rotate", |_, this, vector: LuaVector3| {
+            let rotation = compose_trs_mat4(Vector3::ZERO, this.0, Vector3::ONE);
+
+            Ok(LuaVector3(transform_vector3_mat4(rotation, vector.0)))
+        });
+
+        methods.add_meta_method(MetaMethod::Mul, |_, this, other: LuaQuaternion| {
+            Ok(LuaQuaternion(this.0 * other.0))
+        });
+        methods.add_meta_method(MetaMethod::ToString, |_, this, ()| {
+            Ok(format!("{}", this.0))
+        });
+    }
+}
+
+// Modification time and size, coarse file system clocks can give two quick
+// writes the same modification time.
+type FileStamp = Option<(SystemTime, u64)>;
+
+struct LoadedScript {
+    stamp: FileStamp,
+    environment: RegistryKey,
+}
+
+// One Lua state shared by every script. Each script file runs in its own
+// environment that falls back to a read only view of the globals, so scripts
+// can not see each other's variables nor change what the others see. Every
+// chunk and callback gets instruction_limit instructions before it is aborted
+// and the whole state is capped at DEFAULT_MEMORY_LIMIT bytes. Lua is not
+// Send, add it with add_unique_non_send_sync.
+pub struct LuaSubsystem {
+    lua: Lua,
+    sandbox: RegistryKey,
+    scripts: HashMap<PathBuf, LoadedScript>,
+    instances: HashMap<u64, RegistryKey>,
+    budget: Arc<AtomicU64>,
+    pub instruction_limit: u64,
+    // Everything that failed during the last run, scripts keep running.
+    pub errors: Vec<ScriptError>,
+}
+
+impl Unique for LuaSubsystem {
+    type Tracking = Modification;
+}
+
+impl LuaSubsystem {
+    pub fn new() -> Result<Self, ScriptError> {
+        let lua = Lua::new_with(
+            StdLib::COROUTINE | StdLib::TABLE | StdLib::STRING | StdLib::UTF8 | StdLib::MATH,
+            LuaOptions::new(),
+        )?;
+
+        lua.set_memory_limit(DEFAULT_MEMORY_LIMIT)?;
+
+        // The budget only reaches zero once it is spent, see catching below.
+        let budget = Arc::new(AtomicU64::new(DEFAULT_INSTRUCTION_LIMIT));
+        let hook_budget = Arc::clone(&budget);
+
+        lua.set_hook(
+            HookTriggers {
+                every_nth_instruction: Some(HOOK_INTERVAL),
+                ..HookTriggers::default()
+            },
+            move |_, _| {
+                let remaining = hook_budget.load(Ordering::Relaxed);
+
+                if remaining <= HOOK_INTERVAL as u64 {
+                    hook_budget.store(0, Ordering::Relaxed);
+
+                    return Err(budget_error());
+                }
+
+                hook_budget.store(remaining - HOOK_INTERVAL as u64, Ordering::Relaxed);
+
+                Ok(())
+            },
+        )?;
+
+        let globals = lua.globals();
+
+        for name in UNSAFE_GLOBALS {
+            globals.set(name, Value::Nil)?;
+        }
+
+        // The hook error is an ordinary runtime error, everything able to
+        // catch it raises it again once the budget is spent.
+        for name in ["pcall", "xpcall"] {
+            let function: Function = globals.get(name)?;
+
+            globals.set(name, rethrow_when_spent(&lua, function, &budget)?)?;
+        }
+
+        let coroutine: Table = globals.get("coroutine")?;
+        let resume: Function = coroutine.get("resume")?;
+
+        coroutine.set("resume", rethrow_when_spent(&lua, resume, &budget)?)?;
+
+        for name in SHARED_LIBRARIES {
+            let library: Table = globals.get(name)?;
+
+            globals.set(name, read_only(&lua, library)?)?;
+        }
+
+        globals.set(
+            "vec3",
+            lua.create_function(|_, (x, y, z): (f32, f32, f32)| {
+                Ok(LuaVector3(Vector3::set(x, y, z)))
+            })?,
+        )?;
+        globals.set(
+            "quat",
+            lua.create_function(|_, (x, y, z, w): (f32, f32, f32, f32)| {
+                Ok(LuaQuaternion(Quaternion::set(x, y, z, w)))
+            })?,
+        )?;
+        globals.set(
+            "quat_angle_axis",
+            lua.create_function(|_, (axis, angle): (LuaVector3, f32)| {
+                Ok(LuaQuaternion(from_angle_axis_quat(axis.0, angle)))
+            })?,
+        )?;
+
+        let sandbox = read_only(&lua, globals)?;
+        let sandbox = lua.create_registry_value(sandbox)?;
+
+        Ok(Self {
+            lua,
+            sandbox,
+            scripts: HashMap::new(),
+            instances: HashMap::new(),
+            budget,
+            instruction_limit: DEFAULT_INSTRUCTION_LIMIT,
+            errors: Vec::new(),
+        })
+    }
+
+    pub fn set_memory_limit(&self, bytes: usize) -> Result<(), ScriptError> {
+        self.lua.set_memory_limit(bytes)?;
+
+        Ok(())
+    }
+
+    // Runs a chunk in a fresh environment of the sandbox and returns its
+    // result, for consoles and tests.
+    pub fn eval<'lua, R: mlua::FromLua<'lua>>(&'lua self, source: &str) -> Result<R, ScriptError> {
+        self.refuel();
+
+        Ok(self
+            .lua
+            .load(source)
+            .set_environment(self.environment()?)?
+            .eval()?)
+    }
+
+    fn refuel(&self) {
+        self.budget.store(self.instruction_limit, Ordering::Relaxed);
+    }
+
+    fn environment(&self) -> mlua::Result<Table> {
+        let environment = self.lua.create_table()?;
+        let metatable = self.lua.create_table()?;
+
+        metatable.set("__index", self.lua.registry_value::<Table>(&self.sandbox)?)?;
+        environment.set_metatable(Some(metatable));
+
+        Ok(environment)
+    }
+
+    // Loads the script, or reloads it when it is already known. On failure the
+    // previous version stays active.
+    pub fn load_script(&mut self, path: &Path) -> Result<(), ScriptError> {
+        let source = std::fs::read_to_string(path).map_err(|source| ScriptError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let stamp = file_stamp(path);
+
+        let environment =
+            self.create_environment(path, &source)
+                .map_err(|source| ScriptError::Script {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+
+        let environment = self.lua.create_registry_value(environment)?;
+
+        if let Some(previous) = self
+            .scripts
+            .insert(path.to_path_buf(), LoadedScript { stamp, environment })
+        {
+            self.lua.remove_registry_value(previous.environment)?;
+        }
+
+        Ok(())
+    }
+
+    fn create_environment(&self, path: &Path, source: &str) -> mlua::Result<Table> {
+        let environment = self.environment()?;
+
+        self.refuel();
+
+        self.lua
+            .load(source)
+            .set_name(&path.display().to_string())?
+            .set_environment(environment.clone())?
+            .exec()?;
+
+        Ok(environment)
+    }
+
+    // Reloads every script whose file changed on disk, returning the paths
+    // that were reloaded. Failures are added to errors.
+    pub fn reload_changed(&mut self) -> Vec<PathBuf> {
+        let changed: Vec<PathBuf> = self
+            .scripts
+            .iter()
+            .filter(|(path, script)| {
+                let stamp = file_stamp(path);
+
+                stamp.is_some() && stamp != script.stamp
+            })
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        let mut reloaded: Vec<PathBuf> = Vec::with_capacity(changed.len());
+
+        for path in changed {
+            match self.load_script(&path) {
+                Ok(()) => reloaded.push(path),
+                Err(error) => {
+                    // Do not retry the broken file every frame.
+                    if let Some(script) = self.scripts.get_mut(&path) {
+                        script.stamp = file_stamp(&path);
+                    }
+
+                    self.errors.push(error);
+                }
+            }
+        }
+
+        reloaded
+    }
+
+    fn instance(&mut self, entity: u64) -> mlua::Result<Table> {
+        if let Some(key) = self.instances.get(&entity) {
+            return self.lua.registry_value(key);
+        }
+
+        let instance = self.lua.create_table()?;
+        instance.set("entity", entity as i64)?;
+
+        self.instances
+            .insert(entity, self.lua.create_registry_value(instance.clone())?);
+
+        Ok(instance)
+    }
+
+    fn drop_instance(&mut self, entity: u64) {
+        if let Some(key) = self.instances.remove(&entity) {
+            let _ = self.lua.remove_registry_value(key);
+        }
+    }
+
+    fn callback(&self, path: &Path, name: &str) -> mlua::Result<Option<Function>> {
+        match self.scripts.get(path) {
+            Some(script) => self
+                .lua
+                .registry_value::<Table>(&script.environment)?
+                .get(name),
+            None => Ok(None),
+        }
+    }
+}
+
+// A table reading through to target and refusing writes.
+fn read_only<'lua>(lua: &'lua Lua, target: Table<'lua>) -> mlua::Result<Table<'lua>> {
+    let proxy = lua.create_table()?;
+    let metatable = lua.create_table()?;
+
+    metatable.set("__index", target)?;
+    metatable.set(
+        "__newindex",
+        lua.create_function(|_, _: (Value, Value, Value)| -> mlua::Result<()> {
+            Err(mlua::Error::RuntimeError(String::from(
+                "attempt to modify a read only table",
+            )))
+        })?,
+    )?;
+    proxy.set_metatable(Some(metatable));
+
+    Ok(proxy)
+}
+
+fn rethrow_when_spent<'lua>(
+    lua: &'lua Lua,
+    function: Function<'lua>,
+    budget: &Arc<AtomicU64>,
+) -> mlua::Result<Function<'lua>> {
+    let function = lua.create_registry_value(function)?;
+    let budget = Arc::clone(budget);
+
+    lua.create_function(move |lua, arguments: MultiValue| {
+        let results: MultiValue = lua.registry_value::<Function>(&function)?.call(arguments)?;
+
+        if budget.load(Ordering::Relaxed) == 0 {
+            return Err(budget_error());
+        }
+
+        Ok(results)
+    })
+}
+
+#[inline]
+fn budget_error() -> mlua::Error {
+    mlua::Error::RuntimeError(String::from("instruction limit exceeded"))
+}
+
+fn file_stamp(path: &Path) -> FileStamp {
+    let metadata = std::fs::metadata(path).ok()?;
+
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+#[inline]
+fn to_entity_id(entity: i64) -> EntityId {
+    EntityId::from_inner(entity as u64).unwrap_or_else(EntityId::dead)
+}
+
+// Calls the script callbacks of every entity with a Script. During the calls
+// the global table world exposes create_entity, add_script and get and set
+// functions for Translation, Rotation and Scale. They are only valid inside
+// callbacks, add_script and the setters return false for dead entities.
+#[allow(clippy::too_many_arguments)]
+pub fn run_lua_script_system(
+    mut lua_subsystem: NonSendSync<UniqueViewMut<LuaSubsystem>>,
+    time: UniqueView<Time>,
+    mut entities: EntitiesViewMut,
+    mut script_storage: ViewMut<Script>,
+    mut translation_storage: ViewMut<Translation>,
+    mut rotation_storage: ViewMut<Rotation>,
+    mut scale_storage: ViewMut<Scale>,
+    mut local_to_world_storage: ViewMut<LocalToWorld>,
+) {
+    lua_subsystem.errors.clear();
+
+    let reloaded: Vec<PathBuf> = lua_subsystem.reload_changed();
+
+    let stopped: Vec<EntityId> = script_storage.removed_or_deleted().collect();
+
+    for entity_id in stopped {
+        lua_subsystem.drop_instance(entity_id.inner());
+    }
+
+    let scripted: Vec<(EntityId, PathBuf, bool)> = script_storage
+        .iter()
+        .with_id()
+        .map(|(entity_id, script)| {
+            let started = lua_subsystem.instances.contains_key(&entity_id.inner())
+                && !script_storage.is_inserted_or_modified(entity_id);
+
+            (entity_id, script.path.clone(), started)
+        })
+        .collect();
+
+    let mut calls: Vec<(u64, PathBuf, bool)> = Vec::with_capacity(scripted.len());
+
+    for (entity_id, path, started) in scripted {
+        if !lua_subsystem.scripts.contains_key(&path) {
+            if let Err(error) = lua_subsystem.load_script(&path) {
+                lua_subsystem.errors.push(error);
+                continue;
+            }
+        }
+
+        if let Err(error) = lua_subsystem.instance(entity_id.inner()) {
+            lua_subsystem.errors.push(error.into());
+            continue;
+        }
+
+        calls.push((entity_id.inner(), path, started));
+    }
+
+    let lua_subsystem = &mut *lua_subsystem;
+    let mut errors: Vec<ScriptError> = Vec::new();
+
+    let entities = RefCell::new(&mut entities);
+    let script_storage = RefCell::new(&mut script_storage);
+    let translation_storage = RefCell::new(&mut translation_storage);
+    let rotation_storage = RefCell::new(&mut rotation_storage);
+    let scale_storage = RefCell::new(&mut scale_storage);
+    let local_to_world_storage = RefCell::new(&mut local_to_world_storage);
+
+    let result = lua_subsystem.lua.scope(|scope| {
+        let world = lua_subsystem.lua.create_table()?;
+
+        world.set(
+            "create_entity",
+            scope.create_function(|_, ()| {
+                let mut translation_storage = translation_storage.borrow_mut();
+                let mut rotation_storage = rotation_storage.borrow_mut();
+                let mut scale_storage = scale_storage.borrow_mut();
+                let mut local_to_world_storage = local_to_world_storage.borrow_mut();
+
+                let entity_id = entities.borrow_mut().add_entity(
+                    (
+                        &mut **translation_storage,
+                        &mut **rotation_storage,
+                        &mut **scale_storage,
+                        &mut **local_to_world_storage,
+                    ),
+                    (
+                        Translation::default(),
+                        Rotation::default(),
+                        Scale::default(),
+                        LocalToWorld::default(),
+                    ),
+                );
+
+                Ok(entity_id.inner() as i64)
+            })?,
+        )?;
+
+        world.set(
+            "add_script",
+            scope.create_function(|_, (entity, path): (i64, String)| {
+                let entities = entities.borrow();
+                let entity_id = to_entity_id(entity);
+
+                // Scripts pass any integer, only live entities may get storage.
+                if !entities.is_alive(entity_id) {
+                    return Ok(false);
+                }
+
+                entities.add_component(
+                    entity_id,
+                    &mut **script_storage.borrow_mut(),
+                    Script {
+                        path: PathBuf::from(path),
+                    },
+                );
+
+                Ok(true)
+            })?,
+        )?;
+
+        world.set(
+            "translation",
+            scope.create_function(|_, entity: i64| {
+                Ok((&**translation_storage.borrow())
+                    .get(to_entity_id(entity))
+                    .ok()
+                    .map(|translation| LuaVector3(translation.value)))
+            })?,
+        )?;
+
+        world.set(
+            "set_translation",
+            scope.create_function(|_, (entity, value): (i64, LuaVector3)| {
+                let mut translation_storage = translation_storage.borrow_mut();
+
+                Ok(
+                    match (&mut **translation_storage).get(to_entity_id(entity)) {
+                        Ok(mut translation) => {
+                            translation.value = value.0;
+                            true
+                        }
+                        Err(_) => false,
+                    },
+                )
+            })?,
+        )?;
+
+        world.set(
+            "rotation",
+            scope.create_function(|_, entity: i64| {
+                Ok((&**rotation_storage.borrow())
+                    .get(to_entity_id(entity))
+                    .ok()
+                    .map(|rotation| LuaQuaternion(rotation.value)))
+            })?,
+        )?;
+
+        world.set(
+            "set_rotation",
+            scope.create_function(|_, (entity, value): (i64, LuaQuaternion)| {
+                let mut rotation_storage = rotation_storage.borrow_mut();
+
+                Ok(match (&mut **rotation_storage).get(to_entity_id(entity)) {
+                    Ok(mut rotation) => {
+                        rotation.value = value.0;
+                        true
+                    }
+                    Err(_) => false,
+                })
+            })?,
+        )?;
+
+        world.set(
+            "scale",
+            scope.create_function(|_, entity: i64| {
+                Ok((&**scale_storage.borrow())
+                    .get(to_entity_id(entity))
+                    .ok()
+                    .map(|scale| LuaVector3(scale.value)))
+            })?,
+        )?;
+
+        world.set(
+            "set_scale",
+            scope.create_function(|_, (entity, value): (i64, LuaVector3)| {
+                let mut scale_storage = scale_storage.borrow_mut();
+
+                Ok(match (&mut **scale_storage).get(to_entity_id(entity)) {
+                    Ok(mut scale) => {
+                        scale.value = value.0;
+                        true
+                    }
+                    Err(_) => false,
+                })
+            })?,
+        )?;
+
+        lua_subsystem.lua.globals().set("world", world)?;
+
+        for (entity, path, started) in &calls {
+            let instance: Table = lua_subsystem
+                .lua
+                .registry_value(&lua_subsystem.instances[entity])?;
+
+            let call = |name: &str, with_delta: bool| -> mlua::Result<()> {
+                if let Some(function) = lua_subsystem.callback(path, name)? {
+                    lua_subsystem.refuel();
+
+                    if with_delta {
+                        function.call::<_, ()>((instance.clone(), time.delta))?;
+                    } else {
+                        function.call::<_, ()>(instance.clone())?;
+                    }
+                }
+
+                Ok(())
+            };
+
+            let outcome = if !started {
+                call("on_start", false)
+            } else if reloaded.contains(path) {
+                call("on_reload", false)
+            } else {
+                Ok(())
+            }
+            .and_then(|_| call("on_update", true));
+
+            if let Err(source) = outcome {
+                errors.push(ScriptError::Script {
+                    path: path.clone(),
+                    source,
+                });
+            }
+        }
+
+        lua_subsystem.lua.globals().set("world", Value::Nil)?;
+
+        Ok(())
+    });
+
+    if let Err(error) = result {
+        errors.push(error.into());
+    }
+
+    lua_subsystem.errors.append(&mut errors);
+}
+
+#[cfg(test)]
+mod lua_system_test {
+    use crate::{run_lua_script_system, LuaSubsystem, Script, Time};
+    use fabled_transform::{LocalToWorld, Rotation, Scale, Translation};
+    use shipyard::{Get, IntoIter, NonSendSync, UniqueView, View, World};
+
+    use std::path::PathBuf;
+
+    // Unique per process and test, test binaries may run side by side.
+    fn script_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("fabled_{}_{}.lua", std::process::id(), name))
+    }
+
+    // Rewrites keep a different size so the reload is seen even when the
+    // modification time does not move.
+    fn write_script(path: &PathBuf, source: &str) {
+        std::fs::write(path, source).unwrap();
+    }
+
+    fn errors(world: &World) -> usize {
+        world
+            .borrow::<NonSendSync<UniqueView<LuaSubsystem>>>()
+            .unwrap()
+            .errors
+            .len()
+    }
+
+    #[test]
+    fn sandboxed_globals() {
+        let lua_subsystem = LuaSubsystem::new().unwrap();
+
+        assert!(lua_subsystem
+            .eval::<bool>("return io == nil and os == nil and require == nil and load == nil")
+            .unwrap());
+        assert_eq!(
+            lua_subsystem
+                .eval::<f32>("return (vec3(1, 2, 2) * 2):length()")
+                .unwrap(),
+            6.0
+        );
+    }
+
+    #[test]
+    fn shared_tables_are_read_only() {
+        let lua_subsystem = LuaSubsystem::new().unwrap();
+
+        assert!(lua_subsystem
+            .eval::<bool>(
+                "return _G == nil and rawset == nil and rawget == nil and setmetatable == nil \
+                 and getmetatable == nil"
+            )
+            .unwrap());
+        assert!(lua_subsystem.eval::<()>("string.upper = nil").is_err());
+        assert!(lua_subsystem.eval::<()>("math.pi = 3").is_err());
+
+        // Globals land in the chunk's own environment.
+        lua_subsystem.eval::<()>("vec3 = nil leaked = 1").unwrap();
+
+        assert!(lua_subsystem
+            .eval::<bool>("return leaked == nil and vec3 ~= nil and string.upper('a') == 'A'")
+            .unwrap());
+    }
+
+    #[test]
+    fn runaway_scripts_are_aborted() {
+        let lua_subsystem = LuaSubsystem::new().unwrap();
+
+        assert!(lua_subsystem.eval::<()>("while true do end").is_err());
+        assert!(lua_subsystem
+            .eval::<()>("while true do pcall(function() while true do end end) end")
+            .is_err());
+        assert!(lua_subsystem
+            .eval::<()>("while true do xpcall(function() while true do end end, tostring) end")
+            .is_err());
+        assert!(lua_subsystem
+            .eval::<()>(
+                "while true do coroutine.resume(coroutine.create(function() while true do end \
+                 end)) end"
+            )
+            .is_err());
+        assert!(lua_subsystem
+            .eval::<()>("local text = string.rep('x', 1 << 30)")
+            .is_err());
+
+        // The budget is refilled for the next chunk.
+        assert_eq!(lua_subsystem.eval::<i32>("return 1 + 1").unwrap(), 2);
+    }
+
+    #[test]
+    fn scripts_drive_entities_and_hot_reload() {
+        let path = script_path("scripts_drive_entities_and_hot_reload");
+
+        write_script(
+            &path,
+            r#"
+            function on_start(self)
+                self.spawned = world.create_entity()
+                world.set_scale(self.spawned, vec3(2, 2, 2))
+
+                assert(world.add_script(-1, "invalid.lua") == false)
+                assert(world.add_script(1 << 40, "invalid.lua") == false)
+            end
+
+            function on_update(self, delta)
+                local position = world.translation(self.entity)
+                world.set_translation(self.entity, position + vec3(delta, 0, 0))
+            end
+            "#,
+        );
+
+        let mut world = World::new();
+
+        world
+            .add_unique(Time {
+                delta: 0.5,
+                ..Time::default()
+            })
+            .unwrap();
+        world
+            .add_unique_non_send_sync(LuaSubsystem::new().unwrap())
+            .unwrap();
+
+        let entity = world.add_entity((
+            Translation::default(),
+            Rotation::default(),
+            Scale::default(),
+            LocalToWorld::default(),
+            Script { path: path.clone() },
+        ));
+
+        shipyard::Workload::builder("lua_test")
+            .with_system(&run_lua_script_system)
+            .add_to_world(&world)
+            .unwrap();
+
+        world.run_workload("lua_test").unwrap();
+        world.run_workload("lua_test").unwrap();
+
+        assert_eq!(errors(&world), 0);
+
+        world.run(
+            |translation_storage: View<Translation>, scale_storage: View<Scale>| {
+                assert_eq!(
+                    translation_storage
+                        .get(entity)
+                        .unwrap()
+                        .value
+                        .to_primitive(),
+                    [1.0, 0.0, 0.0]
+                );
+                assert_eq!(
+                    scale_storage
+                        .iter()
+                        .filter(|scale| scale.value.to_primitive() == [2.0, 2.0, 2.0])
+                        .count(),
+                    1
+                );
+            },
+        );
+
+        // The reloaded script moves along y, state on self survives.
+        write_script(
+            &path,
+            r#"
+            function on_reload(self)
+                assert(self.spawned ~= nil)
+            end
+
+            function on_update(self, delta)
+                world.set_translation(self.entity, world.translation(self.entity) + vec3(0, delta, 0))
+            end
+            "#,
+        );
+
+        world.run_workload("lua_test").unwrap();
+
+        assert_eq!(errors(&world), 0);
+
+        world.run(|translation_storage: View<Translation>| {
+            assert_eq!(
+                translation_storage
+                    .get(entity)
+                    .unwrap()
+                    .value
+                    .to_primitive(),
+                [1.0, 0.5, 0.0]
+            );
+        });
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,7 +1,8 @@
 use crate::PerspectiveCameraViewMut;
+use fabled_math::frustum_math::slice_sphere_frustum;
 use fabled_math::matrix4x4_math::inverse_mat4;
-use fabled_math::vector_math::{length, normalize, pow, round};
-use fabled_math::{from_euler_quat, EulerOrder, Matrix4x4, Swizzles4, Vector3, Vector4};
+use fabled_math::vector_math::{normalize, pow, round};
+use fabled_math::{from_euler_quat, EulerOrder, Matrix4x4, Vector3, Vector4};
 use fabled_render::camera::{
     compute_look_at_matrix, compute_orthographic_matrix, compute_perspective_matrix, AspectRatio,
     ClippingPlane, Fov, RenderProjection, RenderView,
@@ -54,20 +55,9 @@ fn compute_csm_frustum_system(
     view: UniqueView<RenderView>,
     mut frustum: UniqueViewMut<CascadeFrustum>,
 ) {
-    let inverse_view_projection = inverse_mat4(view.view_matrix * projection.projection_matrix);
+    let inverse_view_projection = inverse_mat4(projection.projection_matrix * view.view_matrix);
 
     for cascade_index in 0..MAX_CASCADE_SIZE {
-        let mut frustum_corner_ws = [
-            Vector3::set(-1.0, 1.0, 0.0),
-            Vector3::set(1.0, 1.0, 0.0),
-            Vector3::set(1.0, -1.0, 0.0),
-            Vector3::set(-1.0, -1.0, 0.0),
-            Vector3::set(-1.0, 1.0, 1.0),
-            Vector3::set(1.0, 1.0, 1.0),
-            Vector3::set(1.0, -1.0, 1.0),
-            Vector3::set(-1.0, -1.0, 1.0),
-        ];
-
         let prev_split_distance = if cascade_index == 0 {
             0.0
         } else {
@@ -76,43 +66,14 @@ fn compute_csm_frustum_system(
 
         let current_split_distance = cascade_split.splits.value[cascade_index];
 
-        for index in 0..8 {
-            // temp solution
-            let inverse_point = inverse_view_projection
-                * Vector4::set(
-                    frustum_corner_ws[index].x(),
-                    frustum_corner_ws[index].y(),
-                    frustum_corner_ws[index].z(),
-                    1.0,
-                );
-
-            frustum_corner_ws[index] = inverse_point.xyz() / inverse_point.w();
-        }
-
-
-        for index in 0..4 {
-            let corner_ray = frustum_corner_ws[index + 4] - frustum_corner_ws[index];
-            let near_corner_ray = corner_ray * prev_split_distance;
-            let far_corner_ray = corner_ray * current_split_distance;
-            frustum_corner_ws[index + 4] = frustum_corner_ws[i] + far_corner_ray;
-            frustum_corner_ws[i] = frustum_corner_ws[index] + near_corner_ray;
-        }
-
-        let mut frustum_center = Vector3::ZERO;
-
-        for index in 0..8 {
-            frustum_center += frustum_corner_ws[index];
-        }
-        frustum_center *= 0.125;
-
-        let mut sphere_radius = 0.0f32;
-
-        for index in 0..8 {
-            let dist = length((frustum_corner_ws[index] - frustum_center).value);
-            sphere_radius = sphere_radius.max(dist);
-        }
+        let cascade_sphere = slice_sphere_frustum(
+            inverse_view_projection,
+            prev_split_distance,
+            current_split_distance,
+        );
 
-        sphere_radius = (sphere_radius * 16.0).ceil() * 0.0625;
+        let frustum_center = cascade_sphere.center();
+        let sphere_radius = (cascade_sphere.radius() * 16.0).ceil() * 0.0625;
 
         let max_extent = Vector3::broadcast(sphere_radius);
         let min_extent = -max_extent;
@@ -1,19 +1,130 @@
+use fabled_math::matrix4x4_math::compose_trs_mat4;
+use fabled_math::Matrix4x4;
 use fabled_transform::{
-    decompose_transformation_matrix, get_transformation_matrix, transform, Frozen, LocalToWorld,
-    Parent, Rotation, Scale, ScaleType, Translation,
+    BakedLocalToWorld, Frozen, LocalToWorld, Parent, Rotation, Scale, Translation,
 };
-use rayon::iter::ParallelIterator;
+
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use shipyard::*;
 
+use std::collections::{HashMap, HashSet};
+
+#[inline]
+fn compose_local_matrix(
+    entity_id: EntityId,
+    translation_storage: &View<Translation>,
+    rotation_storage: &View<Rotation>,
+    scale_storage: &View<Scale>,
+) -> Matrix4x4 {
+    let translation = translation_storage
+        .get(entity_id)
+        .copied()
+        .unwrap_or_default();
+    let rotation = rotation_storage.get(entity_id).copied().unwrap_or_default();
+    let scale = scale_storage.get(entity_id).copied().unwrap_or_default();
+
+    compose_trs_mat4(translation.value, rotation.value, scale.value)
+}
+
+#[inline]
+fn is_local_dirty(
+    entity_id: EntityId,
+    translation_storage: &View<Translation>,
+    rotation_storage: &View<Rotation>,
+    scale_storage: &View<Scale>,
+) -> bool {
+    translation_storage.is_inserted_or_modified(entity_id)
+        || rotation_storage.is_inserted_or_modified(entity_id)
+        || scale_storage.is_inserted_or_modified(entity_id)
+}
+
+#[inline]
+fn parent_entity(parent: &Parent) -> EntityId {
+    EntityId::from_inner(parent.value).unwrap_or_else(EntityId::dead)
+}
+
+// Number of Parent links above the entity, memoized across calls. Returns
+// None when the chain loops back on itself, those entities are skipped.
+fn hierarchy_depth(
+    entity_id: EntityId,
+    parent_storage: &View<Parent>,
+    depth_cache: &mut HashMap<EntityId, Option<usize>>,
+) -> Option<usize> {
+    let mut chain: Vec<EntityId> = Vec::new();
+    let mut current = entity_id;
+
+    let base_depth = loop {
+        if let Some(depth) = depth_cache.get(&current) {
+            break *depth;
+        }
+
+        match parent_storage.get(current) {
+            Ok(parent) => {
+                if chain.contains(&current) {
+                    break None;
+                }
+
+                chain.push(current);
+                current = parent_entity(parent);
+            }
+            Err(_) => break Some(0),
+        }
+    };
+
+    // A cycle poisons every entity leading into it as well.
+    for (offset, chain_entity) in chain.iter().rev().enumerate() {
+        depth_cache.insert(*chain_entity, base_depth.map(|depth| depth + offset + 1));
+    }
+
+    depth_cache.get(&entity_id).copied().flatten()
+}
+
+// Drops the cached matrix of unfrozen entities, the local world systems then
+// recompute them through the removed Frozen. Frozen entities whose
+// LocalToWorld was overwritten since they were baked get the baked matrix back,
+// their children pick it up as a modified parent.
+pub fn invalidate_unfrozen_system(
+    frozen_storage: View<Frozen>,
+    mut baked_storage: ViewMut<BakedLocalToWorld>,
+    mut local_to_world_storage: ViewMut<LocalToWorld>,
+) {
+    for entity_id in frozen_storage.removed() {
+        baked_storage.remove(entity_id);
+    }
+
+    let overwritten: Vec<(EntityId, Matrix4x4)> = (&baked_storage, &local_to_world_storage)
+        .iter()
+        .with_id()
+        .filter(|(_, (baked, local_world))| baked.value != local_world.value)
+        .map(|(entity_id, (baked, _))| (entity_id, baked.value))
+        .collect();
+
+    for (entity_id, baked) in overwritten {
+        if let Ok(mut local_world) = (&mut local_to_world_storage).get(entity_id) {
+            local_world.value = baked;
+        }
+    }
+}
+
+// Roots only, children are handled level by level in the parent system.
+// Frozen roots are composed once and cached in BakedLocalToWorld.
 pub fn calculate_local_world_system(
     translation_storage: View<Translation>,
     rotation_storage: View<Rotation>,
     scale_storage: View<Scale>,
     frozen_storage: View<Frozen>,
     parent_storage: View<Parent>,
+    mut baked_storage: ViewMut<BakedLocalToWorld>,
     mut local_to_world_storage: ViewMut<LocalToWorld>,
 ) {
+    let newly_inserted: HashSet<EntityId> = local_to_world_storage
+        .inserted()
+        .iter()
+        .with_id()
+        .map(|(entity_id, _)| entity_id)
+        .collect();
+
     (
         &translation_storage,
         &rotation_storage,
@@ -23,126 +134,211 @@ pub fn calculate_local_world_system(
         !&frozen_storage,
     )
         .par_iter()
-        .for_each(|(translation, rotation, scale, mut local_world, _, _)| {
-            local_world.value = get_transformation_matrix(*translation, *rotation, *scale);
-        });
+        .with_id()
+        .filter(|(entity_id, _)| {
+            is_local_dirty(
+                *entity_id,
+                &translation_storage,
+                &rotation_storage,
+                &scale_storage,
+            ) || parent_storage.is_removed(*entity_id)
+                || frozen_storage.is_removed(*entity_id)
+                || newly_inserted.contains(entity_id)
+        })
+        .for_each(
+            |(_, (translation, rotation, scale, mut local_world, _, _))| {
+                local_world.value =
+                    compose_trs_mat4(translation.value, rotation.value, scale.value);
+            },
+        );
+
+    let unbaked: Vec<(EntityId, Matrix4x4)> = (
+        &translation_storage,
+        &rotation_storage,
+        &scale_storage,
+        &frozen_storage,
+        !&parent_storage,
+        !&baked_storage,
+    )
+        .iter()
+        .with_id()
+        .map(|(entity_id, (translation, rotation, scale, _, _, _))| {
+            (
+                entity_id,
+                compose_trs_mat4(translation.value, rotation.value, scale.value),
+            )
+        })
+        .collect();
+
+    for (entity_id, world_matrix) in unbaked {
+        if let Ok(mut local_world) = (&mut local_to_world_storage).get(entity_id) {
+            local_world.value = world_matrix;
+
+            baked_storage.add_component_unchecked(
+                entity_id,
+                BakedLocalToWorld {
+                    value: world_matrix,
+                },
+            );
+        }
+    }
 }
 
+// Children are grouped by depth so every parent is final before its children
+// read it. Each level is composed in parallel and written back afterwards.
+// An entity is recomputed only when its own transform or Parent changed or
+// its parent's LocalToWorld changed this frame, so untouched subtrees are
+// skipped. A Frozen entity is composed against its parent once and cached,
+// after that it stays put in world space even when the parent moves. Its
+// unfrozen children keep following the baked matrix.
 pub fn calculate_local_world_parent_system(
     translation_storage: View<Translation>,
     rotation_storage: View<Rotation>,
     scale_storage: View<Scale>,
     frozen_storage: View<Frozen>,
     parent_storage: View<Parent>,
+    mut baked_storage: ViewMut<BakedLocalToWorld>,
     mut local_to_world_storage: ViewMut<LocalToWorld>,
 ) {
-    (
-        &translation_storage,
-        &rotation_storage,
-        &scale_storage,
-        &parent_storage,
-        !&frozen_storage,
-    )
+    let mut depth_cache: HashMap<EntityId, Option<usize>> = HashMap::new();
+    let mut levels: Vec<Vec<EntityId>> = Vec::new();
+
+    // Baked entities are skipped, a Frozen entity is visited once to bake it.
+    for (entity_id, _) in (&parent_storage, &local_to_world_storage, !&baked_storage)
         .iter()
         .with_id()
-        .for_each(|(entity_id, (translation, rotation, scale, parent, _))| {
-            let identity_matrix = LocalToWorld::default();
-
-            let parent_entity_id =
-                EntityId::from_inner(parent.value).unwrap_or_else(EntityId::dead);
-
-            let parent_local_to_world_matrix = (&local_to_world_storage)
-                .get(parent_entity_id)
-                .unwrap_or(&identity_matrix);
-
-            let (parent_world_translation, parent_world_rotation, parent_world_scale) =
-                decompose_transformation_matrix(parent_local_to_world_matrix.value);
-
-            let parent_world_translation = parent_world_translation.value;
-
-            let rcp_parent_world_scalar = 1.0 / (parent_world_translation[3] + f32::EPSILON);
-
-            let parent_world_translation = [
-                parent_world_translation[0] * rcp_parent_world_scalar,
-                parent_world_translation[1] * rcp_parent_world_scalar,
-                parent_world_translation[2] * rcp_parent_world_scalar,
-            ];
-
-            let parent_world_rotation = parent_world_rotation.value;
-            let parent_world_scale = match parent_world_scale.value {
-                ScaleType::Uniform(uniform) => [uniform; 3],
-                ScaleType::NonUniform(non_uniform) => non_uniform,
-            };
-
-            let child_local_translation = translation.value;
-            let child_local_rotation = rotation.value;
-            let child_local_scale = match scale.value {
-                ScaleType::Uniform(uniform) => [uniform; 3],
-                ScaleType::NonUniform(non_uniform) => non_uniform,
-            };
-
-            let transform_child = transform(child_local_translation, parent_world_rotation.into());
-
-            let child_world_translation = Translation {
-                value: [
-                    transform_child[0] * parent_world_scale[0] + parent_world_translation[0],
-                    transform_child[1] * parent_world_scale[1] + parent_world_translation[1],
-                    transform_child[2] * parent_world_scale[2] + parent_world_translation[2],
-                    1.0,
-                ],
-            };
-
-            let child_world_rotation = Rotation {
-                value: [
-                    parent_world_rotation[3] * child_local_rotation[0] // 0
-                        + parent_world_rotation[0] * child_local_rotation[3]
-                        + parent_world_rotation[1] * child_local_rotation[2]
-                        - parent_world_rotation[2] * child_local_rotation[1],
-                    //
-                    parent_world_rotation[3] * child_local_rotation[1]
-                        - parent_world_rotation[0] * child_local_rotation[2]
-                        + parent_world_rotation[1] * child_local_rotation[3]
-                        + parent_world_rotation[2] * child_local_rotation[0],
-                    //
-                    parent_world_rotation[3] * child_local_rotation[2]
-                        + parent_world_rotation[0] * child_local_rotation[1]
-                        - parent_world_rotation[1] * child_local_rotation[0]
-                        + parent_world_rotation[2] * child_local_rotation[3],
-                    //
-                    parent_world_rotation[3] * child_local_rotation[3]
-                        - parent_world_rotation[0] * child_local_rotation[0]
-                        - parent_world_rotation[1] * child_local_rotation[1]
-                        - parent_world_rotation[2] * child_local_rotation[2],
-                ],
-            };
-
-            let child_world_scale = Scale {
-                value: ScaleType::NonUniform([
-                    parent_world_scale[0] * child_local_scale[0],
-                    parent_world_scale[1] * child_local_scale[1],
-                    parent_world_scale[2] * child_local_scale[2],
-                ]),
-            };
-
-            let mut child_local_to_world_matrix =
-                (&mut local_to_world_storage).get(entity_id).unwrap();
-
-
-            child_local_to_world_matrix.value = get_transformation_matrix(
-                child_world_translation,
-                child_world_rotation,
-                child_world_scale,
-            );
-        });
+    {
+        if let Some(depth) = hierarchy_depth(entity_id, &parent_storage, &mut depth_cache) {
+            if levels.len() < depth {
+                levels.resize_with(depth, Vec::new);
+            }
+
+            levels[depth - 1].push(entity_id);
+        }
+    }
+
+    // Roots written earlier in the workload show up as modified.
+    let mut dirty: HashSet<EntityId> = local_to_world_storage
+        .inserted_or_modified()
+        .iter()
+        .with_id()
+        .map(|(entity_id, _)| entity_id)
+        .collect();
+
+    for level in levels {
+        let updates: Vec<(EntityId, Matrix4x4)> = level
+            .par_iter()
+            .filter_map(|entity_id| {
+                let parent_id = parent_entity(parent_storage.get(*entity_id).ok()?);
+
+                let needs_update = dirty.contains(&parent_id)
+                    || dirty.contains(entity_id)
+                    || parent_storage.is_inserted_or_modified(*entity_id)
+                    || frozen_storage.contains(*entity_id)
+                    || frozen_storage.is_removed(*entity_id)
+                    || is_local_dirty(
+                        *entity_id,
+                        &translation_storage,
+                        &rotation_storage,
+                        &scale_storage,
+                    );
+
+                if !needs_update {
+                    return None;
+                }
+
+                let parent_world = (&local_to_world_storage)
+                    .get(parent_id)
+                    .map(|local_world| local_world.value)
+                    .unwrap_or(Matrix4x4::IDENTITY);
+
+                let local = compose_local_matrix(
+                    *entity_id,
+                    &translation_storage,
+                    &rotation_storage,
+                    &scale_storage,
+                );
+
+                Some((*entity_id, parent_world * local))
+            })
+            .collect();
+
+        for (entity_id, world_matrix) in updates {
+            if let Ok(mut local_world) = (&mut local_to_world_storage).get(entity_id) {
+                local_world.value = world_matrix;
+            }
+
+            if frozen_storage.contains(entity_id) {
+                baked_storage.add_component_unchecked(
+                    entity_id,
+                    BakedLocalToWorld {
+                        value: world_matrix,
+                    },
+                );
+            }
+
+            dirty.insert(entity_id);
+        }
+    }
+}
+
+pub fn calculate_local_world_workload() -> Workload {
+    (
+        invalidate_unfrozen_system,
+        calculate_local_world_system,
+        calculate_local_world_parent_system,
+    )
+        .into_workload()
 }
 
 #[cfg(test)]
 mod local_world_test {
     use crate::system::transform::local_world_system::{
         calculate_local_world_parent_system, calculate_local_world_system,
+        invalidate_unfrozen_system,
     };
-    use fabled_transform::{LocalToWorld, Parent, ScaleType};
-    use shipyard::Get;
+    use fabled_math::matrix4x4_math::compose_trs_mat4;
+    use fabled_math::quaternion_math::from_angle_axis_quat;
+    use fabled_math::{Matrix4x4, Quaternion, Vector3};
+    use fabled_transform::{
+        BakedLocalToWorld, Frozen, LocalToWorld, Parent, Rotation, Scale, Translation,
+    };
+    use shipyard::{Get, Remove};
+
+    fn transform_bundle(
+        translation: Vector3,
+        rotation: Quaternion,
+        scale: Vector3,
+    ) -> (Translation, Rotation, Scale, LocalToWorld) {
+        (
+            Translation { value: translation },
+            Rotation { value: rotation },
+            Scale { value: scale },
+            LocalToWorld::default(),
+        )
+    }
+
+    fn build_workload(world: &shipyard::World) {
+        shipyard::Workload::builder("run_test")
+            .with_system(&invalidate_unfrozen_system)
+            .with_system(&calculate_local_world_system)
+            .with_system(&calculate_local_world_parent_system)
+            .add_to_world(world)
+            .unwrap();
+    }
+
+    fn world_matrix(world: &shipyard::World, entity: shipyard::EntityId) -> Matrix4x4 {
+        let local_world_storage = world.borrow::<shipyard::View<LocalToWorld>>().unwrap();
+
+        (&local_world_storage).get(entity).unwrap().value
+    }
+
+    fn approx_matrix(lhs: Matrix4x4, rhs: Matrix4x4, threshold: f32) -> bool {
+        lhs.to_primitive()
+            .iter()
+            .zip(rhs.to_primitive().iter())
+            .all(|(lhs, rhs)| (lhs - rhs).abs() <= threshold)
+    }
 
     #[test]
     fn retrieve_modified_world_matrix() {
@@ -150,17 +346,10 @@ mod local_world_test {
 
         let mut world = shipyard::World::new();
 
-        let entity = world.add_entity((
-            fabled_transform::Translation {
-                value: [94.53416, 50.44112, 26.40424, 1.0],
-            },
-            fabled_transform::Scale {
-                value: ScaleType::NonUniform([1.0, 2.1, 11.1]),
-            },
-            fabled_transform::Rotation {
-                value: [0.3097265, 0.2101103, 0.5141426, 0.7717387],
-            },
-            LocalToWorld::default(),
+        let entity = world.add_entity(transform_bundle(
+            Vector3::set(94.53416, 50.44112, 26.40424),
+            Quaternion::set(0.3097265, 0.2101103, 0.5141426, 0.7717387),
+            Vector3::set(1.0, 2.1, 11.1),
         ));
 
         shipyard::Workload::builder("run_test")
@@ -170,11 +359,7 @@ mod local_world_test {
 
         world.run_workload("run_test").unwrap();
 
-        let local_world_storage = world.borrow::<shipyard::View<LocalToWorld>>().unwrap();
-
-        let local_world = (&local_world_storage).get(entity).unwrap();
-
-        let local_world_inner = local_world.value;
+        let local_world_inner = world_matrix(&world, entity).to_primitive();
 
         // Unity's result (our matrix is column-major)
         //  0.38302	 -1.39317   7.13494	  94.53416
@@ -200,65 +385,47 @@ mod local_world_test {
 
         let mut world = shipyard::World::new();
 
-        let root_entity_parent = world.add_entity((
-            fabled_transform::Translation {
-                value: [44.42781, 13.65856, 41.4725, 1.0],
-            },
-            fabled_transform::Scale {
-                value: ScaleType::Uniform(2.0),
-            },
-            fabled_transform::Rotation {
-                value: [0.0, 0.0, 0.0, 1.0],
-            },
-            LocalToWorld::default(),
+        let rotation = Quaternion::set(0.3097265, 0.2101103, 0.5141426, 0.7717387);
+
+        let root_entity_parent = world.add_entity(transform_bundle(
+            Vector3::set(44.42781, 13.65856, 41.4725),
+            Quaternion::IDENTITY,
+            Vector3::broadcast(2.0),
         ));
 
-        let entity_parent = world.add_entity((
-            fabled_transform::Translation {
-                value: [34.42781, -13.65856, -1.472504, 1.0],
-            },
-            fabled_transform::Scale {
-                value: ScaleType::Uniform(3.0),
-            },
-            fabled_transform::Rotation {
-                value: [0.3097265, 0.2101103, 0.5141426, 0.7717387],
-            },
-            LocalToWorld::default(),
+        let entity_parent = world.add_entity(transform_bundle(
+            Vector3::set(34.42781, -13.65856, -1.472504),
+            rotation,
+            Vector3::broadcast(3.0),
+        ));
+
+        world.add_component(
+            entity_parent,
             Parent {
                 value: root_entity_parent.inner(),
             },
-        ));
+        );
 
+        let entity_child = world.add_entity(transform_bundle(
+            Vector3::set(12.2, 9.45, 11.0),
+            rotation,
+            Vector3::broadcast(2.0),
+        ));
 
-        let entity_child = world.add_entity((
-            fabled_transform::Translation {
-                value: [12.2, 9.45, 11.0, 1.0],
-            },
-            fabled_transform::Scale {
-                value: ScaleType::Uniform(2.0),
-            },
-            fabled_transform::Rotation {
-                value: [0.3097265, 0.2101103, 0.5141426, 0.7717387],
-            },
-            LocalToWorld::default(),
+        world.add_component(
+            entity_child,
             Parent {
                 value: entity_parent.inner(),
             },
-        ));
+        );
 
-        shipyard::Workload::builder("run_test")
-            .with_system(&calculate_local_world_system)
-            .with_system(&calculate_local_world_parent_system)
-            .add_to_world(&world)
-            .unwrap();
+        build_workload(&world);
 
         world.run_workload("run_test").unwrap();
 
-        let local_world_storage = world.borrow::<shipyard::View<LocalToWorld>>().unwrap();
-
-        let root_parent_local_world = (&local_world_storage).get(root_entity_parent).unwrap();
-        let parent_local_world = (&local_world_storage).get(entity_parent).unwrap();
-        let child_local_world = (&local_world_storage).get(entity_child).unwrap();
+        let root_parent_local_world = world_matrix(&world, root_entity_parent).to_primitive();
+        let parent_local_world = world_matrix(&world, entity_parent).to_primitive();
+        let child_local_world = world_matrix(&world, entity_child).to_primitive();
 
         // Unity's root parent local to world matrix result (our matrix is column-major)
         // 2.00000	0.00000	0.00000	44.42781
@@ -301,16 +468,264 @@ mod local_world_test {
 
         for each in 0..16_usize {
             assert!(
-                (proven_root_parent_local_world[each] - root_parent_local_world.value[each]).abs()
+                (proven_root_parent_local_world[each] - root_parent_local_world[each]).abs()
                     <= THRESHOLD
             );
             assert!(
-                (proven_parent_local_world[each] - parent_local_world.value[each]).abs()
-                    <= THRESHOLD
+                (proven_parent_local_world[each] - parent_local_world[each]).abs() <= THRESHOLD
             );
-            assert!(
-                (proven_child_local_world[each] - child_local_world.value[each]).abs() <= THRESHOLD
+            assert!((proven_child_local_world[each] - child_local_world[each]).abs() <= THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn deep_hierarchy_non_uniform_scale() {
+        const THRESHOLD: f32 = 0.001;
+        const DEPTH: usize = 6;
+
+        let mut world = shipyard::World::new();
+
+        let mut entities = Vec::with_capacity(DEPTH);
+        let mut expected = Vec::with_capacity(DEPTH);
+        let mut parent_world = Matrix4x4::IDENTITY;
+
+        // Children are added before their parents so storage order is not
+        // hierarchy order.
+        for depth in (0..DEPTH).rev() {
+            let entity = world.add_entity(transform_bundle(
+                Vector3::set(depth as f32, 1.0, -0.5),
+                from_angle_axis_quat(Vector3::UP, 0.3 * depth as f32),
+                Vector3::set(1.0 + depth as f32 * 0.5, 0.5, 2.0),
+            ));
+
+            entities.insert(0, entity);
+        }
+
+        for (depth, entity) in entities.iter().enumerate() {
+            if depth > 0 {
+                world.add_component(
+                    *entity,
+                    Parent {
+                        value: entities[depth - 1].inner(),
+                    },
+                );
+            }
+
+            let local = compose_trs_mat4(
+                Vector3::set(depth as f32, 1.0, -0.5),
+                from_angle_axis_quat(Vector3::UP, 0.3 * depth as f32),
+                Vector3::set(1.0 + depth as f32 * 0.5, 0.5, 2.0),
             );
+
+            parent_world = parent_world * local;
+            expected.push(parent_world);
+        }
+
+        build_workload(&world);
+        world.run_workload("run_test").unwrap();
+
+        for (entity, expected) in entities.iter().zip(expected.iter()) {
+            assert!(approx_matrix(
+                world_matrix(&world, *entity),
+                *expected,
+                THRESHOLD
+            ));
+        }
+
+        // Moving the root alone must reach the deepest descendant.
+        {
+            let mut translation_storage = world.borrow::<shipyard::ViewMut<Translation>>().unwrap();
+
+            (&mut translation_storage).get(entities[0]).unwrap().value =
+                Vector3::set(10.0, 0.0, 0.0);
+        }
+
+        world.run_workload("run_test").unwrap();
+
+        let moved_root = world_matrix(&world, entities[0]);
+        let leaf = world_matrix(&world, entities[DEPTH - 1]);
+
+        let mut relative = Matrix4x4::IDENTITY;
+        for depth in 1..DEPTH {
+            relative = relative
+                * compose_trs_mat4(
+                    Vector3::set(depth as f32, 1.0, -0.5),
+                    from_angle_axis_quat(Vector3::UP, 0.3 * depth as f32),
+                    Vector3::set(1.0 + depth as f32 * 0.5, 0.5, 2.0),
+                );
         }
+
+        assert!(approx_matrix(leaf, moved_root * relative, THRESHOLD));
+    }
+
+    #[test]
+    fn parent_cycle_is_skipped() {
+        let mut world = shipyard::World::new();
+
+        let first = world.add_entity(transform_bundle(
+            Vector3::RIGHT,
+            Quaternion::IDENTITY,
+            Vector3::ONE,
+        ));
+        let second = world.add_entity(transform_bundle(
+            Vector3::UP,
+            Quaternion::IDENTITY,
+            Vector3::ONE,
+        ));
+
+        world.add_component(
+            first,
+            Parent {
+                value: second.inner(),
+            },
+        );
+        world.add_component(
+            second,
+            Parent {
+                value: first.inner(),
+            },
+        );
+
+        build_workload(&world);
+        world.run_workload("run_test").unwrap();
+
+        assert!(approx_matrix(
+            world_matrix(&world, first),
+            Matrix4x4::IDENTITY,
+            0.0
+        ));
+    }
+
+    #[test]
+    fn frozen_bakes_once_under_moving_parent() {
+        const THRESHOLD: f32 = 0.0001;
+
+        let mut world = shipyard::World::new();
+
+        let parent = world.add_entity(transform_bundle(
+            Vector3::set(1.0, 0.0, 0.0),
+            Quaternion::IDENTITY,
+            Vector3::ONE,
+        ));
+
+        let frozen = world.add_entity(transform_bundle(
+            Vector3::set(0.0, 2.0, 0.0),
+            Quaternion::IDENTITY,
+            Vector3::ONE,
+        ));
+
+        let child = world.add_entity(transform_bundle(
+            Vector3::set(0.0, 0.0, 3.0),
+            Quaternion::IDENTITY,
+            Vector3::ONE,
+        ));
+
+        world.add_component(
+            frozen,
+            (
+                Parent {
+                    value: parent.inner(),
+                },
+                Frozen {},
+            ),
+        );
+        world.add_component(
+            child,
+            Parent {
+                value: frozen.inner(),
+            },
+        );
+
+        build_workload(&world);
+        world.run_workload("run_test").unwrap();
+
+        let baked = compose_trs_mat4(
+            Vector3::set(1.0, 2.0, 0.0),
+            Quaternion::IDENTITY,
+            Vector3::ONE,
+        );
+
+        assert!(approx_matrix(
+            world_matrix(&world, frozen),
+            baked,
+            THRESHOLD
+        ));
+        assert!(world
+            .borrow::<shipyard::View<BakedLocalToWorld>>()
+            .unwrap()
+            .get(frozen)
+            .is_ok());
+
+        // The frozen entity stays in place, its child follows the baked matrix.
+        {
+            let mut translation_storage = world.borrow::<shipyard::ViewMut<Translation>>().unwrap();
+
+            (&mut translation_storage).get(parent).unwrap().value = Vector3::set(5.0, 0.0, 0.0);
+        }
+
+        world.run_workload("run_test").unwrap();
+
+        assert!(approx_matrix(
+            world_matrix(&world, frozen),
+            baked,
+            THRESHOLD
+        ));
+        assert!(approx_matrix(
+            world_matrix(&world, child),
+            compose_trs_mat4(
+                Vector3::set(1.0, 2.0, 3.0),
+                Quaternion::IDENTITY,
+                Vector3::ONE
+            ),
+            THRESHOLD
+        ));
+
+        // Anything overwriting the frozen matrix is undone on the next update.
+        {
+            let mut local_to_world_storage =
+                world.borrow::<shipyard::ViewMut<LocalToWorld>>().unwrap();
+
+            (&mut local_to_world_storage).get(frozen).unwrap().value = Matrix4x4::IDENTITY;
+        }
+
+        world.run_workload("run_test").unwrap();
+
+        assert!(approx_matrix(
+            world_matrix(&world, frozen),
+            baked,
+            THRESHOLD
+        ));
+        assert!(approx_matrix(
+            world_matrix(&world, child),
+            compose_trs_mat4(
+                Vector3::set(1.0, 2.0, 3.0),
+                Quaternion::IDENTITY,
+                Vector3::ONE
+            ),
+            THRESHOLD
+        ));
+
+        // Unfreezing drops the cache and snaps back under the moved parent.
+        world
+            .borrow::<shipyard::ViewMut<Frozen>>()
+            .unwrap()
+            .remove(frozen);
+
+        world.run_workload("run_test").unwrap();
+
+        assert!(world
+            .borrow::<shipyard::View<BakedLocalToWorld>>()
+            .unwrap()
+            .get(frozen)
+            .is_err());
+        assert!(approx_matrix(
+            world_matrix(&world, child),
+            compose_trs_mat4(
+                Vector3::set(5.0, 2.0, 3.0),
+                Quaternion::IDENTITY,
+                Vector3::ONE
+            ),
+            THRESHOLD
+        ));
     }
 }
@@ -1,5 +1,7 @@
 mod local_world_system;
+mod motion_vector_system;
 mod transform_validation_system;
 
 pub use local_world_system::*;
+pub use motion_vector_system::*;
 pub use transform_validation_system::*;
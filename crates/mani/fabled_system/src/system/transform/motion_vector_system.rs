@@ -0,0 +1,246 @@
+use crate::Time;
+
+use fabled_math::matrix4x4_math::decompose_mat4;
+use fabled_math::quaternion_math::conjugate_quat;
+use fabled_math::vector_math::length;
+use fabled_math::{DecomposedTransform, Matrix4x4, Quaternion, Vector3};
+use fabled_transform::{AngularVelocity, LocalToWorld, PreviousLocalToWorld, Teleport, Velocity};
+
+use shipyard::*;
+
+// Linear and angular velocity in world space that take the previous matrix to
+// the current one over delta_time seconds.
+pub fn motion_between(
+    previous: Matrix4x4,
+    current: Matrix4x4,
+    delta_time: f32,
+) -> (Vector3, Vector3) {
+    if delta_time <= f32::EPSILON {
+        return (Vector3::ZERO, Vector3::ZERO);
+    }
+
+    let previous: DecomposedTransform = decompose_mat4(previous);
+    let current: DecomposedTransform = decompose_mat4(current);
+
+    let inverse_delta: f32 = delta_time.recip();
+
+    let velocity: Vector3 = (current.translation - previous.translation) * inverse_delta;
+
+    let mut delta_rotation: Quaternion = current.rotation * conjugate_quat(previous.rotation);
+
+    // Shortest arc.
+    if delta_rotation.to_real() < 0.0 {
+        delta_rotation = delta_rotation * -1.0;
+    }
+
+    let pure: Vector3 = delta_rotation.to_pure();
+    let sin_half_angle: f32 = length(pure.value);
+
+    // atan2 keeps the small angles acos would lose.
+    let axis_angle: Vector3 = if sin_half_angle <= f32::EPSILON {
+        pure * 2.0
+    } else {
+        pure * (2.0 * sin_half_angle.atan2(delta_rotation.to_real()) / sin_half_angle)
+    };
+
+    (velocity, axis_angle * inverse_delta)
+}
+
+// Runs before the local world workload so PreviousLocalToWorld holds last
+// frame's matrix. Only matrices that changed since the last run are copied.
+pub fn store_previous_local_world_system(
+    local_to_world_storage: View<LocalToWorld>,
+    mut previous_storage: ViewMut<PreviousLocalToWorld>,
+) {
+    for (local_world, mut previous) in (
+        local_to_world_storage.inserted_or_modified(),
+        &mut previous_storage,
+    )
+        .iter()
+    {
+        previous.value = local_world.value;
+    }
+}
+
+// Runs after the local world workload. Teleported and newly tracked entities
+// have their previous matrix reset to the current one so they report no
+// motion. Entities that did not move and were not moving are skipped.
+pub fn calculate_motion_system(
+    time: UniqueView<Time>,
+    local_to_world_storage: View<LocalToWorld>,
+    mut previous_storage: ViewMut<PreviousLocalToWorld>,
+    mut velocity_storage: ViewMut<Velocity>,
+    mut angular_velocity_storage: ViewMut<AngularVelocity>,
+    mut teleport_storage: ViewMut<Teleport>,
+) {
+    let teleported: Vec<EntityId> = teleport_storage
+        .iter()
+        .with_id()
+        .map(|(entity_id, _)| entity_id)
+        .collect();
+
+    let reset: Vec<EntityId> = previous_storage
+        .inserted()
+        .iter()
+        .with_id()
+        .map(|(entity_id, _)| entity_id)
+        .chain(teleported.iter().copied())
+        .collect();
+
+    for entity_id in reset {
+        if let (Ok(local_world), Ok(mut previous)) = (
+            local_to_world_storage.get(entity_id),
+            (&mut previous_storage).get(entity_id),
+        ) {
+            previous.value = local_world.value;
+        }
+    }
+
+    for entity_id in teleported {
+        teleport_storage.remove(entity_id);
+    }
+
+    let motions: Vec<(EntityId, (Vector3, Vector3))> = (&local_to_world_storage, &previous_storage)
+        .iter()
+        .with_id()
+        .filter(|(entity_id, _)| {
+            local_to_world_storage.is_inserted_or_modified(*entity_id)
+                || previous_storage.is_inserted_or_modified(*entity_id)
+        })
+        .map(|(entity_id, (local_world, previous))| {
+            (
+                entity_id,
+                motion_between(previous.value, local_world.value, time.delta),
+            )
+        })
+        .collect();
+
+    for (entity_id, (velocity, angular_velocity)) in motions {
+        if let Ok(mut linear) = (&mut velocity_storage).get(entity_id) {
+            linear.value = velocity;
+        }
+
+        if let Ok(mut angular) = (&mut angular_velocity_storage).get(entity_id) {
+            angular.value = angular_velocity;
+        }
+    }
+}
+
+#[cfg(test)]
+mod motion_vector_test {
+    use crate::system::transform::local_world_system::{
+        calculate_local_world_parent_system, calculate_local_world_system,
+        invalidate_unfrozen_system,
+    };
+    use crate::system::transform::motion_vector_system::{
+        calculate_motion_system, store_previous_local_world_system,
+    };
+    use crate::Time;
+    use fabled_math::quaternion_math::from_angle_axis_quat;
+    use fabled_math::{Quaternion, Vector3};
+    use fabled_transform::{
+        AngularVelocity, LocalToWorld, PreviousLocalToWorld, Rotation, Scale, Teleport,
+        Translation, Velocity,
+    };
+    use shipyard::{EntityId, Get, World};
+
+    fn motion(world: &World, entity: EntityId) -> Vec<f32> {
+        let (velocity_storage, angular_velocity_storage) = world
+            .borrow::<(shipyard::View<Velocity>, shipyard::View<AngularVelocity>)>()
+            .unwrap();
+
+        [
+            velocity_storage.get(entity).unwrap().value.to_primitive(),
+            angular_velocity_storage
+                .get(entity)
+                .unwrap()
+                .value
+                .to_primitive(),
+        ]
+        .concat()
+    }
+
+    fn approx(lhs: Vec<f32>, rhs: [f32; 6]) -> bool {
+        lhs.iter()
+            .zip(rhs.iter())
+            .all(|(lhs, rhs)| (lhs - rhs).abs() <= 0.001)
+    }
+
+    #[test]
+    fn velocity_and_teleport() {
+        let mut world = World::new();
+
+        world
+            .add_unique(Time {
+                delta: 0.5,
+                ..Time::default()
+            })
+            .unwrap();
+
+        let entity = world.add_entity((
+            Translation::default(),
+            Rotation::default(),
+            Scale::default(),
+            LocalToWorld::default(),
+            PreviousLocalToWorld::default(),
+            Velocity::default(),
+            AngularVelocity::default(),
+        ));
+
+        shipyard::Workload::builder("motion_test")
+            .with_system(&store_previous_local_world_system)
+            .with_system(&invalidate_unfrozen_system)
+            .with_system(&calculate_local_world_system)
+            .with_system(&calculate_local_world_parent_system)
+            .with_system(&calculate_motion_system)
+            .add_to_world(&world)
+            .unwrap();
+
+        world.run_workload("motion_test").unwrap();
+
+        assert!(approx(motion(&world, entity), [0.0; 6]));
+
+        world.run(
+            |mut translation_storage: shipyard::ViewMut<Translation>,
+             mut rotation_storage: shipyard::ViewMut<Rotation>| {
+                (&mut translation_storage).get(entity).unwrap().value = Vector3::set(1.0, 0.0, 0.0);
+                (&mut rotation_storage).get(entity).unwrap().value =
+                    from_angle_axis_quat(Vector3::UP, 0.1);
+            },
+        );
+
+        world.run_workload("motion_test").unwrap();
+
+        let up = Vector3::UP * 0.2;
+
+        assert!(approx(
+            motion(&world, entity),
+            [2.0, 0.0, 0.0, up.x(), up.y(), up.z()]
+        ));
+
+        // Standing still again brings the velocity back to zero.
+        world.run_workload("motion_test").unwrap();
+
+        assert!(approx(motion(&world, entity), [0.0; 6]));
+
+        world.run(
+            |mut translation_storage: shipyard::ViewMut<Translation>,
+             mut rotation_storage: shipyard::ViewMut<Rotation>,
+             mut teleport_storage: shipyard::ViewMut<Teleport>| {
+                (&mut translation_storage).get(entity).unwrap().value =
+                    Vector3::set(100.0, 0.0, 0.0);
+                (&mut rotation_storage).get(entity).unwrap().value = Quaternion::IDENTITY;
+                teleport_storage.add_component_unchecked(entity, Teleport {});
+            },
+        );
+
+        world.run_workload("motion_test").unwrap();
+
+        assert!(approx(motion(&world, entity), [0.0; 6]));
+        assert!(world
+            .borrow::<shipyard::View<Teleport>>()
+            .unwrap()
+            .get(entity)
+            .is_err());
+    }
+}
@@ -1,143 +1,428 @@
-// use fabled_transform::{LocalToWorld, Rotation, Scale, Translation};
-// use shipyard::*;
-//
-// pub fn removed_deleted_transform_system(
-// mut translation_storage: shipyard::ViewMut<Translation>,
-// mut rotation_storage: shipyard::ViewMut<Rotation>,
-// mut scale_storage: shipyard::ViewMut<Scale>,
-// mut local_to_world_storage: ViewMut<LocalToWorld>,
-// ) {
-// let (removed_translation_entities, deleted_translation_metas) =
-// translation_storage.take_removed_and_deleted();
-//
-// {
-// removed_translation_entities
-// .iter()
-// .for_each(|&removed_translation_entity_id| {
-// translation_storage
-// .add_component_unchecked(removed_translation_entity_id,
-// Translation::default()); });
-//
-// deleted_translation_metas.iter().for_each(
-// |&(deleted_translation_entity_id, deleted_translation)| {
-// translation_storage
-// .add_component_unchecked(deleted_translation_entity_id, deleted_translation);
-// },
-// );
-// }
-//
-// let (removed_rotation_entities, deleted_rotation_metas) =
-// rotation_storage.take_removed_and_deleted();
-//
-// {
-// removed_rotation_entities
-// .iter()
-// .for_each(|&removed_rotation_entity_id| {
-// rotation_storage
-// .add_component_unchecked(removed_rotation_entity_id, Rotation::default());
-// });
-//
-// deleted_rotation_metas.iter().for_each(
-// |&(deleted_rotation_entity_id, deleted_rotation)| {
-// rotation_storage
-// .add_component_unchecked(deleted_rotation_entity_id, deleted_rotation)
-// },
-// );
-// }
-//
-// let (removed_scale_entities, deleted_scale_metas) =
-// scale_storage.take_removed_and_deleted();
-//
-// {
-// removed_scale_entities
-// .iter()
-// .for_each(|&removed_scale_entity_id| {
-// scale_storage.add_component_unchecked(removed_scale_entity_id,
-// Scale::default()); });
-//
-// deleted_scale_metas
-// .iter()
-// .for_each(|&(deleted_scale_entity_id, deleted_scale)| {
-// scale_storage.add_component_unchecked(deleted_scale_entity_id,
-// deleted_scale); });
-// }
-//
-//
-// let (removed_local_world_entities, deleted_local_world_metas) =
-// local_to_world_storage.take_removed_and_deleted();
-//
-// {
-// removed_local_world_entities
-// .iter()
-// .for_each(|&removed_local_world_entity_id| {
-// local_to_world_storage.add_component_unchecked(
-// removed_local_world_entity_id,
-// LocalToWorld::default(),
-// );
-// });
-//
-// deleted_local_world_metas.iter().for_each(
-// |&(deleted_local_world_entity_id, deleted_local_world)| {
-// local_to_world_storage
-// .add_component_unchecked(deleted_local_world_entity_id, deleted_local_world);
-// },
-// );
-// }
-// }
-//
-//
-// #[cfg(test)]
-// mod transform_validation_test {
-//
-// use crate::system::transform::transform_validation_system::
-// removed_deleted_transform_system; use fabled_transform::{LocalToWorld,
-// Rotation, Scale, Translation}; use shipyard::{Get, Remove, World};
-//
-// #[test]
-// fn transform_validation() {
-// let mut world = World::new();
-//
-// let entity_id = world.add_entity((
-// Translation::default(),
-// Rotation::default(),
-// Scale::default(),
-// LocalToWorld::default(),
-// ));
-//
-// shipyard::Workload::builder("transform_validation_test")
-// .with_system(&removed_deleted_transform_system)
-// .add_to_world(&world)
-// .unwrap();
-//
-// {
-// let (mut local_world_storage, mut scale_storage, mut rotation_storage) =
-// world .borrow::<(
-// shipyard::ViewMut<LocalToWorld>,
-// shipyard::ViewMut<Scale>,
-// shipyard::ViewMut<Rotation>,
-// )>()
-// .unwrap();
-//
-// rotation_storage.remove(entity_id);
-// scale_storage.remove(entity_id);
-// local_world_storage.remove(entity_id);
-// }
-//
-// world.run_workload("transform_validation_test").unwrap();
-//
-// {
-// let (local_world_storage, scale_storage, rotation_storage) = world
-// .borrow::<(
-// shipyard::View<LocalToWorld>,
-// shipyard::View<Scale>,
-// shipyard::View<Rotation>,
-// )>()
-// .unwrap();
-//
-// (&local_world_storage).get(entity_id).unwrap();
-// (&scale_storage).get(entity_id).unwrap();
-// (&rotation_storage).get(entity_id).unwrap();
-// }
-// world.run_workload("transform_validation_test").unwrap();
-// }
-// }
+use fabled_component::{All, Modification, Unique};
+use fabled_math::vector_math::{dot, is_finite, normalize};
+use fabled_math::{Matrix4x4, Quaternion, Vector3};
+use fabled_transform::{LocalToWorld, Rotation, Scale, Translation};
+
+use shipyard::*;
+
+use std::fmt::Display;
+
+const ROTATION_DRIFT_EPSILON: f32 = 0.0001;
+const ZERO_SCALE_EPSILON: f32 = 1.0e-6;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransformDiagnostic {
+    // A required transform component was removed and restored to its default.
+    Restored {
+        entity: u64,
+        component: &'static str,
+    },
+    // The rotation drifted away from unit length and was renormalized.
+    Renormalized {
+        entity: u64,
+    },
+    // NaN or infinity, local components are reset, LocalToWorld is only flagged.
+    NonFinite {
+        entity: u64,
+        component: &'static str,
+    },
+    // A scale axis is zero, the world matrix is singular but left as is.
+    ZeroScale {
+        entity: u64,
+    },
+}
+
+impl Display for TransformDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransformDiagnostic::Restored { entity, component } => {
+                write!(f, "Restored default {} on entity {}", component, entity)
+            }
+            TransformDiagnostic::Renormalized { entity } => {
+                write!(f, "Renormalized rotation on entity {}", entity)
+            }
+            TransformDiagnostic::NonFinite { entity, component } => {
+                write!(f, "Non finite {} on entity {}", component, entity)
+            }
+            TransformDiagnostic::ZeroScale { entity } => {
+                write!(f, "Zero scale on entity {}", entity)
+            }
+        }
+    }
+}
+
+// Everything the validation pass found during its last run. Each system
+// replaces its own kind of diagnostics when it runs, so either one can run
+// alone without them piling up.
+#[derive(Clone, Default)]
+pub struct TransformDiagnostics {
+    pub value: Vec<TransformDiagnostic>,
+}
+
+impl Unique for TransformDiagnostics {
+    type Tracking = Modification;
+}
+
+// The removal tracking is cleared once read, an entity that was deleted or
+// got the component back since the removal is left alone.
+fn restore_removed<T: Component<Tracking = All> + Default>(
+    entities: &EntitiesView,
+    storage: &mut ViewMut<T>,
+    component: &'static str,
+    diagnostics: &mut TransformDiagnostics,
+) {
+    let removed: Vec<EntityId> = storage.removed().collect();
+
+    storage.clear_all_removed_and_deleted();
+
+    for entity_id in removed {
+        if !entities.is_alive(entity_id) || storage.contains(entity_id) {
+            continue;
+        }
+
+        storage.add_component_unchecked(entity_id, T::default());
+
+        diagnostics.value.push(TransformDiagnostic::Restored {
+            entity: entity_id.inner(),
+            component,
+        });
+    }
+}
+
+// Components removed from a still alive entity are restored to their default.
+// Deleted entities are gone, so their components are not brought back.
+pub fn removed_deleted_transform_system(
+    entities: EntitiesView,
+    mut translation_storage: ViewMut<Translation>,
+    mut rotation_storage: ViewMut<Rotation>,
+    mut scale_storage: ViewMut<Scale>,
+    mut local_to_world_storage: ViewMut<LocalToWorld>,
+    mut diagnostics: UniqueViewMut<TransformDiagnostics>,
+) {
+    diagnostics
+        .value
+        .retain(|diagnostic| !matches!(diagnostic, TransformDiagnostic::Restored { .. }));
+
+    restore_removed(
+        &entities,
+        &mut translation_storage,
+        "Translation",
+        &mut diagnostics,
+    );
+    restore_removed(
+        &entities,
+        &mut rotation_storage,
+        "Rotation",
+        &mut diagnostics,
+    );
+    restore_removed(&entities, &mut scale_storage, "Scale", &mut diagnostics);
+    restore_removed(
+        &entities,
+        &mut local_to_world_storage,
+        "LocalToWorld",
+        &mut diagnostics,
+    );
+}
+
+// Only components inserted or modified since the last run are checked.
+pub fn validate_transform_system(
+    mut translation_storage: ViewMut<Translation>,
+    mut rotation_storage: ViewMut<Rotation>,
+    mut scale_storage: ViewMut<Scale>,
+    local_to_world_storage: View<LocalToWorld>,
+    mut diagnostics: UniqueViewMut<TransformDiagnostics>,
+) {
+    diagnostics
+        .value
+        .retain(|diagnostic| matches!(diagnostic, TransformDiagnostic::Restored { .. }));
+
+    let changed_translations: Vec<EntityId> = translation_storage
+        .inserted_or_modified()
+        .iter()
+        .with_id()
+        .filter(|(_, translation)| !is_finite(translation.value.value).all())
+        .map(|(entity_id, _)| entity_id)
+        .collect();
+
+    for entity_id in changed_translations {
+        (&mut translation_storage).get(entity_id).unwrap().value = Vector3::ZERO;
+
+        diagnostics.value.push(TransformDiagnostic::NonFinite {
+            entity: entity_id.inner(),
+            component: "Translation",
+        });
+    }
+
+    let changed_rotations: Vec<(EntityId, Quaternion)> = rotation_storage
+        .inserted_or_modified()
+        .iter()
+        .with_id()
+        .map(|(entity_id, rotation)| (entity_id, rotation.value))
+        .collect();
+
+    for (entity_id, rotation) in changed_rotations {
+        let length_squared = dot(rotation.value, rotation.value);
+
+        if !length_squared.is_finite() || length_squared <= f32::EPSILON {
+            (&mut rotation_storage).get(entity_id).unwrap().value = Quaternion::IDENTITY;
+
+            diagnostics.value.push(TransformDiagnostic::NonFinite {
+                entity: entity_id.inner(),
+                component: "Rotation",
+            });
+        } else if (length_squared - 1.0).abs() > ROTATION_DRIFT_EPSILON {
+            (&mut rotation_storage).get(entity_id).unwrap().value = Quaternion {
+                value: normalize(rotation.value),
+            };
+
+            diagnostics.value.push(TransformDiagnostic::Renormalized {
+                entity: entity_id.inner(),
+            });
+        }
+    }
+
+    let changed_scales: Vec<(EntityId, Vector3)> = scale_storage
+        .inserted_or_modified()
+        .iter()
+        .with_id()
+        .map(|(entity_id, scale)| (entity_id, scale.value))
+        .collect();
+
+    for (entity_id, scale) in changed_scales {
+        if !is_finite(scale.value).all() {
+            (&mut scale_storage).get(entity_id).unwrap().value = Vector3::ONE;
+
+            diagnostics.value.push(TransformDiagnostic::NonFinite {
+                entity: entity_id.inner(),
+                component: "Scale",
+            });
+        } else if scale
+            .to_primitive()
+            .iter()
+            .any(|axis| axis.abs() <= ZERO_SCALE_EPSILON)
+        {
+            diagnostics.value.push(TransformDiagnostic::ZeroScale {
+                entity: entity_id.inner(),
+            });
+        }
+    }
+
+    for (entity_id, local_world) in local_to_world_storage
+        .inserted_or_modified()
+        .iter()
+        .with_id()
+    {
+        if !is_finite_matrix(local_world.value) {
+            diagnostics.value.push(TransformDiagnostic::NonFinite {
+                entity: entity_id.inner(),
+                component: "LocalToWorld",
+            });
+        }
+    }
+}
+
+#[inline]
+fn is_finite_matrix(matrix: Matrix4x4) -> bool {
+    matrix
+        .to_primitive()
+        .iter()
+        .all(|element| element.is_finite())
+}
+
+pub fn transform_validation_workload() -> Workload {
+    (removed_deleted_transform_system, validate_transform_system).into_workload()
+}
+
+#[cfg(test)]
+mod transform_validation_test {
+    use crate::system::transform::transform_validation_system::{
+        removed_deleted_transform_system, validate_transform_system, TransformDiagnostic,
+        TransformDiagnostics,
+    };
+    use fabled_math::{Quaternion, Vector3};
+    use fabled_transform::{LocalToWorld, Rotation, Scale, Translation};
+    use shipyard::{Get, Remove, World};
+
+    #[test]
+    fn transform_validation() {
+        let mut world = World::new();
+
+        world.add_unique(TransformDiagnostics::default()).unwrap();
+
+        let entity_id = world.add_entity((
+            Translation::default(),
+            Rotation::default(),
+            Scale::default(),
+            LocalToWorld::default(),
+        ));
+
+        shipyard::Workload::builder("transform_validation_test")
+            .with_system(&removed_deleted_transform_system)
+            .add_to_world(&world)
+            .unwrap();
+
+        {
+            let (mut local_world_storage, mut scale_storage, mut rotation_storage) = world
+                .borrow::<(
+                    shipyard::ViewMut<LocalToWorld>,
+                    shipyard::ViewMut<Scale>,
+                    shipyard::ViewMut<Rotation>,
+                )>()
+                .unwrap();
+
+            rotation_storage.remove(entity_id);
+            scale_storage.remove(entity_id);
+            local_world_storage.remove(entity_id);
+        }
+
+        world.run_workload("transform_validation_test").unwrap();
+
+        {
+            let (local_world_storage, scale_storage, rotation_storage) = world
+                .borrow::<(
+                    shipyard::View<LocalToWorld>,
+                    shipyard::View<Scale>,
+                    shipyard::View<Rotation>,
+                )>()
+                .unwrap();
+
+            (&local_world_storage).get(entity_id).unwrap();
+            (&scale_storage).get(entity_id).unwrap();
+            (&rotation_storage).get(entity_id).unwrap();
+        }
+
+        assert_eq!(
+            world
+                .borrow::<shipyard::UniqueView<TransformDiagnostics>>()
+                .unwrap()
+                .value
+                .len(),
+            3
+        );
+
+        // Nothing left to restore on the second run.
+        world.run_workload("transform_validation_test").unwrap();
+
+        assert!(world
+            .borrow::<shipyard::UniqueView<TransformDiagnostics>>()
+            .unwrap()
+            .value
+            .is_empty());
+    }
+
+    #[test]
+    fn deleted_entities_stay_deleted() {
+        let mut world = World::new();
+
+        world.add_unique(TransformDiagnostics::default()).unwrap();
+
+        let entity_id = world.add_entity((
+            Translation::default(),
+            Rotation::default(),
+            Scale::default(),
+            LocalToWorld::default(),
+        ));
+
+        shipyard::Workload::builder("transform_deleted_test")
+            .with_system(&removed_deleted_transform_system)
+            .add_to_world(&world)
+            .unwrap();
+
+        world
+            .borrow::<shipyard::ViewMut<Scale>>()
+            .unwrap()
+            .remove(entity_id);
+
+        world.delete_entity(entity_id);
+
+        world.run_workload("transform_deleted_test").unwrap();
+
+        assert!(!world
+            .borrow::<shipyard::View<Scale>>()
+            .unwrap()
+            .contains(entity_id));
+        assert!(world
+            .borrow::<shipyard::UniqueView<TransformDiagnostics>>()
+            .unwrap()
+            .value
+            .is_empty());
+    }
+
+    #[test]
+    fn invariants_are_enforced() {
+        let mut world = World::new();
+
+        world.add_unique(TransformDiagnostics::default()).unwrap();
+
+        let drifting = world.add_entity((
+            Translation {
+                value: Vector3::set(f32::NAN, 0.0, 0.0),
+            },
+            Rotation {
+                value: Quaternion::set(0.0, 0.0, 0.0, 1.1),
+            },
+            Scale {
+                value: Vector3::set(1.0, 0.0, 1.0),
+            },
+            LocalToWorld::default(),
+        ));
+
+        shipyard::Workload::builder("transform_invariant_test")
+            .with_system(&validate_transform_system)
+            .add_to_world(&world)
+            .unwrap();
+
+        world.run_workload("transform_invariant_test").unwrap();
+
+        let (translation_storage, rotation_storage, diagnostics) = world
+            .borrow::<(
+                shipyard::View<Translation>,
+                shipyard::View<Rotation>,
+                shipyard::UniqueView<TransformDiagnostics>,
+            )>()
+            .unwrap();
+
+        assert_eq!(
+            (&translation_storage)
+                .get(drifting)
+                .unwrap()
+                .value
+                .to_primitive(),
+            [0.0, 0.0, 0.0]
+        );
+        assert!(
+            ((&rotation_storage)
+                .get(drifting)
+                .unwrap()
+                .value
+                .to_primitive()[3]
+                - 1.0)
+                .abs()
+                <= 0.0001
+        );
+
+        let entity = drifting.inner();
+
+        assert!(diagnostics.value.contains(&TransformDiagnostic::NonFinite {
+            entity,
+            component: "Translation",
+        }));
+        assert!(diagnostics
+            .value
+            .contains(&TransformDiagnostic::Renormalized { entity }));
+        assert!(diagnostics
+            .value
+            .contains(&TransformDiagnostic::ZeroScale { entity }));
+
+        drop((translation_storage, rotation_storage, diagnostics));
+
+        // Running alone again replaces the previous findings.
+        world.run_workload("transform_invariant_test").unwrap();
+
+        assert!(world
+            .borrow::<shipyard::UniqueView<TransformDiagnostics>>()
+            .unwrap()
+            .value
+            .is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+use fabled_component::{All, Component};
+use fabled_math::Vector3;
+
+use std::fmt::Display;
+
+// World space rotation axis scaled by radians per second.
+#[derive(Copy, Clone)]
+pub struct AngularVelocity {
+    pub value: Vector3,
+}
+
+impl Default for AngularVelocity {
+    fn default() -> Self {
+        Self {
+            value: Vector3::ZERO,
+        }
+    }
+}
+
+impl Display for AngularVelocity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AngularVelocity({})", self.value)
+    }
+}
+
+impl Component for AngularVelocity {
+    type Tracking = All;
+}
@@ -0,0 +1,22 @@
+use fabled_component::{All, Component};
+use fabled_math::Matrix4x4;
+
+use std::fmt::Display;
+
+// World matrix cached when a Frozen entity is baked. It is the reference the
+// LocalToWorld of the frozen entity is restored from, and is removed again
+// when the entity is unfrozen.
+#[derive(Copy, Clone)]
+pub struct BakedLocalToWorld {
+    pub value: Matrix4x4,
+}
+
+impl Display for BakedLocalToWorld {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BakedLocalToWorld(\n{}\n)", self.value)
+    }
+}
+
+impl Component for BakedLocalToWorld {
+    type Tracking = All;
+}
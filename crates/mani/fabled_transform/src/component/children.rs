@@ -0,0 +1,23 @@
+use fabled_component::{All, Component};
+
+use std::fmt::Display;
+
+// Entity ids of the direct children, kept in sync with their Parent by the
+// hierarchy functions. Replaces the single child Child component, code that
+// added Child should call Hierarchy::attach from the child instead, which
+// fills both Parent and Children.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Children {
+    pub value: Vec<u64>,
+}
+
+impl Display for Children {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Children({:?})", self.value)
+    }
+}
+
+impl Component for Children {
+    type Tracking = All;
+}
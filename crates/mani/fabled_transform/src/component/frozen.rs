@@ -2,7 +2,11 @@ use fabled_component::{Component, All};
 
 use std::fmt::Display;
 
+// Marks static geometry. The world matrix is baked once and kept in world
+// space, a moving parent no longer carries the entity along until Frozen is
+// removed. Unfrozen children still follow the baked matrix.
 #[derive(Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Frozen {}
 
 impl Display for Frozen{
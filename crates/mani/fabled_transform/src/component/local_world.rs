@@ -1,9 +1,16 @@
-use fabled_math::Matrix4x4;
+use fabled_math::{DMatrix4x4, DVector3, Matrix4x4, Vector3};
+use fabled_math::dmatrix4x4_math::to_camera_relative_mat4;
+use fabled_math::matrix4x4_math::{
+    inverse_transform_direction3_mat4, inverse_transform_point3_mat4,
+    inverse_transform_vector3_mat4, transform_direction3_mat4, transform_point3_mat4,
+    transform_vector3_mat4,
+};
 use fabled_component::{Component, All};
 
 use std::fmt::Display;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocalToWorld {
     pub value: Matrix4x4,
 }
@@ -16,6 +23,46 @@ impl Default for LocalToWorld {
     }
 }
 
+impl LocalToWorld {
+    // Rebases a double precision world matrix around the camera for rendering.
+    #[inline]
+    pub fn from_camera_relative(world: DMatrix4x4, camera_position: DVector3) -> LocalToWorld {
+        LocalToWorld {
+            value: to_camera_relative_mat4(world, camera_position),
+        }
+    }
+
+    #[inline]
+    pub fn transform_point(&self, point: Vector3) -> Vector3 {
+        transform_point3_mat4(self.value, point)
+    }
+
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector3) -> Vector3 {
+        transform_vector3_mat4(self.value, vector)
+    }
+
+    #[inline]
+    pub fn transform_direction(&self, direction: Vector3) -> Vector3 {
+        transform_direction3_mat4(self.value, direction)
+    }
+
+    #[inline]
+    pub fn inverse_transform_point(&self, point: Vector3) -> Vector3 {
+        inverse_transform_point3_mat4(self.value, point)
+    }
+
+    #[inline]
+    pub fn inverse_transform_vector(&self, vector: Vector3) -> Vector3 {
+        inverse_transform_vector3_mat4(self.value, vector)
+    }
+
+    #[inline]
+    pub fn inverse_transform_direction(&self, direction: Vector3) -> Vector3 {
+        inverse_transform_direction3_mat4(self.value, direction)
+    }
+}
+
 impl Display for LocalToWorld{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f,"LocalToWorld(\n{}\n)", self.value)
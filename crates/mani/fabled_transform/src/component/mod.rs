@@ -1,15 +1,25 @@
-pub use child::*;
+pub use angular_velocity::*;
+pub use baked_local_world::*;
+pub use children::*;
 pub use frozen::*;
 pub use local_world::*;
 pub use parent::*;
+pub use previous_local_world::*;
 pub use rotation::*;
 pub use scale::*;
+pub use teleport::*;
 pub use translation::*;
+pub use velocity::*;
 
-mod child;
+mod angular_velocity;
+mod baked_local_world;
+mod children;
 mod frozen;
 mod local_world;
 mod parent;
+mod previous_local_world;
 mod rotation;
 mod scale;
+mod teleport;
 mod translation;
+mod velocity;
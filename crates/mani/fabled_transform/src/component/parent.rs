@@ -3,6 +3,7 @@ use std::fmt::Display;
 use fabled_component::{Component, All};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parent {
     pub value: u64,
 }
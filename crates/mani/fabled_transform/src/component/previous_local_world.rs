@@ -0,0 +1,28 @@
+use fabled_component::{All, Component};
+use fabled_math::Matrix4x4;
+
+use std::fmt::Display;
+
+// LocalToWorld as it was at the end of the previous update.
+#[derive(Copy, Clone)]
+pub struct PreviousLocalToWorld {
+    pub value: Matrix4x4,
+}
+
+impl Default for PreviousLocalToWorld {
+    fn default() -> Self {
+        Self {
+            value: Matrix4x4::IDENTITY,
+        }
+    }
+}
+
+impl Display for PreviousLocalToWorld {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PreviousLocalToWorld(\n{}\n)", self.value)
+    }
+}
+
+impl Component for PreviousLocalToWorld {
+    type Tracking = All;
+}
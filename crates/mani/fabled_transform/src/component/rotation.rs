@@ -4,6 +4,7 @@ use fabled_component::{Component, All};
 use fabled_math::Quaternion;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rotation {
     pub value: Quaternion,
 }
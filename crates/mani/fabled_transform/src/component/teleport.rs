@@ -0,0 +1,18 @@
+use fabled_component::{All, Component};
+
+use std::fmt::Display;
+
+// Added after moving an entity discontinuously. The previous matrix is reset
+// to the current one so no motion is reported, then the marker is removed.
+#[derive(Copy, Clone, Default)]
+pub struct Teleport {}
+
+impl Display for Teleport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Teleport()")
+    }
+}
+
+impl Component for Teleport {
+    type Tracking = All;
+}
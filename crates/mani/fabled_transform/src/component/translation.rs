@@ -4,6 +4,7 @@ use fabled_component::{Component, All};
 use std::fmt::Display;
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Translation {
     pub value: Vector3,
 }
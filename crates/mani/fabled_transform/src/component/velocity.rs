@@ -0,0 +1,28 @@
+use fabled_component::{All, Component};
+use fabled_math::Vector3;
+
+use std::fmt::Display;
+
+// World space units per second, derived from PreviousLocalToWorld.
+#[derive(Copy, Clone)]
+pub struct Velocity {
+    pub value: Vector3,
+}
+
+impl Default for Velocity {
+    fn default() -> Self {
+        Self {
+            value: Vector3::ZERO,
+        }
+    }
+}
+
+impl Display for Velocity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Velocity({})", self.value)
+    }
+}
+
+impl Component for Velocity {
+    type Tracking = All;
+}
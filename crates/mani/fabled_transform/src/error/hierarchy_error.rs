@@ -0,0 +1,16 @@
+use thiserror::*;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HierarchyError {
+    #[error("Entity {0:?} can not be its own parent.")]
+    SelfParent(u64),
+
+    #[error("Attaching {entity:?} to {parent:?} would create a cycle, the parent is a descendant of the entity.")]
+    Cycle { entity: u64, parent: u64 },
+
+    #[error("Entity {0:?} is not alive.")]
+    DeadEntity(u64),
+
+    #[error("Entity {0:?} has no LocalToWorld to preserve.")]
+    MissingLocalToWorld(u64),
+}
@@ -0,0 +1,3 @@
+mod hierarchy_error;
+
+pub use hierarchy_error::*;
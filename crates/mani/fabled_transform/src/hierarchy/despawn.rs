@@ -0,0 +1,21 @@
+use crate::{descendants_iter, Children, Hierarchy, HierarchyViewMut};
+
+use shipyard::{AllStorages, EntityId, View};
+
+// Deletes the entity and every descendant, and removes it from its parent's
+// child list.
+pub fn despawn_recursive(all_storages: &mut AllStorages, entity: EntityId) {
+    let descendants: Vec<EntityId> = all_storages.run(|children_storage: View<Children>| {
+        descendants_iter(&children_storage, entity).collect()
+    });
+
+    all_storages.run(|mut hierarchy: HierarchyViewMut| {
+        hierarchy.detach(entity);
+    });
+
+    for descendant in descendants.into_iter().rev() {
+        all_storages.delete_entity(descendant);
+    }
+
+    all_storages.delete_entity(entity);
+}
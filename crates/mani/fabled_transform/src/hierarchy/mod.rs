@@ -0,0 +1,7 @@
+mod despawn;
+mod parenting;
+mod traversal;
+
+pub use despawn::*;
+pub use parenting::*;
+pub use traversal::*;
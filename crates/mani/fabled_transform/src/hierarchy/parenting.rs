@@ -0,0 +1,392 @@
+use crate::hierarchy::traversal::to_entity_id;
+use crate::{Children, HierarchyError, LocalToWorld, Parent, Rotation, Scale, Translation};
+
+use fabled_math::matrix4x4_math::{decompose_mat4, inverse_mat4};
+use fabled_math::{DecomposedTransform, Matrix4x4};
+
+use shipyard::{EntitiesView, EntityId, Get, View, ViewMut};
+
+pub type HierarchyViewMut<'a> = (ViewMut<'a, Parent>, ViewMut<'a, Children>, EntitiesView<'a>);
+
+pub type TransformViewMut<'a> = (
+    ViewMut<'a, Translation>,
+    ViewMut<'a, Rotation>,
+    ViewMut<'a, Scale>,
+);
+
+// Keeps Parent and Children consistent. Borrow HierarchyViewMut in a system
+// or through World::run to use it.
+pub trait Hierarchy {
+    // Moves the entity under parent, detaching it from any previous parent.
+    // The local transform is left untouched so the world transform follows the
+    // new parent. Both entities have to be alive.
+    fn attach(&mut self, entity: EntityId, parent: EntityId) -> Result<(), HierarchyError>;
+
+    // Makes the entity a root, returning its previous parent.
+    fn detach(&mut self, entity: EntityId) -> Option<EntityId>;
+
+    // Attaches to new_parent, or detaches when None, rewriting the local
+    // translation, rotation and scale so the current LocalToWorld is kept.
+    // Shear from a non-uniformly scaled parent can not be expressed in TRS and
+    // is dropped.
+    fn reparent_keep_world(
+        &mut self,
+        transform: &mut TransformViewMut,
+        local_to_world_storage: &View<LocalToWorld>,
+        entity: EntityId,
+        new_parent: Option<EntityId>,
+    ) -> Result<(), HierarchyError>;
+
+    fn is_ancestor(&self, ancestor: EntityId, entity: EntityId) -> bool;
+}
+
+impl Hierarchy for HierarchyViewMut<'_> {
+    fn attach(&mut self, entity: EntityId, parent: EntityId) -> Result<(), HierarchyError> {
+        if let Some(dead) = [entity, parent]
+            .iter()
+            .copied()
+            .find(|entity_id| !self.2.is_alive(*entity_id))
+        {
+            return Err(HierarchyError::DeadEntity(dead.inner()));
+        }
+
+        if entity == parent {
+            return Err(HierarchyError::SelfParent(entity.inner()));
+        }
+
+        if self.is_ancestor(entity, parent) {
+            return Err(HierarchyError::Cycle {
+                entity: entity.inner(),
+                parent: parent.inner(),
+            });
+        }
+
+        self.detach(entity);
+
+        let (parent_storage, children_storage, _) = self;
+
+        parent_storage.add_component_unchecked(
+            entity,
+            Parent {
+                value: parent.inner(),
+            },
+        );
+
+        match (&mut *children_storage).get(parent) {
+            Ok(mut children) => children.value.push(entity.inner()),
+            Err(_) => children_storage.add_component_unchecked(
+                parent,
+                Children {
+                    value: vec![entity.inner()],
+                },
+            ),
+        }
+
+        Ok(())
+    }
+
+    fn detach(&mut self, entity: EntityId) -> Option<EntityId> {
+        let (parent_storage, children_storage, _) = self;
+
+        let parent = to_entity_id(parent_storage.remove(entity)?.value);
+
+        let now_empty = match (&mut *children_storage).get(parent) {
+            Ok(mut children) => {
+                children.value.retain(|child| *child != entity.inner());
+                children.value.is_empty()
+            }
+            Err(_) => false,
+        };
+
+        if now_empty {
+            children_storage.remove(parent);
+        }
+
+        Some(parent)
+    }
+
+    fn reparent_keep_world(
+        &mut self,
+        transform: &mut TransformViewMut,
+        local_to_world_storage: &View<LocalToWorld>,
+        entity: EntityId,
+        new_parent: Option<EntityId>,
+    ) -> Result<(), HierarchyError> {
+        let world = local_to_world_storage
+            .get(entity)
+            .map_err(|_| HierarchyError::MissingLocalToWorld(entity.inner()))?
+            .value;
+
+        let parent_world = new_parent
+            .and_then(|parent| local_to_world_storage.get(parent).ok())
+            .map(|local_world| local_world.value)
+            .unwrap_or(Matrix4x4::IDENTITY);
+
+        match new_parent {
+            Some(parent) => self.attach(entity, parent)?,
+            None => {
+                self.detach(entity);
+            }
+        }
+
+        let local: DecomposedTransform = decompose_mat4(inverse_mat4(parent_world) * world);
+
+        let (translation_storage, rotation_storage, scale_storage) = transform;
+
+        match (&mut *translation_storage).get(entity) {
+            Ok(mut translation) => translation.value = local.translation,
+            Err(_) => translation_storage.add_component_unchecked(
+                entity,
+                Translation {
+                    value: local.translation,
+                },
+            ),
+        }
+
+        match (&mut *rotation_storage).get(entity) {
+            Ok(mut rotation) => rotation.value = local.rotation,
+            Err(_) => rotation_storage.add_component_unchecked(
+                entity,
+                Rotation {
+                    value: local.rotation,
+                },
+            ),
+        }
+
+        match (&mut *scale_storage).get(entity) {
+            Ok(mut scale) => scale.value = local.scale,
+            Err(_) => scale_storage.add_component_unchecked(entity, Scale { value: local.scale }),
+        }
+
+        Ok(())
+    }
+
+    fn is_ancestor(&self, ancestor: EntityId, entity: EntityId) -> bool {
+        let parent_storage = &self.0;
+
+        let mut current = entity;
+
+        // Bounded by the number of links so a corrupt cycle still terminates.
+        for _ in 0..parent_storage.len() {
+            match parent_storage.get(current) {
+                Ok(parent) => {
+                    current = to_entity_id(parent.value);
+
+                    if current == ancestor {
+                        return true;
+                    }
+                }
+                Err(_) => return false,
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod hierarchy_test {
+    use crate::{
+        ancestors_iter, children_iter, descendants_iter, despawn_recursive, Children, Hierarchy,
+        HierarchyError, HierarchyViewMut, LocalToWorld, Parent, Rotation, Scale, TransformViewMut,
+        Translation,
+    };
+    use fabled_math::matrix4x4_math::compose_trs_mat4;
+    use fabled_math::{Quaternion, Vector3};
+    use shipyard::{AllStoragesViewMut, EntitiesView, EntityId, Get, View, World};
+
+    fn tree(world: &mut World) -> [EntityId; 5] {
+        // root
+        // ├── a
+        // │   └── c
+        // │       └── d
+        // └── b
+        let entities = [
+            world.add_entity(()),
+            world.add_entity(()),
+            world.add_entity(()),
+            world.add_entity(()),
+            world.add_entity(()),
+        ];
+        let [root, a, b, c, d] = entities;
+
+        world.run(|mut hierarchy: HierarchyViewMut| {
+            hierarchy.attach(a, root).unwrap();
+            hierarchy.attach(b, root).unwrap();
+            hierarchy.attach(c, a).unwrap();
+            hierarchy.attach(d, c).unwrap();
+        });
+
+        entities
+    }
+
+    #[test]
+    fn traversal() {
+        let mut world = World::new();
+        let [root, a, b, c, d] = tree(&mut world);
+
+        world.run(
+            |parent_storage: View<Parent>, children_storage: View<Children>| {
+                assert_eq!(
+                    children_iter(&children_storage, root).collect::<Vec<_>>(),
+                    vec![a, b]
+                );
+                assert_eq!(
+                    descendants_iter(&children_storage, root).collect::<Vec<_>>(),
+                    vec![a, c, d, b]
+                );
+                assert_eq!(
+                    ancestors_iter(&parent_storage, d).collect::<Vec<_>>(),
+                    vec![c, a, root]
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn reparent_and_cycles() {
+        let mut world = World::new();
+        let [root, a, b, c, d] = tree(&mut world);
+
+        world.run(|mut hierarchy: HierarchyViewMut| {
+            assert_eq!(
+                hierarchy.attach(a, a),
+                Err(HierarchyError::SelfParent(a.inner()))
+            );
+            assert_eq!(
+                hierarchy.attach(a, d),
+                Err(HierarchyError::Cycle {
+                    entity: a.inner(),
+                    parent: d.inner(),
+                })
+            );
+
+            assert_eq!(
+                hierarchy.attach(a, EntityId::dead()),
+                Err(HierarchyError::DeadEntity(EntityId::dead().inner()))
+            );
+
+            hierarchy.attach(c, b).unwrap();
+            assert!(hierarchy.is_ancestor(b, d));
+            assert!(!hierarchy.is_ancestor(a, d));
+
+            // a lost its only child so the list is removed.
+            assert!(hierarchy.1.get(a).is_err());
+            assert_eq!(hierarchy.detach(b), Some(root));
+            assert_eq!(hierarchy.detach(b), None);
+        });
+    }
+
+    #[test]
+    fn reparent_keeps_world_transform() {
+        let mut world = World::new();
+
+        let parent_world = compose_trs_mat4(
+            Vector3::set(5.0, 0.0, 0.0),
+            Quaternion::IDENTITY,
+            Vector3::broadcast(2.0),
+        );
+
+        let parent = world.add_entity((LocalToWorld {
+            value: parent_world,
+        },));
+
+        let child = world.add_entity((
+            Translation {
+                value: Vector3::set(1.0, 0.0, 0.0),
+            },
+            Rotation::default(),
+            Scale::default(),
+            LocalToWorld {
+                value: compose_trs_mat4(
+                    Vector3::set(1.0, 0.0, 0.0),
+                    Quaternion::IDENTITY,
+                    Vector3::ONE,
+                ),
+            },
+        ));
+
+        world.run(
+            |mut hierarchy: HierarchyViewMut,
+             mut transform: TransformViewMut,
+             local_to_world_storage: View<LocalToWorld>| {
+                hierarchy
+                    .reparent_keep_world(
+                        &mut transform,
+                        &local_to_world_storage,
+                        child,
+                        Some(parent),
+                    )
+                    .unwrap();
+
+                let translation = transform.0.get(child).unwrap().value;
+                let scale = transform.2.get(child).unwrap().value;
+
+                let expected = [-2.0, 0.0, 0.0, 0.5, 0.5, 0.5];
+                let actual = [translation.to_primitive(), scale.to_primitive()].concat();
+
+                for (expected, actual) in expected.iter().zip(actual.iter()) {
+                    assert!((expected - actual).abs() <= 0.0001);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn corrupt_children_cycle_terminates() {
+        let mut world = World::new();
+        let [root, a, b, c, d] = tree(&mut world);
+
+        // d lists a as its child, closing a loop a -> c -> d -> a.
+        world.run(|mut hierarchy: HierarchyViewMut| {
+            hierarchy.1.add_component_unchecked(
+                d,
+                Children {
+                    value: vec![a.inner()],
+                },
+            );
+        });
+
+        world.run(|children_storage: View<Children>| {
+            assert_eq!(
+                descendants_iter(&children_storage, root).collect::<Vec<_>>(),
+                vec![a, c, d, b]
+            );
+        });
+
+        world.run(|mut all_storages: AllStoragesViewMut| {
+            despawn_recursive(&mut all_storages, a);
+        });
+
+        world.run(|entities: EntitiesView| {
+            assert!(entities.is_alive(root) && entities.is_alive(b));
+            assert!(!entities.is_alive(a) && !entities.is_alive(c) && !entities.is_alive(d));
+        });
+    }
+
+    #[test]
+    fn despawn_subtree() {
+        let mut world = World::new();
+        let [root, a, b, c, d] = tree(&mut world);
+
+        world.run(|mut all_storages: AllStoragesViewMut| {
+            despawn_recursive(&mut all_storages, a);
+        });
+
+        world.run(
+            |parent_storage: View<Parent>, children_storage: View<Children>| {
+                assert!(parent_storage.get(c).is_err());
+                assert!(parent_storage.get(d).is_err());
+                assert_eq!(
+                    descendants_iter(&children_storage, root).collect::<Vec<_>>(),
+                    vec![b]
+                );
+            },
+        );
+
+        world.run(|entities: EntitiesView| {
+            assert!(entities.is_alive(root) && entities.is_alive(b));
+            assert!(!entities.is_alive(a) && !entities.is_alive(c) && !entities.is_alive(d));
+        });
+    }
+}
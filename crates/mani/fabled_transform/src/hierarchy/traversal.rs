@@ -0,0 +1,115 @@
+use crate::{Children, Parent};
+
+use shipyard::{EntityId, Get, View};
+
+use std::collections::HashSet;
+
+#[inline]
+pub(crate) fn to_entity_id(inner: u64) -> EntityId {
+    EntityId::from_inner(inner).unwrap_or_else(EntityId::dead)
+}
+
+#[inline]
+pub fn parent_of(parent_storage: &View<Parent>, entity: EntityId) -> Option<EntityId> {
+    parent_storage
+        .get(entity)
+        .ok()
+        .map(|parent| to_entity_id(parent.value))
+}
+
+pub fn children_iter<'a>(
+    children_storage: &'a View<Children>,
+    entity: EntityId,
+) -> impl Iterator<Item = EntityId> + 'a {
+    children_storage
+        .get(entity)
+        .map(|children| children.value.as_slice())
+        .unwrap_or(&[])
+        .iter()
+        .map(|child| to_entity_id(*child))
+}
+
+// Walks up the Parent chain, nearest first. The walk stops after as many
+// steps as there are Parent components so a corrupt cycle can not hang it.
+pub struct AncestorIter<'a, 'v> {
+    parent_storage: &'a View<'v, Parent>,
+    current: EntityId,
+    remaining: usize,
+}
+
+impl Iterator for AncestorIter<'_, '_> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        let parent = parent_of(self.parent_storage, self.current)?;
+        self.current = parent;
+
+        Some(parent)
+    }
+}
+
+pub fn ancestors_iter<'a, 'v>(
+    parent_storage: &'a View<'v, Parent>,
+    entity: EntityId,
+) -> AncestorIter<'a, 'v> {
+    AncestorIter {
+        parent_storage,
+        current: entity,
+        remaining: parent_storage.len(),
+    }
+}
+
+// Depth first, pre-order, the entity itself is not included. Every entity is
+// yielded once, so a corrupt Children cycle can not hang the walk.
+pub struct DescendantIter<'a, 'v> {
+    children_storage: &'a View<'v, Children>,
+    stack: Vec<EntityId>,
+    visited: HashSet<EntityId>,
+}
+
+impl Iterator for DescendantIter<'_, '_> {
+    type Item = EntityId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entity = self.stack.pop()?;
+
+        if let Ok(children) = self.children_storage.get(entity) {
+            let visited = &mut self.visited;
+
+            self.stack.extend(
+                children
+                    .value
+                    .iter()
+                    .rev()
+                    .map(|child| to_entity_id(*child))
+                    .filter(|child| visited.insert(*child)),
+            );
+        }
+
+        Some(entity)
+    }
+}
+
+pub fn descendants_iter<'a, 'v>(
+    children_storage: &'a View<'v, Children>,
+    entity: EntityId,
+) -> DescendantIter<'a, 'v> {
+    let mut visited: HashSet<EntityId> = std::iter::once(entity).collect();
+
+    let mut stack: Vec<EntityId> = children_iter(children_storage, entity)
+        .filter(|child| visited.insert(*child))
+        .collect();
+    stack.reverse();
+
+    DescendantIter {
+        children_storage,
+        stack,
+        visited,
+    }
+}
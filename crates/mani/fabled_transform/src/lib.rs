@@ -1,3 +1,7 @@
 mod component;
+mod error;
+mod hierarchy;
 
 pub use component::*;
+pub use error::*;
+pub use hierarchy::*;